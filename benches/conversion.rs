@@ -0,0 +1,126 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use geoconvert::{Ellipsoid, LatLon, Mgrs, UtmUps};
+
+/// A spread of points across the UTM and UPS regions
+fn points(count: usize) -> Vec<LatLon> {
+    (0..count)
+        .map(|i| {
+            let t = i as f64 / count as f64;
+            LatLon::create(-89.0 + 178.0 * t, -180.0 + 359.0 * ((t * 7919.0) % 1.0)).unwrap()
+        })
+        .collect()
+}
+
+fn batch_vs_loop(c: &mut Criterion) {
+    let points = points(10_000);
+
+    let mut group = c.benchmark_group("latlon_to_utmups");
+    group.bench_function("loop", |b| {
+        b.iter(|| {
+            black_box(&points)
+                .iter()
+                .map(LatLon::to_utmups)
+                .collect::<Vec<_>>()
+        });
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| LatLon::batch_to_utmups(black_box(&points)));
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("latlon_to_mgrs");
+    group.bench_function("loop", |b| {
+        b.iter(|| {
+            black_box(&points)
+                .iter()
+                .map(|point| point.to_mgrs(5))
+                .collect::<Vec<_>>()
+        });
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| LatLon::batch_to_mgrs(black_box(&points), 5));
+    });
+    group.finish();
+
+    let mgrs = LatLon::batch_to_mgrs(&points, 5);
+
+    let mut group = c.benchmark_group("mgrs_to_string");
+    group.bench_function("to_string", |b| {
+        b.iter(|| {
+            black_box(&mgrs)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        });
+    });
+    group.bench_function("format_many", |b| {
+        b.iter(|| Mgrs::format_many(black_box(&mgrs)));
+    });
+    group.bench_function("write_to", |b| {
+        let mut buf = String::new();
+        b.iter(|| {
+            buf.clear();
+            for point in black_box(&mgrs) {
+                point.write_to(&mut buf);
+                buf.push('\n');
+            }
+        });
+    });
+    group.finish();
+}
+
+fn distance_matrix(c: &mut Criterion) {
+    let points = points(500);
+
+    let mut group = c.benchmark_group("distance_matrix");
+    group.bench_function("loop", |b| {
+        b.iter(|| {
+            let points = black_box(&points);
+            points
+                .iter()
+                .map(|a| points.iter().map(|b| a.haversine(b)).collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        });
+    });
+    group.bench_function("distance_matrix", |b| {
+        b.iter(|| LatLon::distance_matrix(black_box(&points)));
+    });
+    group.finish();
+}
+
+fn within(c: &mut Criterion) {
+    let points = points(10_000);
+    let center = LatLon::create(40.748333, -73.985278).unwrap();
+
+    let mut group = c.benchmark_group("within");
+    group.bench_function("loop", |b| {
+        b.iter(|| {
+            black_box(&points)
+                .iter()
+                .map(|point| point.within(&center, 500_000.0))
+                .collect::<Vec<_>>()
+        });
+    });
+    group.bench_function("within_fast", |b| {
+        b.iter(|| LatLon::within_fast(black_box(&points), &center, 500_000.0));
+    });
+    group.finish();
+}
+
+fn projection_setup(c: &mut Criterion) {
+    let coord = LatLon::create(40.748333, -73.985278).unwrap();
+
+    // from_latlon_with_ellipsoid sets up the projections on every call, while
+    // from_latlon reuses the cached WGS84 ones
+    let mut group = c.benchmark_group("projection_setup");
+    group.bench_function("per_call", |b| {
+        b.iter(|| UtmUps::from_latlon_with_ellipsoid(black_box(&coord), Ellipsoid::WGS84));
+    });
+    group.bench_function("cached", |b| {
+        b.iter(|| UtmUps::from_latlon(black_box(&coord)));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, batch_vs_loop, distance_matrix, within, projection_setup);
+criterion_main!(benches);
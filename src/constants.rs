@@ -4,7 +4,24 @@ pub(crate) const WGS84_A: f64 = 6_378_137.;
 #[allow(clippy::unreadable_literal)]
 pub(crate) const WGS84_F: f64 = 1.0 / 298.257223563;
 
+// GRS80 semi-major axis and flattening
+pub(crate) const GRS80_A: f64 = 6_378_137.;
+#[allow(clippy::unreadable_literal)]
+pub(crate) const GRS80_F: f64 = 1.0 / 298.257222101;
+
+// Clarke 1866 semi-major axis and flattening
+pub(crate) const CLARKE1866_A: f64 = 6_378_206.4;
+#[allow(clippy::unreadable_literal)]
+pub(crate) const CLARKE1866_F: f64 = 1.0 / 294.9786982;
+
+// Airy 1830 semi-major axis and flattening
+pub(crate) const AIRY1830_A: f64 = 6_377_563.396;
+#[allow(clippy::unreadable_literal)]
+pub(crate) const AIRY1830_F: f64 = 1.0 / 299.3249646;
+
 // UTM central scale factor
 pub(crate) const UTM_K0: f64 = 9996.0 / 10_000.;
 // UPS central scale factor
-pub(crate) const UPS_K0: f64 = 994.0 / 1000.;
\ No newline at end of file
+pub(crate) const UPS_K0: f64 = 994.0 / 1000.;
+// British National Grid central scale factor
+pub(crate) const OSGB_K0: f64 = 0.999_601_271_7;
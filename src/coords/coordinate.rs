@@ -0,0 +1,95 @@
+use crate::{latlon::LatLon, mgrs::{Mgrs, MAX_MEANINGFUL_PRECISION}, position::Position, utm::UtmUps};
+
+/// Conversion to and from [`LatLon`], implemented by all of the coordinate
+/// types, for writing code that is generic over them.
+/// 
+/// [`Mgrs`] needs a precision when converting from [`LatLon`], which this trait
+/// can't pass along, so [`Coordinate::from_latlon`] uses
+/// [`Mgrs::max_meaningful_precision`] (1 m). Use [`Mgrs::from_latlon`] or
+/// [`set_precision`](Mgrs::set_precision) for other precisions.
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::{Coordinate, LatLon, Mgrs, UtmUps};
+/// 
+/// fn distance<A: Coordinate, B: Coordinate>(a: &A, b: &B) -> f64 {
+///     a.to_latlon().haversine(&b.to_latlon())
+/// }
+/// 
+/// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+/// let utm = UtmUps::create(18, true, 585664.121, 4511415.422).unwrap();
+/// 
+/// // About 100 m north on the grid
+/// assert!((distance(&coord, &utm) - 100.0).abs() < 1.0);
+/// 
+/// // Convert between any two types
+/// let mgrs: Mgrs = utm.convert();
+/// assert_eq!(mgrs.to_string(), "18TWL8566411415");
+/// ```
+pub trait Coordinate {
+    /// Converts the coordinate to [`LatLon`].
+    fn to_latlon(&self) -> LatLon;
+
+    /// Converts from [`LatLon`] to this coordinate type.
+    fn from_latlon(value: &LatLon) -> Self
+    where
+        Self: Sized;
+
+    /// Converts the coordinate to another coordinate type through [`LatLon`].
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{Coordinate, LatLon, UtmUps};
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// let utm: UtmUps = coord.convert();
+    /// 
+    /// assert_eq!(utm, coord.to_utmups());
+    /// ```
+    fn convert<T: Coordinate>(&self) -> T {
+        T::from_latlon(&self.to_latlon())
+    }
+}
+
+impl Coordinate for LatLon {
+    fn to_latlon(&self) -> LatLon {
+        *self
+    }
+
+    fn from_latlon(value: &LatLon) -> Self {
+        *value
+    }
+}
+
+impl Coordinate for UtmUps {
+    fn to_latlon(&self) -> LatLon {
+        UtmUps::to_latlon(self)
+    }
+
+    fn from_latlon(value: &LatLon) -> Self {
+        UtmUps::from_latlon(value)
+    }
+}
+
+impl Coordinate for Mgrs {
+    fn to_latlon(&self) -> LatLon {
+        Mgrs::to_latlon(self)
+    }
+
+    fn from_latlon(value: &LatLon) -> Self {
+        Mgrs::from_latlon(value, MAX_MEANINGFUL_PRECISION)
+    }
+}
+
+/// Converting from [`LatLon`] gives a [`Position::LatLon`].
+impl Coordinate for Position {
+    fn to_latlon(&self) -> LatLon {
+        Position::to_latlon(self)
+    }
+
+    fn from_latlon(value: &LatLon) -> Self {
+        Position::LatLon(*value)
+    }
+}
@@ -1,15 +1,25 @@
-use std::fmt::Display;
+use alloc::{format, string::String, vec, vec::Vec};
+use core::{fmt::Display, str::FromStr};
 
-use crate::{Error, utm::UtmUps, mgrs::Mgrs};
+#[cfg(not(feature = "std"))]
+use num::traits::Float;
+
+use crate::{Error, ThisOrThat, constants::UTM_K0, utm::UtmUps, mgrs::{Mgrs, MAX_MEANINGFUL_PRECISION}, utility::{dms, GeoMath}, ellipsoid::Ellipsoid, geodesic, projections::wgs84};
 
 /// Mean radius of Earth in meters
 /// 
 /// <https://en.wikipedia.org/wiki/Earth_radius#Arithmetic_mean_radius>
-const EARTH_MEAN_RADIUS_M: f64 = 6371.0088 * 1000.0;
+pub(crate) const EARTH_MEAN_RADIUS_M: f64 = 6371.0088 * 1000.0;
+
+/// Most points [`LatLon::densify_path`] will return
+const MAX_DENSIFY_POINTS: usize = 1_000_000;
 
 /// Representation of a WGS84 Latitude/Longitude point. Can be converted
 /// to/from [`UtmUps`] and [`Mgrs`].
-#[derive(Clone, Copy, Debug)]
+/// 
+/// Equality compares the latitude and longitude exactly. Use
+/// [`approx_eq`](#method.approx_eq) to compare within a tolerance.
+#[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LatLon {
     #[cfg_attr(feature = "serde", serde(alias = "lat"))]
@@ -19,8 +29,44 @@ pub struct LatLon {
 }
 
 impl LatLon {
+    /// The point at latitude `0` and longitude `0` ("Null Island"), where the
+    /// equator meets the prime meridian.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// assert_eq!(LatLon::ORIGIN.latitude(), 0.0);
+    /// assert_eq!(LatLon::ORIGIN.longitude(), 0.0);
+    /// assert_eq!(LatLon::ORIGIN.to_mgrs(5).to_string(), "31NAA6602100000");
+    /// ```
+    pub const ORIGIN: LatLon = LatLon { latitude: 0.0, longitude: 0.0 };
+
     /// Internal-only constructor that doesn't check the bounds of lat/lon
     pub(crate) fn new(lat: f64, lon: f64) -> LatLon {
+        Self::new_unchecked(lat, lon)
+    }
+
+    /// Creates a latitude/longitude point without checking the values, for
+    /// coordinates already known to be valid (e.g. from a trusted database) where
+    /// the checks in [`create`](#method.create) would be wasted in a hot loop.
+    /// Being a `const fn`, it can also be used to define constants.
+    /// 
+    /// This is not `unsafe`, as it can't cause undefined behavior, but the caller
+    /// is responsible for passing a latitude in `[-90, 90]` and a longitude in
+    /// `[-180, 180)`. Out of range or non-finite values give meaningless results
+    /// from every conversion, and may cause panics (e.g. when formatting as
+    /// [`Mgrs`]).
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// const EMPIRE_STATE_BUILDING: LatLon = LatLon::new_unchecked(40.748333, -73.985278);
+    /// 
+    /// assert_eq!(EMPIRE_STATE_BUILDING, LatLon::create(40.748333, -73.985278).unwrap());
+    /// ```
+    pub const fn new_unchecked(lat: f64, lon: f64) -> LatLon {
         Self {
             latitude: lat,
             longitude: lon,
@@ -34,12 +80,14 @@ impl LatLon {
     /// 
     /// # Errors
     /// 
-    /// Returns [`Error::InvalidCoord`] if either latitude or longitude are invalid.
+    /// Returns [`Error::LatLonOutOfRange`] if either latitude or longitude is
+    /// outside of its range, or [`Error::InvalidCoord`] if either is `NaN` or
+    /// infinite.
     /// 
     /// # Usage
     /// 
     /// ```
-    /// use geoconvert::LatLon;
+    /// use geoconvert::{Error, LatLon};
     /// 
     /// let coord = LatLon::create(40.748333, -73.985278);
     /// 
@@ -55,17 +103,139 @@ impl LatLon {
     /// 
     /// let invalid_coord_lon = LatLon::create(0.0, -200.0);
     /// assert!(invalid_coord_lon.is_err());
+    /// 
+    /// // The error keeps the values for clamping and retrying
+    /// if let Err(Error::LatLonOutOfRange { lat, lon, .. }) = LatLon::create(90.5, 10.0) {
+    ///     assert_eq!(LatLon::create(lat.clamp(-90.0, 90.0), lon).unwrap().latitude(), 90.0);
+    /// } else {
+    ///     panic!("expected LatLonOutOfRange");
+    /// }
     /// ```
     pub fn create(lat: f64, lon: f64) -> Result<LatLon, Error> {
-        if !(-90_f64..=90_f64).contains(&lat) {
-            Err(Error::InvalidCoord(format!("Latitude {lat} outside of valid range [-90, 90].")))
+        if !(lat.is_finite() && lon.is_finite()) {
+            Err(Error::InvalidCoord(format!("Latitude {lat} and longitude {lon} must be finite.")))
+        } else if !(-90_f64..=90_f64).contains(&lat) {
+            Err(Error::LatLonOutOfRange { lat, lon, msg: format!("Latitude {lat} outside of valid range [-90, 90].") })
         } else if !(-180_f64..180_f64).contains(&lon) {
-            Err(Error::InvalidCoord(format!("Longitude {lon} outside of valid range [-180, 180].")))
+            Err(Error::LatLonOutOfRange { lat, lon, msg: format!("Longitude {lon} outside of valid range [-180, 180].") })
         } else {
             Ok(LatLon::new(lat, lon))
         }
     }
 
+    /// Decomposes the point into `(latitude, longitude)`. The reverse of
+    /// [`from_parts`](#method.from_parts).
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// let (lat, lon) = coord.into_parts();
+    /// 
+    /// assert_eq!((lat, lon), (40.748333, -73.985278));
+    /// ```
+    pub fn into_parts(self) -> (f64, f64) {
+        (self.latitude, self.longitude)
+    }
+
+    /// Tries to create a point from `(latitude, longitude)`, validated the same
+    /// way as [`create`](#method.create). The reverse of
+    /// [`into_parts`](#method.into_parts).
+    /// 
+    /// # Errors
+    /// 
+    /// See [`create`](#method.create).
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// assert_eq!(LatLon::from_parts(coord.into_parts()).unwrap(), coord);
+    /// 
+    /// assert!(LatLon::from_parts((100.0, 0.0)).is_err());
+    /// ```
+    pub fn from_parts((lat, lon): (f64, f64)) -> Result<LatLon, Error> {
+        LatLon::create(lat, lon)
+    }
+
+    /// Creates a latitude/longitude point from any lat/lon pair, normalizing
+    /// values that [`create`](#method.create) would reject, e.g. a longitude
+    /// pushed slightly out of range by adding an offset.
+    /// * Latitude is reflected over the poles, so going past a pole comes back
+    ///   down the other side at the opposite longitude, e.g. `95` becomes `85`
+    ///   with `180` added to the longitude
+    /// * Longitude is wrapped to the range [-180,180)
+    /// 
+    /// Non-finite values can't be normalized, and give a point with `NaN`
+    /// values.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let coord = LatLon::normalize(40.748333, -73.985278 + 360.0);
+    /// assert!(coord.approx_eq(&LatLon::create(40.748333, -73.985278).unwrap(), 1e-12));
+    /// 
+    /// let coord = LatLon::normalize(10.0, 180.0);
+    /// assert_eq!(coord, LatLon::create(10.0, -180.0).unwrap());
+    /// 
+    /// // Over the north pole
+    /// let coord = LatLon::normalize(95.0, 30.0);
+    /// assert_eq!(coord, LatLon::create(85.0, -150.0).unwrap());
+    /// 
+    /// // Over the south pole
+    /// let coord = LatLon::normalize(-100.0, -170.0);
+    /// assert_eq!(coord, LatLon::create(-80.0, 10.0).unwrap());
+    /// 
+    /// // Valid values are unchanged
+    /// let coord = LatLon::create(-33.8688, 151.2093).unwrap();
+    /// assert_eq!(LatLon::normalize(coord.latitude(), coord.longitude()), coord);
+    /// ```
+    pub fn normalize(lat: f64, lon: f64) -> LatLon {
+        let hd = f64::from(dms::HD);
+        let qd = f64::from(dms::QD);
+
+        let lat = lat.ang_normalize();
+
+        let (lat, lon) = if lat > qd {
+            (hd - lat, lon + hd)
+        } else if lat < -qd {
+            (-hd - lat, lon + hd)
+        } else {
+            (lat, lon)
+        };
+
+        LatLon::new(lat, wrap_longitude(lon))
+    }
+
+    /// Returns the antipode, the point on the opposite side of the Earth, at the
+    /// negated latitude and 180° away in longitude. The longitude is wrapped to
+    /// the range [-180,180).
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// let antipode = coord.antipode();
+    /// assert_eq!(antipode, LatLon::create(-40.748333, -73.985278 + 180.0).unwrap());
+    /// assert_eq!(antipode.antipode(), coord);
+    /// 
+    /// assert_eq!(LatLon::create(0.0, 0.0).unwrap().antipode(), LatLon::create(0.0, -180.0).unwrap());
+    /// assert_eq!(LatLon::create(10.0, -180.0).unwrap().antipode(), LatLon::create(-10.0, 0.0).unwrap());
+    /// 
+    /// // Half way around the world
+    /// let distance = coord.haversine(&antipode);
+    /// assert!((distance - std::f64::consts::PI * 6_371_008.8).abs() < 1e-3);
+    /// ```
+    pub fn antipode(&self) -> LatLon {
+        LatLon::new(-self.latitude, wrap_longitude(self.longitude + f64::from(dms::HD)))
+    }
+
     /// Returns the latitude value.
     /// 
     /// # Example
@@ -94,120 +264,1640 @@ impl LatLon {
         self.longitude
     }
 
-    /// Returns whether the current point is in the northern hemisphere.
+    /// Returns the latitude and longitude rounded to [`f32`]s, as
+    /// `(latitude, longitude)`, for storing large numbers of points in less
+    /// memory.
+    /// 
+    /// An `f32` only has 24 bits of precision, so values are only kept to within
+    /// about `2e-6` degrees (`0.2` m) near 45° and `8e-6` degrees (`0.85` m of
+    /// longitude at the equator) near 180°.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// let (lat, lon) = coord.to_f32_pair();
+    /// 
+    /// assert!((f64::from(lat) - coord.latitude()).abs() < 2e-6);
+    /// assert!((f64::from(lon) - coord.longitude()).abs() < 4e-6);
+    /// ```
+    pub fn to_f32_pair(&self) -> (f32, f32) {
+        (self.latitude as f32, self.longitude as f32)
+    }
+
+    /// Returns the latitude and longitude in radians, as `(latitude, longitude)`,
+    /// for use with math libraries that work in radians.
     /// 
     /// # Example
+    /// ```
+    /// use std::f64::consts::FRAC_PI_4;
+    /// 
+    /// use geoconvert::LatLon;
+    /// 
+    /// let coord = LatLon::create(45.0, -90.0).unwrap();
+    /// let (lat, lon) = coord.to_radians();
+    /// 
+    /// assert!((lat - FRAC_PI_4).abs() < 1e-15);
+    /// assert!((lon + 2.0 * FRAC_PI_4).abs() < 1e-15);
+    /// ```
+    pub fn to_radians(&self) -> (f64, f64) {
+        (self.latitude.to_radians(), self.longitude.to_radians())
+    }
+
+    /// Tries to create a point from a latitude and longitude in radians. They're
+    /// converted to degrees and validated the same way as [`create`](#method.create),
+    /// except that the longitude is first wrapped to `[-180, 180)`, so e.g. a
+    /// longitude of `PI` gives `-180`. The reverse of [`to_radians`](#method.to_radians).
+    /// 
+    /// # Errors
+    /// 
+    /// See [`create`](#method.create).
     /// 
+    /// # Example
     /// ```
+    /// use std::f64::consts::PI;
+    /// 
     /// use geoconvert::LatLon;
     /// 
+    /// let coord = LatLon::from_radians(PI / 4.0, -PI / 2.0).unwrap();
+    /// assert!(coord.approx_eq(&LatLon::create(45.0, -90.0).unwrap(), 1e-12));
+    /// 
     /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
-    /// assert!(coord.is_north());
+    /// let (lat, lon) = coord.to_radians();
+    /// assert!(LatLon::from_radians(lat, lon).unwrap().approx_eq(&coord, 1e-12));
     /// 
-    /// let coord = LatLon::create(-40.748333, -73.985278).unwrap();
-    /// assert!(!coord.is_north());
+    /// // The antimeridian, as given by many libraries working in radians
+    /// assert_eq!(LatLon::from_radians(0.0, PI).unwrap().longitude(), -180.0);
+    /// 
+    /// // More than 90°
+    /// assert!(LatLon::from_radians(2.0, 0.0).is_err());
     /// ```
-    pub fn is_north(&self) -> bool {
-        self.latitude.is_sign_positive()
+    pub fn from_radians(lat: f64, lon: f64) -> Result<LatLon, Error> {
+        let lon = lon.to_degrees();
+
+        // Non-finite longitudes are left for create to reject
+        LatLon::create(lat.to_degrees(), lon.is_finite().ternary_lazy(|| wrap_longitude(lon), || lon))
     }
 
-    /// Returns the distance in meters between two [`LatLon`] points
-    /// using the [haversine formula](https://en.wikipedia.org/wiki/Haversine_formula).
-    /// Uses the [mean radius of the Earth](https://en.wikipedia.org/wiki/Earth_radius#Arithmetic_mean_radius)
-    /// in the calculation: `6371.0088`
-    pub fn haversine(&self, other: &LatLon) -> f64 {
-        let lat1_r = self.latitude.to_radians();
-        let lat2_r = other.latitude.to_radians();
-        
-        2.0 * EARTH_MEAN_RADIUS_M * (
-            ((other.latitude - self.latitude).to_radians() / 2.0).sin().powi(2) + 
-            lat1_r.cos() * lat2_r.cos() *
-            ((other.longitude - self.longitude).to_radians() / 2.0).sin().powi(2)
-        ).sqrt().asin()
+    /// Returns the point with the latitude and longitude rounded to `decimals`
+    /// decimal places, e.g. for coarsening locations before sharing them to
+    /// protect privacy (`2` decimals is about 1 km, `3` about 100 m), or for
+    /// stable string output.
+    /// 
+    /// A longitude rounded up to `180` is wrapped to `-180`, so the result is
+    /// always a valid point. Rounding to more than `15` decimals leaves the
+    /// point unchanged, since that's beyond the precision of an [`f64`].
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// 
+    /// assert_eq!(coord.round_to(2), LatLon::create(40.75, -73.99).unwrap());
+    /// assert_eq!(coord.round_to(0), LatLon::create(41.0, -74.0).unwrap());
+    /// assert_eq!(coord.round_to(20), coord);
+    /// 
+    /// // Wraps across the antimeridian
+    /// let coord = LatLon::create(-16.5, 179.99999).unwrap();
+    /// assert_eq!(coord.round_to(0), LatLon::create(-17.0, -180.0).unwrap());
+    /// ```
+    pub fn round_to(&self, decimals: u32) -> LatLon {
+        if decimals > 15 {
+            return *self;
+        }
+
+        let scale = 10_f64.powi(decimals as i32);
+        let round = |value: f64| (value * scale).round() / scale;
+
+        LatLon::new(round(self.latitude), wrap_longitude(round(self.longitude)))
     }
-    
-    /// Converts from [`UtmUps`] to [`LatLon`]
+
+    /// Returns a copy of this point with a different latitude, validated the same
+    /// way as [`create`](#method.create). Consumes and returns `Self` so calls
+    /// can be chained.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::LatLonOutOfRange`] if the latitude is outside of its
+    /// range, or [`Error::InvalidCoord`] if it is `NaN` or infinite.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap()
+    ///     .with_latitude(41.0).unwrap()
+    ///     .with_longitude(-74.0).unwrap();
+    /// 
+    /// assert_eq!(coord.latitude(), 41.0);
+    /// assert_eq!(coord.longitude(), -74.0);
+    /// 
+    /// assert!(coord.with_latitude(91.0).is_err());
+    /// ```
+    pub fn with_latitude(self, lat: f64) -> Result<LatLon, Error> {
+        LatLon::create(lat, self.longitude)
+    }
+
+    /// Returns a copy of this point with a different longitude, validated the
+    /// same way as [`create`](#method.create). Consumes and returns `Self` so
+    /// calls can be chained.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::LatLonOutOfRange`] if the longitude is outside of its
+    /// range, or [`Error::InvalidCoord`] if it is `NaN` or infinite.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// 
+    /// assert_eq!(coord.with_longitude(-74.0).unwrap().longitude(), -74.0);
+    /// assert!(coord.with_longitude(180.0).is_err());
+    /// ```
+    pub fn with_longitude(self, lon: f64) -> Result<LatLon, Error> {
+        LatLon::create(self.latitude, lon)
+    }
+
+    /// Formats the point in degrees, minutes and seconds with hemisphere
+    /// designators, e.g. `40°44'54.0"N 073°59'07.0"W`, with `seconds_precision`
+    /// decimal places of seconds (at most 9). Longitude degrees are padded to 3
+    /// digits. Seconds are rounded without rolling over to 60. The result can be
+    /// parsed back with [`FromStr`].
     /// 
     /// # Usage
     /// 
     /// ```
-    /// use geoconvert::{LatLon, UtmUps};
+    /// use geoconvert::LatLon;
     /// 
     /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
-    /// let coord_utm = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
     /// 
-    /// let converted = LatLon::from_utmups(&coord_utm);
+    /// assert_eq!(coord.to_dms_string(1), "40°44'54.0\"N 073°59'07.0\"W");
+    /// assert_eq!(coord.to_dms_string(0), "40°44'54\"N 073°59'07\"W");
     /// 
-    /// // Check if the converted coordinate is accurate to 6 decimals (same as reference)
-    /// assert!((converted.latitude() - coord.latitude()).abs() < 1e-6);
-    /// assert!((converted.longitude() - coord.longitude()).abs() < 1e-6);
+    /// // Rounds up to the next minute instead of 60 seconds
+    /// let coord = LatLon::create(-33.99999999, 151.0).unwrap();
+    /// assert_eq!(coord.to_dms_string(2), "34°00'00.00\"S 151°00'00.00\"E");
+    /// 
+    /// let parsed: LatLon = coord.to_dms_string(4).parse().unwrap();
+    /// assert!(coord.approx_eq(&parsed, 1e-7));
     /// ```
-    pub fn from_utmups(value: &UtmUps) -> LatLon {
-        value.to_latlon()
+    pub fn to_dms_string(&self, seconds_precision: usize) -> String {
+        format!(
+            "{} {}",
+            dms::encode(self.latitude, dms::Flag::Latitude, seconds_precision),
+            dms::encode(self.longitude, dms::Flag::Longitude, seconds_precision),
+        )
     }
-    
-    /// Converts from [`LatLon`] to [`UtmUps`]
+
+    /// Formats the point as [WKT](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry),
+    /// e.g. `POINT(-73.985278 40.748333)`, for use with `PostGIS` and other tools
+    /// that speak WKT. Note that WKT puts the longitude (x) _before_ the latitude
+    /// (y), the opposite of this crate's usual order. The result can be parsed
+    /// back with [`from_wkt`](#method.from_wkt).
     /// 
     /// # Usage
     /// 
     /// ```
-    /// use geoconvert::{LatLon, UtmUps};
+    /// use geoconvert::LatLon;
     /// 
     /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
-    /// let coord_utm = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
+    /// assert_eq!(coord.to_wkt(), "POINT(-73.985278 40.748333)");
+    /// ```
+    pub fn to_wkt(&self) -> String {
+        let mut buf = ryu::Buffer::new();
+        let lon = buf.format(self.longitude);
+        let mut buf = ryu::Buffer::new();
+        let lat = buf.format(self.latitude);
+
+        format!("POINT({lon} {lat})")
+    }
+
+    /// Parses a [WKT](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry)
+    /// point, e.g. `POINT(-73.985278 40.748333)`, with the longitude (x) first as
+    /// in [`to_wkt`](#method.to_wkt). The `POINT` keyword is case insensitive, and
+    /// whitespace around the keyword, parentheses and numbers is ignored.
     /// 
-    /// let converted = coord.to_utmups();
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidCoord`] if the string isn't a 2D WKT point, or
+    /// [`Error::LatLonOutOfRange`] if the latitude or longitude is out of range.
+    /// 
+    /// # Usage
     /// 
-    /// assert_eq!(converted.zone(), coord_utm.zone());
-    /// assert_eq!(converted.is_north(), coord_utm.is_north());
-    /// // Check if the converted coordinate is accurate to 3 decimals (same as reference)
-    /// assert!((converted.easting() - coord_utm.easting()).abs() < 1e-3);
-    /// assert!((converted.northing() - coord_utm.northing()).abs() < 1e-3);
     /// ```
-    pub fn to_utmups(&self) -> UtmUps {
-        UtmUps::from_latlon(self)
+    /// use geoconvert::LatLon;
+    /// 
+    /// let coord = LatLon::from_wkt("POINT(-73.985278 40.748333)").unwrap();
+    /// assert_eq!(coord.latitude(), 40.748333);
+    /// assert_eq!(coord.longitude(), -73.985278);
+    /// 
+    /// assert_eq!(LatLon::from_wkt(" point ( -73.985278  40.748333 ) ").unwrap(), coord);
+    /// 
+    /// // Latitude out of range, likely from putting the latitude first
+    /// assert!(LatLon::from_wkt("POINT(40.748333 -173.985278)").is_err());
+    /// assert!(LatLon::from_wkt("POINT EMPTY").is_err());
+    /// assert!(LatLon::from_wkt("POINT Z(-73.985278 40.748333 10)").is_err());
+    /// ```
+    pub fn from_wkt(s: &str) -> Result<LatLon, Error> {
+        let invalid = || Error::InvalidCoord(format!("Not a WKT point: {s}"));
+
+        let s = s.trim();
+        let keyword = s.get(..5).filter(|keyword| keyword.eq_ignore_ascii_case("POINT")).ok_or_else(invalid)?;
+
+        let values = s[keyword.len()..]
+            .trim_start()
+            .strip_prefix('(')
+            .and_then(|rest| rest.strip_suffix(')'))
+            .ok_or_else(invalid)?;
+
+        let mut values = values.split_whitespace().map(str::parse::<f64>);
+        match (values.next(), values.next(), values.next()) {
+            (Some(Ok(lon)), Some(Ok(lat)), None) => LatLon::create(lat, lon),
+            _ => Err(invalid()),
+        }
     }
 
-    /// Converts from [`Mgrs`] to [`LatLon`]
+    /// Returns whether the current point is in the northern hemisphere.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// assert!(coord.is_north());
+    /// 
+    /// let coord = LatLon::create(-40.748333, -73.985278).unwrap();
+    /// assert!(!coord.is_north());
+    /// ```
+    pub fn is_north(&self) -> bool {
+        self.latitude.is_sign_positive()
+    }
+
+    /// Returns whether two points are within `tol_deg` degrees of each other in
+    /// both latitude and longitude. Longitudes are compared across the
+    /// antimeridian, so `179.9999999` and `-180.0` are close.
+    /// 
+    /// The tolerance is in degrees, not meters: `1e-9` degrees of latitude is
+    /// about 0.1 mm. Use [`UtmUps::approx_eq`] to compare in meters on the grid,
+    /// or [`haversine`](#method.haversine) for the distance between the points.
     /// 
     /// # Usage
     /// 
     /// ```
-    /// use geoconvert::{LatLon, Mgrs};
+    /// use geoconvert::LatLon;
     /// 
     /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
-    /// let coord_mgrs = Mgrs::parse_str("18TWL856641113154").unwrap();
+    /// let converted = coord.to_utmups().to_latlon();
     /// 
-    /// let converted = LatLon::from_mgrs(&coord_mgrs);
+    /// assert!(coord.approx_eq(&converted, 1e-9));
+    /// assert!(!coord.approx_eq(&LatLon::create(40.75, -73.985278).unwrap(), 1e-9));
     /// 
-    /// // Check if the converted coordinate is accurate to 6 decimals (same as reference)
-    /// assert!((converted.latitude() - coord.latitude()).abs() < 1e-6);
-    /// assert!((converted.longitude() - coord.longitude()).abs() < 1e-6);
+    /// let west = LatLon::create(0.0, 179.9999999).unwrap();
+    /// let east = LatLon::create(0.0, -180.0).unwrap();
+    /// assert!(west.approx_eq(&east, 1e-6));
     /// ```
-    pub fn from_mgrs(value: &Mgrs) -> LatLon {
-        value.to_latlon()
+    pub fn approx_eq(&self, other: &LatLon, tol_deg: f64) -> bool {
+        (self.latitude - other.latitude).abs() <= tol_deg
+            && self.longitude.ang_diff(other.longitude).abs() <= tol_deg
     }
 
-    /// Converts from [`LatLon`] to [`Mgrs`]
+    /// Returns the distance in meters between two [`LatLon`] points
+    /// using the [haversine formula](https://en.wikipedia.org/wiki/Haversine_formula).
+    /// Uses the [mean radius of the Earth](https://en.wikipedia.org/wiki/Earth_radius#Arithmetic_mean_radius)
+    /// in the calculation: `6371.0088` km. See [`haversine_with_radius`](#method.haversine_with_radius)
+    /// to use a different radius.
+    pub fn haversine(&self, other: &LatLon) -> f64 {
+        self.haversine_with_radius(other, EARTH_MEAN_RADIUS_M)
+    }
+
+    /// Returns the distance between two [`LatLon`] points on a sphere of radius
+    /// `radius_m` using the [haversine formula](https://en.wikipedia.org/wiki/Haversine_formula).
+    /// The distance is in the same units as the radius, normally meters.
+    /// 
+    /// Useful for other Earth radius conventions, or for other bodies such as
+    /// the Moon or Mars.
     /// 
     /// # Usage
     /// 
     /// ```
-    /// use geoconvert::{LatLon, Mgrs};
+    /// use geoconvert::LatLon;
     /// 
-    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// let a = LatLon::create(0.0, 0.0).unwrap();
+    /// let b = LatLon::create(0.0, 90.0).unwrap();
     /// 
-    /// let converted = coord.to_mgrs(6);
+    /// // A quarter of the way around the Moon's equator
+    /// let moon_radius_m = 1_737_400.0;
+    /// let distance = a.haversine_with_radius(&b, moon_radius_m);
+    /// assert!((distance - std::f64::consts::FRAC_PI_2 * moon_radius_m).abs() < 1e-6);
     /// 
-    /// assert_eq!(converted.to_string(), "18TWL856641113154");
+    /// assert_eq!(a.haversine_with_radius(&b, 6_371_008.8), a.haversine(&b));
     /// ```
-    pub fn to_mgrs(&self, precision: i32) -> Mgrs {
-        Mgrs::from_latlon(self, precision)
+    pub fn haversine_with_radius(&self, other: &LatLon, radius_m: f64) -> f64 {
+        haversine_from_cos(
+            self.latitude, self.longitude, self.latitude.to_radians().cos(),
+            other.latitude, other.longitude, other.latitude.to_radians().cos(),
+            radius_m,
+        )
+    }
+
+    /// Returns the [`haversine`](#method.haversine) distances in meters between
+    /// every pair of points, as a symmetric matrix where `matrix[i][j]` is the
+    /// distance between `points[i]` and `points[j]`, with zeros on the diagonal.
+    /// 
+    /// This computes `n * (n - 1) / 2` distances for `n` points, so it takes
+    /// `O(n^2)` time and memory. Each point's latitude is converted and its cosine
+    /// taken once up front rather than for every pair, and each distance is only
+    /// computed once and mirrored. The results are the same as calling
+    /// [`haversine`](#method.haversine) in a double loop.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let points = [
+    ///     LatLon::create(40.6413, -73.7781).unwrap(),
+    ///     LatLon::create(51.47, -0.4543).unwrap(),
+    ///     LatLon::create(-33.9399, 151.1753).unwrap(),
+    /// ];
+    /// 
+    /// let matrix = LatLon::distance_matrix(&points);
+    /// 
+    /// assert_eq!(matrix.len(), 3);
+    /// assert_eq!(matrix[0][0], 0.0);
+    /// assert_eq!(matrix[0][1], points[0].haversine(&points[1]));
+    /// assert_eq!(matrix[2][1], matrix[1][2]);
+    /// ```
+    pub fn distance_matrix(points: &[LatLon]) -> Vec<Vec<f64>> {
+        let cos_lats: Vec<f64> = points
+            .iter()
+            .map(|point| point.latitude.to_radians().cos())
+            .collect();
+
+        let mut matrix = vec![vec![0.0; points.len()]; points.len()];
+
+        for (i, (a, cos_a)) in points.iter().zip(&cos_lats).enumerate() {
+            for (j, (b, cos_b)) in points.iter().zip(&cos_lats).enumerate().skip(i + 1) {
+                let distance = haversine_from_cos(
+                    a.latitude, a.longitude, *cos_a,
+                    b.latitude, b.longitude, *cos_b,
+                    EARTH_MEAN_RADIUS_M,
+                );
+
+                matrix[i][j] = distance;
+                matrix[j][i] = distance;
+            }
+        }
+
+        matrix
+    }
+
+    /// Returns whether this point is within `radius_m` meters of `center`
+    /// (inclusive), by the [`haversine`](#method.haversine) distance, e.g. for
+    /// geofencing. See [`within_fast`](#method.within_fast) for checking many
+    /// points against the same center.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let empire_state = LatLon::create(40.748333, -73.985278).unwrap();
+    /// let times_square = LatLon::create(40.758, -73.9855).unwrap();
+    /// 
+    /// // About 1.07 km apart
+    /// assert!(times_square.within(&empire_state, 1_100.0));
+    /// assert!(!times_square.within(&empire_state, 1_000.0));
+    /// ```
+    pub fn within(&self, center: &LatLon, radius_m: f64) -> bool {
+        self.haversine(center) <= radius_m
+    }
+
+    /// Returns whether each of the points is within `radius_m` meters of
+    /// `center`, with the same results as calling [`within`](#method.within) on
+    /// each point, but faster.
+    /// 
+    /// The center's latitude is converted and its cosine taken once up front.
+    /// Points further from the center in latitude, or (if the circle doesn't
+    /// contain a pole) in longitude, than any point of the circle are rejected
+    /// without computing the distance, which makes this many times faster
+    /// when most points are far away.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let center = LatLon::create(40.748333, -73.985278).unwrap();
+    /// let points = [(40.758, -73.9855), (40.6413, -73.7781), (51.47, -0.4543)]
+    ///     .map(|(lat, lon)| LatLon::create(lat, lon).unwrap());
+    /// 
+    /// let inside = LatLon::within_fast(&points, &center, 25_000.0);
+    /// assert_eq!(inside, [true, true, false]);
+    /// 
+    /// for (point, inside) in points.iter().zip(inside) {
+    ///     assert_eq!(point.within(&center, 25_000.0), inside);
+    /// }
+    /// ```
+    pub fn within_fast(points: &[LatLon], center: &LatLon, radius_m: f64) -> Vec<bool> {
+        let qd = f64::from(dms::QD);
+        let hd = f64::from(dms::HD);
+
+        // Angular radius of the circle in degrees, slightly enlarged so that
+        // rounding can't reject points that are inside
+        let radius_deg = (radius_m / EARTH_MEAN_RADIUS_M).to_degrees() * (1.0 + 1e-9);
+
+        // Half of the longitude range of the circle, if it doesn't contain a pole
+        let max_dlon = (center.latitude.abs() + radius_deg < qd)
+            .ternary_lazy(
+                || (radius_deg.to_radians().sin() / center.latitude.to_radians().cos()).asin().to_degrees() * (1.0 + 1e-9),
+                || f64::INFINITY,
+            );
+
+        let cos_center = center.latitude.to_radians().cos();
+
+        points
+            .iter()
+            .map(|point| {
+                let dlon = (center.longitude - point.longitude).abs();
+                if (center.latitude - point.latitude).abs() > radius_deg || dlon.min(2.0 * hd - dlon) > max_dlon {
+                    return false;
+                }
+
+                let distance = haversine_from_cos(
+                    point.latitude, point.longitude, point.latitude.to_radians().cos(),
+                    center.latitude, center.longitude, cos_center,
+                    EARTH_MEAN_RADIUS_M,
+                );
+
+                distance <= radius_m
+            })
+            .collect()
+    }
+
+    /// Returns the initial bearing (forward azimuth) in degrees, in range `[0, 360)`,
+    /// to follow the great circle from this point to `other`. Uses a spherical
+    /// model of the Earth, like [`haversine`](#method.haversine).
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let jfk = LatLon::create(40.6413, -73.7781).unwrap();
+    /// let lhr = LatLon::create(51.4700, -0.4543).unwrap();
+    /// 
+    /// assert!((jfk.initial_bearing(&lhr) - 51.35).abs() < 0.01);
+    /// 
+    /// // Due south
+    /// let south = LatLon::create(30.0, -73.7781).unwrap();
+    /// assert_eq!(jfk.initial_bearing(&south), 180.0);
+    /// ```
+    pub fn initial_bearing(&self, other: &LatLon) -> f64 {
+        let (lat1_sin, lat1_cos) = self.latitude.to_radians().sin_cos();
+        let (lat2_sin, lat2_cos) = other.latitude.to_radians().sin_cos();
+        let (dlon_sin, dlon_cos) = (other.longitude - self.longitude).to_radians().sin_cos();
+
+        let bearing = (dlon_sin * lat2_cos)
+            .atan2(lat1_cos * lat2_sin - lat1_sin * lat2_cos * dlon_cos)
+            .to_degrees();
+
+        normalize_bearing(bearing)
+    }
+
+    /// Returns the final bearing in degrees, in range `[0, 360)`, on arrival at
+    /// `other` when following the great circle from this point. This differs from
+    /// the initial bearing except along meridians and the equator. Uses a spherical
+    /// model of the Earth, like [`haversine`](#method.haversine).
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let jfk = LatLon::create(40.6413, -73.7781).unwrap();
+    /// let lhr = LatLon::create(51.4700, -0.4543).unwrap();
+    /// 
+    /// assert!((jfk.final_bearing(&lhr) - 107.94).abs() < 0.01);
+    /// ```
+    pub fn final_bearing(&self, other: &LatLon) -> f64 {
+        normalize_bearing(other.initial_bearing(self) + f64::from(dms::HD))
+    }
+
+    /// Returns both the [initial bearing](#method.initial_bearing) in degrees and
+    /// the [haversine distance](#method.haversine) in meters to `other`, as
+    /// `(bearing, distance_m)`, sharing the trigonometry between the two. Uses
+    /// the same spherical model and mean radius as [`haversine`](#method.haversine).
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let jfk = LatLon::create(40.6413, -73.7781).unwrap();
+    /// let lhr = LatLon::create(51.4700, -0.4543).unwrap();
+    /// 
+    /// let (bearing, distance) = jfk.bearing_distance_to(&lhr);
+    /// assert!((bearing - jfk.initial_bearing(&lhr)).abs() < 1e-9);
+    /// assert!((distance - jfk.haversine(&lhr)).abs() < 1e-6);
+    /// 
+    /// assert_eq!(jfk.bearing_distance_to(&jfk), (0.0, 0.0));
+    /// ```
+    pub fn bearing_distance_to(&self, other: &LatLon) -> (f64, f64) {
+        let (lat1_sin, lat1_cos) = self.latitude.to_radians().sin_cos();
+        let (lat2_sin, lat2_cos) = other.latitude.to_radians().sin_cos();
+        let dlat_half_sin = ((other.latitude - self.latitude).to_radians() / 2.0).sin();
+        let (dlon_half_sin, dlon_half_cos) = ((other.longitude - self.longitude).to_radians() / 2.0).sin_cos();
+
+        // Double angle formulas for the full longitude difference
+        let dlon_sin = 2.0 * dlon_half_sin * dlon_half_cos;
+        let dlon_cos = 1.0 - 2.0 * dlon_half_sin.powi(2);
+
+        let bearing = (dlon_sin * lat2_cos)
+            .atan2(lat1_cos * lat2_sin - lat1_sin * lat2_cos * dlon_cos)
+            .to_degrees();
+
+        let h = dlat_half_sin.powi(2) + lat1_cos * lat2_cos * dlon_half_sin.powi(2);
+        let distance = 2.0 * EARTH_MEAN_RADIUS_M * h.sqrt().min(1.0).asin();
+
+        (normalize_bearing(bearing), distance)
+    }
+
+    /// Returns the point reached by travelling `distance_m` meters from this point
+    /// along the great circle with the given initial bearing (in degrees clockwise
+    /// from north). Uses the same spherical model and mean radius as
+    /// [`haversine`](#method.haversine).
+    /// 
+    /// The result is always a valid coordinate: paths crossing the antimeridian
+    /// have their longitude wrapped into `[-180, 180)` and paths crossing a pole
+    /// continue down the other side.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let jfk = LatLon::create(40.6413, -73.7781).unwrap();
+    /// let lhr = LatLon::create(51.4700, -0.4543).unwrap();
+    /// 
+    /// let bearing = jfk.initial_bearing(&lhr);
+    /// let distance = jfk.haversine(&lhr);
+    /// let dest = jfk.destination(bearing, distance);
+    /// 
+    /// assert!(dest.approx_eq(&lhr, 1e-9));
+    /// 
+    /// // Crossing the antimeridian
+    /// let coord = LatLon::create(0.0, 179.5).unwrap();
+    /// let dest = coord.destination(90.0, 111_195.0);
+    /// assert!((dest.longitude() - -179.5).abs() < 1e-3);
+    /// 
+    /// // Crossing the north pole
+    /// let coord = LatLon::create(89.0, 0.0).unwrap();
+    /// let dest = coord.destination(0.0, 222_390.0);
+    /// assert!((dest.latitude() - 89.0).abs() < 1e-3);
+    /// assert_eq!(dest.longitude(), -180.0);
+    /// ```
+    pub fn destination(&self, bearing_deg: f64, distance_m: f64) -> LatLon {
+        let delta = distance_m / EARTH_MEAN_RADIUS_M;
+        let (delta_sin, delta_cos) = delta.sin_cos();
+        let (bearing_sin, bearing_cos) = bearing_deg.to_radians().sin_cos();
+        let (lat1_sin, lat1_cos) = self.latitude.to_radians().sin_cos();
+
+        let lat2_sin = (lat1_sin * delta_cos + lat1_cos * delta_sin * bearing_cos).clamp(-1.0, 1.0);
+        let lat2 = lat2_sin.asin();
+        let dlon = (bearing_sin * delta_sin * lat1_cos).atan2(delta_cos - lat1_sin * lat2_sin);
+
+        LatLon::new(lat2.to_degrees(), wrap_longitude(self.longitude + dlon.to_degrees()))
+    }
+
+    /// Returns the point halfway between this point and `other` along the great
+    /// circle joining them, using a spherical model. The path takes the shorter
+    /// way around, so the midpoint of two points either side of the antimeridian
+    /// lies on the antimeridian. The longitude of the result is in `[-180, 180)`.
+    /// 
+    /// For exactly antipodal points the midpoint is undefined, and this point is
+    /// returned.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let jfk = LatLon::create(40.6413, -73.7781).unwrap();
+    /// let lhr = LatLon::create(51.4700, -0.4543).unwrap();
+    /// 
+    /// let mid = jfk.midpoint(&lhr);
+    /// assert!((mid.haversine(&jfk) - mid.haversine(&lhr)).abs() < 1e-6);
+    /// assert!((mid.haversine(&jfk) + mid.haversine(&lhr) - jfk.haversine(&lhr)).abs() < 1e-6);
+    /// 
+    /// // Crossing the antimeridian
+    /// let west = LatLon::create(0.0, 179.0).unwrap();
+    /// let east = LatLon::create(0.0, -179.0).unwrap();
+    /// let mid = west.midpoint(&east);
+    /// assert!(mid.latitude().abs() < 1e-9);
+    /// assert_eq!(mid.longitude(), -180.0);
+    /// ```
+    pub fn midpoint(&self, other: &LatLon) -> LatLon {
+        let (lat1_sin, lat1_cos) = self.latitude.to_radians().sin_cos();
+        let (lon1_sin, lon1_cos) = self.longitude.to_radians().sin_cos();
+        let (lat2_sin, lat2_cos) = other.latitude.to_radians().sin_cos();
+        let (lon2_sin, lon2_cos) = other.longitude.to_radians().sin_cos();
+
+        // Sum of the unit vectors; the direction is the same as their average
+        let x = lat1_cos * lon1_cos + lat2_cos * lon2_cos;
+        let y = lat1_cos * lon1_sin + lat2_cos * lon2_sin;
+        let z = lat1_sin + lat2_sin;
+
+        if x.hypot(y).hypot(z) < f64::EPSILON {
+            return *self;
+        }
+
+        let lat = z.atan2(x.hypot(y)).to_degrees();
+        let lon = y.atan2(x).to_degrees();
+
+        LatLon::new(lat, wrap_longitude(lon))
+    }
+
+    /// Returns the point a `fraction` of the way from this point to `other`
+    /// along the great circle joining them, using spherical linear interpolation
+    /// ([slerp](https://en.wikipedia.org/wiki/Slerp)). A `fraction` of `0` returns
+    /// this point and `1` returns `other`; values outside `[0, 1]` extend the path
+    /// beyond the endpoints. Like [`midpoint`](#method.midpoint), the path takes the
+    /// shorter way around, and the longitude of the result is in `[-180, 180)`.
+    /// 
+    /// For nearly antipodal points many great circles are almost as short, so
+    /// the path is very sensitive to small changes in either point. For exactly
+    /// antipodal points (or identical points) the path is undefined, and this
+    /// point is returned for any `fraction` other than `1`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let jfk = LatLon::create(40.6413, -73.7781).unwrap();
+    /// let lhr = LatLon::create(51.4700, -0.4543).unwrap();
+    /// 
+    /// assert_eq!(jfk.interpolate(&lhr, 0.0), jfk);
+    /// assert_eq!(jfk.interpolate(&lhr, 1.0), lhr);
+    /// assert!(jfk.interpolate(&lhr, 0.5).approx_eq(&jfk.midpoint(&lhr), 1e-9));
+    /// 
+    /// let point = jfk.interpolate(&lhr, 0.25);
+    /// assert!((point.haversine(&jfk) - jfk.haversine(&lhr) / 4.0).abs() < 1e-6);
+    /// 
+    /// // Crossing the antimeridian
+    /// let west = LatLon::create(0.0, 170.0).unwrap();
+    /// let east = LatLon::create(0.0, -170.0).unwrap();
+    /// assert!(west.interpolate(&east, 0.75).approx_eq(&LatLon::create(0.0, -175.0).unwrap(), 1e-9));
+    /// ```
+    #[allow(clippy::float_cmp)]
+    pub fn interpolate(&self, other: &LatLon, fraction: f64) -> LatLon {
+        if fraction == 1.0 {
+            return *other;
+        }
+
+        let (lat1_sin, lat1_cos) = self.latitude.to_radians().sin_cos();
+        let (lon1_sin, lon1_cos) = self.longitude.to_radians().sin_cos();
+        let (lat2_sin, lat2_cos) = other.latitude.to_radians().sin_cos();
+        let (lon2_sin, lon2_cos) = other.longitude.to_radians().sin_cos();
+
+        let v1 = [lat1_cos * lon1_cos, lat1_cos * lon1_sin, lat1_sin];
+        let v2 = [lat2_cos * lon2_cos, lat2_cos * lon2_sin, lat2_sin];
+
+        // Angle between the two points, from the norm of the cross product
+        // and the dot product, which is accurate for small and large angles
+        let cross = [
+            v1[1] * v2[2] - v1[2] * v2[1],
+            v1[2] * v2[0] - v1[0] * v2[2],
+            v1[0] * v2[1] - v1[1] * v2[0],
+        ];
+        let omega_sin = cross[0].hypot(cross[1]).hypot(cross[2]);
+        let omega = omega_sin.atan2(v1[0] * v2[0] + v1[1] * v2[1] + v1[2] * v2[2]);
+
+        if fraction == 0.0 || omega_sin < f64::EPSILON {
+            return *self;
+        }
+
+        let w1 = ((1.0 - fraction) * omega).sin() / omega_sin;
+        let w2 = (fraction * omega).sin() / omega_sin;
+
+        let x = w1 * v1[0] + w2 * v2[0];
+        let y = w1 * v1[1] + w2 * v2[1];
+        let z = w1 * v1[2] + w2 * v2[2];
+
+        let lat = z.atan2(x.hypot(y)).to_degrees();
+        let lon = y.atan2(x).to_degrees();
+
+        LatLon::new(lat, wrap_longitude(lon))
+    }
+
+    /// Returns `n` evenly spaced points along the great circle from this point
+    /// to `other`, including both endpoints, as given by
+    /// [`interpolate`](#method.interpolate). Useful for drawing routes, or for
+    /// densifying a line segment before projecting it so it stays close to the
+    /// great circle.
+    /// 
+    /// Returns an empty vector if `n` is `0`, and just this point if `n` is `1`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let jfk = LatLon::create(40.6413, -73.7781).unwrap();
+    /// let lhr = LatLon::create(51.4700, -0.4543).unwrap();
+    /// 
+    /// let path = jfk.sample_path(&lhr, 11);
+    /// assert_eq!(path.len(), 11);
+    /// assert_eq!(path[0], jfk);
+    /// assert_eq!(path[10], lhr);
+    /// 
+    /// let step = jfk.haversine(&lhr) / 10.0;
+    /// for pair in path.windows(2) {
+    ///     assert!((pair[0].haversine(&pair[1]) - step).abs() < 1e-6);
+    /// }
+    /// 
+    /// assert!(jfk.sample_path(&lhr, 0).is_empty());
+    /// assert_eq!(jfk.sample_path(&lhr, 1), [jfk]);
+    /// ```
+    #[allow(clippy::cast_precision_loss)]
+    pub fn sample_path(&self, other: &LatLon, n: usize) -> Vec<LatLon> {
+        match n {
+            0 => Vec::new(),
+            1 => alloc::vec![*self],
+            _ => (0..n)
+                .map(|i| self.interpolate(other, i as f64 / (n - 1) as f64))
+                .collect(),
+        }
+    }
+
+    /// Returns the path through `points` with points added along the great
+    /// circle between each pair of consecutive points (see
+    /// [`sample_path`](#method.sample_path)), so that no segment is longer than
+    /// `max_segment_m` meters. Projecting a sparse path to e.g. UTM and drawing
+    /// straight lines between the points strays from the true path, which this
+    /// keeps within a tolerance set by the segment length.
+    /// 
+    /// Segment lengths are measured like [`haversine`](#method.haversine), on a
+    /// sphere. Each segment is split into equal parts, and the original points
+    /// are kept.
+    /// 
+    /// The densified path can't have more than 1,000,000 points; the number of
+    /// points is worked out before any are added, so a very short
+    /// `max_segment_m` fails quickly instead of using up memory.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidCoord`] if `max_segment_m` is not positive and
+    /// finite.
+    /// Returns [`Error::TooManyItems`] if the densified path would have more
+    /// than 1,000,000 points.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{Error, LatLon};
+    /// 
+    /// let path = [(40.6413, -73.7781), (51.4700, -0.4543), (48.3538, 11.7861)]
+    ///     .map(|(lat, lon)| LatLon::create(lat, lon).unwrap());
+    /// 
+    /// let dense = LatLon::densify_path(&path, 100_000.0).unwrap();
+    /// 
+    /// assert_eq!(dense.first(), Some(&path[0]));
+    /// assert_eq!(dense.last(), Some(&path[2]));
+    /// assert!(dense.contains(&path[1]));
+    /// assert!(dense.windows(2).all(|pair| pair[0].haversine(&pair[1]) <= 100_000.0));
+    /// 
+    /// // 5,540 km and 941 km
+    /// assert_eq!(dense.len(), 56 + 10 + 1);
+    /// 
+    /// assert!(matches!(LatLon::densify_path(&path, 0.0), Err(Error::InvalidCoord(_))));
+    /// 
+    /// // A 1 mm segment length would need billions of points
+    /// assert!(matches!(LatLon::densify_path(&path, 1e-3), Err(Error::TooManyItems { .. })));
+    /// ```
+    pub fn densify_path(points: &[LatLon], max_segment_m: f64) -> Result<Vec<LatLon>, Error> {
+        if !(max_segment_m.is_finite() && max_segment_m > 0.0) {
+            return Err(Error::InvalidCoord(format!("Maximum segment length {max_segment_m} must be positive")));
+        }
+
+        let segments: Vec<f64> = points.windows(2)
+            .map(|pair| (pair[0].haversine(&pair[1]) / max_segment_m).ceil().max(1.0))
+            .collect();
+
+        #[allow(clippy::cast_precision_loss)]
+        if segments.iter().sum::<f64>() + 1.0 > MAX_DENSIFY_POINTS as f64 {
+            return Err(Error::TooManyItems {
+                limit: MAX_DENSIFY_POINTS,
+                msg: format!("Densifying the path to {max_segment_m} m segments gives too many points"),
+            });
+        }
+
+        let mut path = Vec::new();
+
+        for (pair, &n) in points.windows(2).zip(&segments) {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let mut segment = pair[0].sample_path(&pair[1], n as usize + 1);
+            segment.pop();
+            path.extend(segment);
+        }
+
+        path.extend(points.last());
+
+        Ok(path)
+    }
+
+    /// Returns the signed distance in meters from this point to the great circle
+    /// through `start` and `end`, i.e. how far it is off the track. The distance
+    /// is positive if the point is to the right of the track (travelling from
+    /// `start` towards `end`) and negative if it is to the left.
+    /// 
+    /// Uses the same spherical model and mean radius as [`haversine`](#method.haversine),
+    /// so it can be off by up to ~0.5%. If `start` and `end` are the same point,
+    /// the track is taken to run due north.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// // A track along the equator, heading east
+    /// let start = LatLon::create(0.0, 0.0).unwrap();
+    /// let end = LatLon::create(0.0, 10.0).unwrap();
+    /// 
+    /// // One degree north, to the left of the track
+    /// let point = LatLon::create(1.0, 5.0).unwrap();
+    /// let distance = point.cross_track_distance(&start, &end);
+    /// assert!((distance + point.haversine(&LatLon::create(0.0, 5.0).unwrap())).abs() < 1e-6);
+    /// 
+    /// // South is to the right
+    /// let point = LatLon::create(-1.0, 20.0).unwrap();
+    /// assert!(point.cross_track_distance(&start, &end) > 0.0);
+    /// ```
+    pub fn cross_track_distance(&self, start: &LatLon, end: &LatLon) -> f64 {
+        let delta13 = start.haversine(self) / EARTH_MEAN_RADIUS_M;
+        let dtheta = (start.initial_bearing(self) - start.initial_bearing(end)).to_radians();
+
+        (delta13.sin() * dtheta.sin()).clamp(-1.0, 1.0).asin() * EARTH_MEAN_RADIUS_M
+    }
+
+    /// Returns the signed distance in meters from `start` along the great circle
+    /// through `start` and `end` to the point on it closest to this point. The
+    /// distance is negative if the closest point is behind `start`, i.e. in the
+    /// direction away from `end`. Together with
+    /// [`cross_track_distance`](#method.cross_track_distance), this locates the
+    /// point relative to the track.
+    /// 
+    /// Uses the same spherical model and mean radius as [`haversine`](#method.haversine).
+    /// If `start` and `end` are the same point, the track is taken to run due
+    /// north.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let start = LatLon::create(0.0, 0.0).unwrap();
+    /// let end = LatLon::create(0.0, 10.0).unwrap();
+    /// 
+    /// let point = LatLon::create(1.0, 5.0).unwrap();
+    /// let distance = point.along_track_distance(&start, &end);
+    /// assert!((distance - start.haversine(&LatLon::create(0.0, 5.0).unwrap())).abs() < 1e-6);
+    /// 
+    /// // Behind the start of the track
+    /// let point = LatLon::create(1.0, -5.0).unwrap();
+    /// assert!(point.along_track_distance(&start, &end) < 0.0);
+    /// ```
+    pub fn along_track_distance(&self, start: &LatLon, end: &LatLon) -> f64 {
+        let delta13 = start.haversine(self) / EARTH_MEAN_RADIUS_M;
+        let dtheta = (start.initial_bearing(self) - start.initial_bearing(end)).to_radians();
+
+        // From the right spherical triangle formed with the closest point on the track
+        (delta13.sin() * dtheta.cos()).atan2(delta13.cos()) * EARTH_MEAN_RADIUS_M
+    }
+
+    /// Returns the distance in meters between two [`LatLon`] points along the
+    /// WGS84 ellipsoid, using [Vincenty's inverse formula](https://en.wikipedia.org/wiki/Vincenty%27s_formulae).
+    /// This is accurate to within a millimeter, whereas [`haversine`](#method.haversine)
+    /// can be off by up to ~0.5% because it treats the Earth as a sphere.
+    /// 
+    /// Vincenty's formula fails to converge for some nearly antipodal points. In
+    /// that case the iteration is capped and the last estimate is returned, which
+    /// should not be relied upon (it can be off by tens of kilometers).
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// // Flinders Peak to Buninyong, from Vincenty's original paper
+    /// let flinders_peak = LatLon::create(-37.95103342, 144.42486789).unwrap();
+    /// let buninyong = LatLon::create(-37.65282114, 143.92649554).unwrap();
+    /// 
+    /// let distance = flinders_peak.geodesic_distance(&buninyong);
+    /// assert!((distance - 54972.271).abs() < 1e-3);
+    /// 
+    /// // The spherical approximation is noticeably off
+    /// assert!((flinders_peak.haversine(&buninyong) - distance).abs() > 10.0);
+    /// ```
+    pub fn geodesic_distance(&self, other: &LatLon) -> f64 {
+        geodesic::inverse(Ellipsoid::WGS84, self.latitude, self.longitude, other.latitude, other.longitude).0
+    }
+
+    /// Returns the distance in meters along the meridian from the equator to the
+    /// point's latitude on the WGS84 ellipsoid, negative in the southern
+    /// hemisphere. This is the northing of the point on a transverse Mercator
+    /// projection centered on its meridian with a scale factor of `1`, computed
+    /// with the same series as UTM (accurate to a few nanometers).
+    /// 
+    /// The difference between two points' arcs is the north-south distance
+    /// between their latitudes along a meridian.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let coord = LatLon::create(45.0, -73.985278).unwrap();
+    /// assert!((coord.meridian_arc_to_equator() - 4_984_944.378).abs() < 1e-3);
+    /// 
+    /// // The quarter meridian
+    /// let pole = LatLon::create(90.0, 0.0).unwrap();
+    /// assert!((pole.meridian_arc_to_equator() - 10_001_965.729).abs() < 1e-3);
+    /// 
+    /// let south = LatLon::create(-45.0, 0.0).unwrap();
+    /// assert_eq!(south.meridian_arc_to_equator(), -coord.meridian_arc_to_equator());
+    /// ```
+    pub fn meridian_arc_to_equator(&self) -> f64 {
+        // The UTM northing on the central meridian, without the UTM scale factor
+        wgs84::with_projections(|tm, _| tm.from_latlon(0.0, self.latitude, 0.0).1) / UTM_K0
+    }
+
+    /// Returns the [rectifying latitude](https://en.wikipedia.org/wiki/Latitude#Rectifying_latitude)
+    /// in degrees, the latitude on a sphere with the same meridian length as the
+    /// WGS84 ellipsoid that is the same distance from the equator along the
+    /// meridian. This is [`meridian_arc_to_equator`](#method.meridian_arc_to_equator)
+    /// as a fraction of the quarter meridian, scaled to `90` degrees.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let coord = LatLon::create(45.0, 0.0).unwrap();
+    /// assert!((coord.rectifying_latitude() - 44.855_682).abs() < 1e-6);
+    /// 
+    /// assert_eq!(LatLon::create(0.0, 0.0).unwrap().rectifying_latitude(), 0.0);
+    /// assert_eq!(LatLon::create(90.0, 0.0).unwrap().rectifying_latitude(), 90.0);
+    /// ```
+    pub fn rectifying_latitude(&self) -> f64 {
+        // The UTM scale factor cancels out in the ratio
+        let (arc, quarter_meridian) = wgs84::with_projections(|tm, _| {
+            (tm.from_latlon(0.0, self.latitude, 0.0).1, tm.from_latlon(0.0, f64::from(dms::QD), 0.0).1)
+        });
+
+        f64::from(dms::QD) * arc / quarter_meridian
+    }
+
+    /// Returns the point halfway between two [`LatLon`] points along the geodesic
+    /// (the shortest path) on the WGS84 ellipsoid. Uses [Vincenty's inverse formula](https://en.wikipedia.org/wiki/Vincenty%27s_formulae)
+    /// for the distance and initial bearing, then the direct formula to travel
+    /// half the distance. This is more accurate than the spherical
+    /// [`midpoint`](#method.midpoint), which can be kilometers off for long
+    /// distances.
+    /// 
+    /// Like [`geodesic_distance`](#method.geodesic_distance), the result should
+    /// not be relied upon for nearly antipodal points.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let jfk = LatLon::create(40.6413, -73.7781).unwrap();
+    /// let lhr = LatLon::create(51.4700, -0.4543).unwrap();
+    /// 
+    /// let mid = jfk.geodesic_midpoint(&lhr);
+    /// let distance = jfk.geodesic_distance(&lhr);
+    /// assert!((mid.geodesic_distance(&jfk) - distance / 2.0).abs() < 1e-3);
+    /// assert!((mid.geodesic_distance(&lhr) - distance / 2.0).abs() < 1e-3);
+    /// 
+    /// // The spherical midpoint is several kilometers away
+    /// assert!(mid.geodesic_distance(&jfk.midpoint(&lhr)) > 1000.0);
+    /// ```
+    pub fn geodesic_midpoint(&self, other: &LatLon) -> LatLon {
+        let ellipsoid = Ellipsoid::WGS84;
+
+        let (distance, azi1, _) = geodesic::inverse(ellipsoid, self.latitude, self.longitude, other.latitude, other.longitude);
+        let (lat, lon, _) = geodesic::direct(ellipsoid, self.latitude, self.longitude, azi1, distance / 2.0);
+
+        LatLon::new(lat, wrap_longitude(lon))
+    }
+
+    /// Returns the area in square meters of the polygon with the given vertices,
+    /// joined by great circles on a sphere of the
+    /// [mean radius of the Earth](https://en.wikipedia.org/wiki/Earth_radius#Arithmetic_mean_radius),
+    /// like [`haversine`](#method.haversine).
+    /// 
+    /// The area is found from the
+    /// [spherical excess](https://en.wikipedia.org/wiki/Spherical_trigonometry#Area_and_spherical_excess)
+    /// of the polygon. The vertices may be given in either winding direction, and
+    /// the ring is closed implicitly, so the last point doesn't need to repeat the
+    /// first. Since a ring divides the sphere in two, the smaller of the two areas
+    /// is returned. Fewer than three points have no area.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// // A 1° square on the equator, about 12,364 km²
+    /// let square = [(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0)]
+    ///     .map(|(lat, lon)| LatLon::create(lat, lon).unwrap());
+    /// 
+    /// let area = LatLon::polygon_area(&square);
+    /// assert!((area / 1e6 - 12_364.0).abs() < 1.0);
+    /// 
+    /// // Winding direction and explicit closing don't matter
+    /// let mut reversed = square.to_vec();
+    /// reversed.reverse();
+    /// reversed.push(reversed[0]);
+    /// assert!((LatLon::polygon_area(&reversed) - area).abs() < 1e-6);
+    /// ```
+    pub fn polygon_area(points: &[LatLon]) -> f64 {
+        let sphere = 4.0 * core::f64::consts::PI;
+
+        let mut excess = 0.0;
+        let mut winding = 0.0;
+
+        for (p1, p2) in points.iter().zip(points.iter().cycle().skip(1)) {
+            let dlon = p1.longitude.ang_diff(p2.longitude);
+            let t1 = (p1.latitude.to_radians() / 2.0).tan();
+            let t2 = (p2.latitude.to_radians() / 2.0).tan();
+
+            // Excess of the triangle formed by the edge and the north pole
+            excess += 2.0 * ((dlon.to_radians() / 2.0).tan() * (t1 + t2) / (1.0 + t1 * t2)).atan();
+            winding += dlon;
+        }
+
+        // A ring around a pole measures the side containing that pole from a hemisphere
+        let area = (winding.abs() > f64::from(dms::HD)).ternary(sphere / 2.0 - excess.abs(), excess.abs());
+
+        area.min(sphere - area) * EARTH_MEAN_RADIUS_M.powi(2)
+    }
+
+    /// Returns the smallest latitude/longitude rectangle containing all of the
+    /// points, as its `(southwest, northeast)` corners, or [`None`] if `points`
+    /// is empty.
+    /// 
+    /// Longitudes wrap around, so a set of points on both sides of the
+    /// antimeridian is spanned by the rectangle crossing it rather than the one
+    /// going the long way round the globe. The rectangle is placed opposite the
+    /// largest gap between the points' longitudes, and in that case the
+    /// southwest corner has a larger longitude than the northeast corner (as
+    /// taken by [`Mgrs::squares_in_bbox`]).
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let points = [(40.7, -74.0), (51.5, -0.1), (48.9, 2.4)]
+    ///     .map(|(lat, lon)| LatLon::create(lat, lon).unwrap());
+    /// 
+    /// let (sw, ne) = LatLon::bounding_box(&points).unwrap();
+    /// assert_eq!(sw, LatLon::create(40.7, -74.0).unwrap());
+    /// assert_eq!(ne, LatLon::create(51.5, 2.4).unwrap());
+    /// 
+    /// // Fiji, on both sides of the antimeridian
+    /// let points = [(-16.5, 179.5), (-18.1, 178.4), (-17.8, -179.9)]
+    ///     .map(|(lat, lon)| LatLon::create(lat, lon).unwrap());
+    /// 
+    /// let (sw, ne) = LatLon::bounding_box(&points).unwrap();
+    /// assert_eq!(sw, LatLon::create(-18.1, 178.4).unwrap());
+    /// assert_eq!(ne, LatLon::create(-16.5, -179.9).unwrap());
+    /// 
+    /// assert!(LatLon::bounding_box(&[]).is_none());
+    /// ```
+    pub fn bounding_box(points: &[LatLon]) -> Option<(LatLon, LatLon)> {
+        let mut lons: Vec<f64> = points.iter().map(|point| point.longitude).collect();
+        lons.sort_by(f64::total_cmp);
+
+        let (&first, &last) = (lons.first()?, lons.last()?);
+
+        // Without crossing the antimeridian, the gap is the one around the back
+        // of the globe, which is kept on ties
+        let mut gap = first + 2.0 * f64::from(dms::HD) - last;
+        let (mut west, mut east) = (first, last);
+
+        for pair in lons.windows(2) {
+            if pair[1] - pair[0] > gap {
+                gap = pair[1] - pair[0];
+                (west, east) = (pair[1], pair[0]);
+            }
+        }
+
+        let south = points.iter().map(|point| point.latitude).fold(f64::INFINITY, f64::min);
+        let north = points.iter().map(|point| point.latitude).fold(f64::NEG_INFINITY, f64::max);
+
+        Some((LatLon::new(south, west), LatLon::new(north, east)))
+    }
+
+    /// Converts the point at `height_m` meters above the WGS84 ellipsoid to
+    /// [Earth-centered, Earth-fixed](https://en.wikipedia.org/wiki/Earth-centered,_Earth-fixed_coordinate_system)
+    /// (geocentric) Cartesian coordinates `(x, y, z)` in meters. The `x` axis
+    /// points to latitude and longitude `0`, the `y` axis to longitude `90`, and
+    /// the `z` axis to the north pole.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let (x, y, z) = LatLon::ORIGIN.to_ecef(0.0);
+    /// assert_eq!((x, y, z), (6_378_137.0, 0.0, 0.0));
+    /// 
+    /// // The semi-minor axis
+    /// let (x, y, z) = LatLon::create(90.0, 0.0).unwrap().to_ecef(0.0);
+    /// assert!(x.abs() < 1e-9 && y.abs() < 1e-9);
+    /// assert!((z - 6_356_752.314245).abs() < 1e-6);
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// let (x, y, z) = coord.to_ecef(443.0);
+    /// assert!((x - 1_335_063.85).abs() < 0.01);
+    /// assert!((y - -4_651_409.88).abs() < 0.01);
+    /// assert!((z - 4_141_579.55).abs() < 0.01);
+    /// ```
+    pub fn to_ecef(&self, height_m: f64) -> (f64, f64, f64) {
+        self.to_ecef_with_ellipsoid(height_m, Ellipsoid::WGS84)
+    }
+
+    /// Same as [`to_ecef`](#method.to_ecef), treating the point as being on
+    /// `ellipsoid` instead of WGS84
+    pub(crate) fn to_ecef_with_ellipsoid(self, height_m: f64, ellipsoid: Ellipsoid) -> (f64, f64, f64) {
+        let e2 = ellipsoid.e2();
+
+        let (lat_sin, lat_cos) = self.latitude.to_radians().sin_cos();
+        let (lon_sin, lon_cos) = self.longitude.to_radians().sin_cos();
+
+        // Radius of curvature in the prime vertical
+        let n = ellipsoid.a / (1.0 - e2 * lat_sin.powi(2)).sqrt();
+
+        (
+            (n + height_m) * lat_cos * lon_cos,
+            (n + height_m) * lat_cos * lon_sin,
+            (n * (1.0 - e2) + height_m) * lat_sin,
+        )
+    }
+
+    /// Converts Earth-centered, Earth-fixed coordinates `(x, y, z)` in meters to
+    /// a point on the WGS84 ellipsoid and the height above it in meters, as
+    /// `(point, height_m)`. The inverse of [`to_ecef`](#method.to_ecef).
+    /// 
+    /// The latitude is found with [Bowring's method](https://en.wikipedia.org/wiki/Geographic_coordinate_conversion#The_application_of_Bowring's_method),
+    /// iterated to within a nanometer for points near the surface of the Earth.
+    /// On the `z` axis the longitude is `0`. Points within about 43 km of the
+    /// center of the Earth have no unique closest point on the ellipsoid, and
+    /// give a latitude and height that should not be relied upon.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let (coord, height) = LatLon::from_ecef(6_378_137.0, 0.0, 0.0);
+    /// assert_eq!(coord, LatLon::ORIGIN);
+    /// assert_eq!(height, 0.0);
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// let (x, y, z) = coord.to_ecef(443.0);
+    /// 
+    /// let (back, height) = LatLon::from_ecef(x, y, z);
+    /// assert!(back.approx_eq(&coord, 1e-12));
+    /// assert!((height - 443.0).abs() < 1e-6);
+    /// ```
+    pub fn from_ecef(x: f64, y: f64, z: f64) -> (LatLon, f64) {
+        Self::from_ecef_with_ellipsoid(x, y, z, Ellipsoid::WGS84)
+    }
+
+    /// Same as [`from_ecef`](#method.from_ecef), finding the closest point on
+    /// `ellipsoid` instead of WGS84
+    pub(crate) fn from_ecef_with_ellipsoid(x: f64, y: f64, z: f64, ellipsoid: Ellipsoid) -> (LatLon, f64) {
+        /// Enough for nanometer accuracy from the ground to beyond the Moon
+        const ITERATIONS: usize = 3;
+
+        let e2 = ellipsoid.e2();
+        let f1 = 1.0 - ellipsoid.f;
+        let semi_minor = ellipsoid.a * f1;
+        let ep2 = e2 / (1.0 - e2);
+
+        let p = x.hypot(y);
+        let lon = y.atan2(x).to_degrees();
+
+        // Starting from the parametric latitude of the point
+        let mut beta = z.atan2(f1 * p);
+        let mut lat = 0.0;
+
+        for _ in 0..ITERATIONS {
+            let (beta_sin, beta_cos) = beta.sin_cos();
+
+            // Inside the evolute of the ellipse, the denominator can go negative
+            lat = (z + ep2 * semi_minor * beta_sin.powi(3)).atan2((p - e2 * ellipsoid.a * beta_cos.powi(3)).max(0.0));
+            beta = (f1 * lat.sin()).atan2(lat.cos());
+        }
+
+        let (lat_sin, lat_cos) = lat.sin_cos();
+        let height = p * lat_cos + z * lat_sin - ellipsoid.a * (1.0 - e2 * lat_sin.powi(2)).sqrt();
+
+        (LatLon::new(lat.to_degrees(), wrap_longitude(lon)), height)
+    }
+
+    /// Converts from [`UtmUps`] to [`LatLon`]
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, UtmUps};
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// let coord_utm = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
+    /// 
+    /// let converted = LatLon::from_utmups(&coord_utm);
+    /// 
+    /// // Check if the converted coordinate is accurate to 6 decimals (same as reference)
+    /// assert!(converted.approx_eq(&coord, 1e-6));
+    /// ```
+    pub fn from_utmups(value: &UtmUps) -> LatLon {
+        value.to_latlon()
+    }
+    
+    /// Converts from [`LatLon`] to [`UtmUps`]
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, UtmUps};
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// let coord_utm = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
+    /// 
+    /// let converted = coord.to_utmups();
+    /// 
+    /// assert_eq!(converted.zone(), coord_utm.zone());
+    /// assert_eq!(converted.is_north(), coord_utm.is_north());
+    /// // Check if the converted coordinate is accurate to 3 decimals (same as reference)
+    /// assert!(converted.approx_eq(&coord_utm, 1e-3));
+    /// ```
+    pub fn to_utmups(&self) -> UtmUps {
+        UtmUps::from_latlon(self)
+    }
+
+    /// Returns the point with its latitude clamped to the UTM latitude range
+    /// `[-80, 84)`, so that [`to_utmups`](#method.to_utmups) always gives a UTM
+    /// point rather than UPS. The longitude is unchanged. This is an opt-in
+    /// convenience for applications that want UTM everywhere; conversions never
+    /// clamp on their own.
+    /// 
+    /// Clamping moves points in the polar regions to the edge of the UTM range,
+    /// so the result is a different location: up to about 670 km away in the
+    /// north and 1,110 km in the south. Only use it when that distortion is
+    /// acceptable, e.g. for display. Use [`UtmUps::try_from_latlon_utm`] to
+    /// get an error for polar points instead.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// // Points within the UTM range are unchanged
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// assert_eq!(coord.clamp_to_utm_band(), coord);
+    /// 
+    /// let coord = LatLon::create(-85.0, 10.0).unwrap().clamp_to_utm_band();
+    /// assert_eq!(coord, LatLon::create(-80.0, 10.0).unwrap());
+    /// assert_ne!(coord.to_utmups().zone(), 0);
+    /// 
+    /// // The upper limit is exclusive, since 84 is UPS
+    /// let coord = LatLon::create(90.0, 10.0).unwrap().clamp_to_utm_band();
+    /// assert!(coord.latitude() < 84.0);
+    /// assert!((coord.latitude() - 84.0).abs() < 1e-12);
+    /// assert_ne!(coord.to_utmups().zone(), 0);
+    /// ```
+    pub fn clamp_to_utm_band(&self) -> LatLon {
+        LatLon::new(self.latitude.clamp(-80.0, 84_f64.next_down()), self.longitude)
+    }
+
+    /// Converts the point to [`UtmUps`] and back, returning the distance in
+    /// meters between the original point and the result, as measured by
+    /// [`haversine`](#method.haversine). Useful for checking the numerical
+    /// accuracy of the conversions in a region of interest.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// assert!(coord.roundtrip_error_utmups() < 1e-6);
+    /// 
+    /// // Near the pole, in UPS
+    /// let coord = LatLon::create(89.99, 45.0).unwrap();
+    /// assert!(coord.roundtrip_error_utmups() < 1e-6);
+    /// ```
+    pub fn roundtrip_error_utmups(&self) -> f64 {
+        self.haversine(&self.to_utmups().to_latlon())
+    }
+
+    /// Converts from [`Mgrs`] to [`LatLon`]
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, Mgrs};
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// let coord_mgrs = Mgrs::parse_str("18TWL856641113154").unwrap();
+    /// 
+    /// let converted = LatLon::from_mgrs(&coord_mgrs);
+    /// 
+    /// // Check if the converted coordinate is accurate to 6 decimals (same as reference)
+    /// assert!(converted.approx_eq(&coord, 1e-6));
+    /// ```
+    pub fn from_mgrs(value: &Mgrs) -> LatLon {
+        value.to_latlon()
+    }
+
+    /// Converts from [`LatLon`] to [`Mgrs`]
+    /// 
+    /// `precision` is clamped to the range `[-1, 11]`, see [`Mgrs::from_latlon`].
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, Mgrs};
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// 
+    /// let converted = coord.to_mgrs(6);
+    /// 
+    /// assert_eq!(converted.to_string(), "18TWL856641113154");
+    /// ```
+    pub fn to_mgrs(&self, precision: i32) -> Mgrs {
+        Mgrs::from_latlon(self, precision)
+    }
+
+    /// Converts from [`LatLon`] to [`Mgrs`] with precision `5`, i.e. 1 m squares
+    /// (five digits each of easting and northing). This is the finest precision
+    /// in the MGRS standard (see [`Mgrs::max_meaningful_precision`]) and the
+    /// usual one in military use.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// 
+    /// let converted = coord.to_mgrs_default();
+    /// 
+    /// assert_eq!(converted, coord.to_mgrs(5));
+    /// assert_eq!(converted.to_string(), "18TWL8566411315");
+    /// ```
+    pub fn to_mgrs_default(&self) -> Mgrs {
+        Mgrs::from_latlon(self, MAX_MEANINGFUL_PRECISION)
+    }
+
+    /// Snaps the point to the MGRS grid square it falls in at the given
+    /// precision, returning either the center of the square (`center = true`) or
+    /// its lower-left (southwest) corner. All points in the same square snap to
+    /// the same coordinate, which is useful for bucketing points into MGRS cells.
+    /// 
+    /// A precision of `0` or less snaps to the 100 km square.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, Mgrs};
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// 
+    /// // 1 km squares
+    /// let corner = coord.snap_to_mgrs(2, false);
+    /// let center = coord.snap_to_mgrs(2, true);
+    /// 
+    /// let corner_utm = corner.to_utmups();
+    /// assert!((corner_utm.easting() - 585000.0).abs() < 1e-6);
+    /// assert!((corner_utm.northing() - 4511000.0).abs() < 1e-6);
+    /// 
+    /// let center_utm = center.to_utmups();
+    /// assert!((center_utm.easting() - 585500.0).abs() < 1e-6);
+    /// assert!((center_utm.northing() - 4511500.0).abs() < 1e-6);
+    /// 
+    /// // Nearby points in the same square snap to the same place
+    /// let nearby = LatLon::create(40.7490, -73.9860).unwrap();
+    /// assert_eq!(nearby.snap_to_mgrs(2, true), center);
+    /// ```
+    pub fn snap_to_mgrs(&self, precision: i32, center: bool) -> LatLon {
+        let mgrs = self.to_mgrs(precision);
+        let (x0, y0, cell) = mgrs.cell_origin();
+        let offset = center.ternary(cell / 2.0, 0.0);
+
+        UtmUps::new(mgrs.zone(), mgrs.is_north(), x0 + offset, y0 + offset).to_latlon()
+    }
+
+    /// Converts a slice of [`LatLon`] to [`UtmUps`]. Gives the same results as
+    /// calling [`to_utmups`](#method.to_utmups) on each point, but the projection
+    /// series are only set up once for the whole slice rather than once per point,
+    /// so the setup cost is amortized over the batch.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let points = [
+    ///     LatLon::create(40.748333, -73.985278).unwrap(),
+    ///     LatLon::create(-33.856784, 151.215297).unwrap(),
+    ///     LatLon::create(89.5, 0.0).unwrap(),
+    /// ];
+    /// 
+    /// let converted = LatLon::batch_to_utmups(&points);
+    /// 
+    /// assert_eq!(converted.len(), 3);
+    /// assert_eq!(converted[0].zone(), 18);
+    /// assert_eq!(converted[1].zone(), 56);
+    /// // UPS
+    /// assert_eq!(converted[2].zone(), 0);
+    /// ```
+    pub fn batch_to_utmups(points: &[LatLon]) -> Vec<UtmUps> {
+        wgs84::with_projections(|tm, ps| {
+            points
+                .iter()
+                .map(|point| UtmUps::from_latlon_with_projections(point, tm, ps))
+                .collect()
+        })
+    }
+
+    /// Converts a slice of [`LatLon`] to [`Mgrs`] with the given precision. See
+    /// [`batch_to_utmups`](#method.batch_to_utmups).
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let points = [
+    ///     LatLon::create(40.748333, -73.985278).unwrap(),
+    ///     LatLon::create(40.689247, -74.044502).unwrap(),
+    /// ];
+    /// 
+    /// let converted = LatLon::batch_to_mgrs(&points, 6);
+    /// 
+    /// assert_eq!(converted[0].to_string(), "18TWL856641113154");
+    /// assert_eq!(converted[0].to_string(), points[0].to_mgrs(6).to_string());
+    /// assert_eq!(converted[1].to_string(), points[1].to_mgrs(6).to_string());
+    /// ```
+    pub fn batch_to_mgrs(points: &[LatLon], precision: i32) -> Vec<Mgrs> {
+        Self::batch_to_utmups(points)
+            .iter()
+            .map(|utm| utm.to_mgrs(precision))
+            .collect()
+    }
+}
+
+/// Wraps a longitude in degrees to the range `[-180, 180)` accepted by [`LatLon::create`]
+pub(crate) fn wrap_longitude(lon: f64) -> f64 {
+    let lon = lon.ang_normalize();
+
+    if lon >= f64::from(dms::HD) {
+        lon - f64::from(dms::TD)
+    } else {
+        lon
+    }
+}
+
+/// Returns the haversine distance between two points given in degrees, on a
+/// sphere of radius `radius_m`, with the cosines of their latitudes already
+/// taken
+fn haversine_from_cos(lat1: f64, lon1: f64, cos1: f64, lat2: f64, lon2: f64, cos2: f64, radius_m: f64) -> f64 {
+    2.0 * radius_m * (
+        ((lat2 - lat1).to_radians() / 2.0).sin().powi(2) +
+        cos1 * cos2 *
+        ((lon2 - lon1).to_radians() / 2.0).sin().powi(2)
+    ).sqrt().asin()
+}
+
+/// Normalizes a bearing in degrees to the range `[0, 360)`
+fn normalize_bearing(bearing: f64) -> f64 {
+    let bearing = bearing.ang_normalize();
+
+    if bearing < 0.0 {
+        let bearing = bearing + f64::from(dms::TD);
+        // Tiny negative bearings round up to 360
+        (bearing < f64::from(dms::TD)).ternary(bearing, 0.0)
+    } else {
+        // Also turns -0 into 0
+        bearing + 0.0
+    }
+}
+
+/// Parses a latitude/longitude pair, validating it with [`LatLon::create`].
+/// Accepted forms include:
+/// 
+/// * `40.748333 -73.985278`
+/// * `40.748333,-73.985278`
+/// * `40°44'54"N 73°59'7"W`
+/// * `40.7483N 73.9853W`, as common in aviation and marine data
+/// 
+/// When hemisphere designators (`N`/`S`/`E`/`W`) are used, the pair may be given
+/// in either order. Otherwise latitude must come first. An angle with a
+/// hemisphere designator can't also have a sign, so e.g. `-40N` is rejected.
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::LatLon;
+/// 
+/// let coord: LatLon = "40.748333 -73.985278".parse().unwrap();
+/// assert_eq!(coord.latitude(), 40.748333);
+/// assert_eq!(coord.longitude(), -73.985278);
+/// 
+/// let coord: LatLon = "40.748333,-73.985278".parse().unwrap();
+/// assert_eq!(coord.latitude(), 40.748333);
+/// 
+/// let coord: LatLon = "40°44'54\"N 73°59'7\"W".parse().unwrap();
+/// assert!((coord.latitude() - 40.748333).abs() < 1e-6);
+/// assert!((coord.longitude() - -73.985278).abs() < 1e-6);
+/// 
+/// let coord: LatLon = "40.7483N 73.9853W".parse().unwrap();
+/// assert_eq!(coord, LatLon::create(40.7483, -73.9853).unwrap());
+/// 
+/// assert!("40.748333".parse::<LatLon>().is_err());
+/// assert!("100.0 0.0".parse::<LatLon>().is_err());
+/// assert!("-40.7483N 73.9853W".parse::<LatLon>().is_err());
+/// ```
+impl FromStr for LatLon {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (lat, lon) = dms::decode_lat_lon(s)?;
+
+        LatLon::create(lat, lon)
+    }
+}
+
+/// Returns [`LatLon::ORIGIN`], the point at latitude and longitude `0`.
+/// 
+/// Since `(0, 0)` is a valid coordinate, a default [`LatLon`] is
+/// indistinguishable from a real point there. Use an [`Option<LatLon>`] for
+/// values that may be unset.
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::LatLon;
+/// 
+/// #[derive(Default)]
+/// struct Track {
+///     start: LatLon,
+///     points: usize,
+/// }
+/// 
+/// let track = Track::default();
+/// assert_eq!(track.start, LatLon::ORIGIN);
+/// ```
+impl Default for LatLon {
+    fn default() -> Self {
+        LatLon::ORIGIN
+    }
+}
+
+/// Converts from [`UtmUps`] to [`LatLon`]. Equivalent to [`UtmUps::to_latlon`].
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::{LatLon, UtmUps};
+/// 
+/// let coord = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
+/// let latlon = LatLon::from(&coord);
+/// 
+/// assert!((latlon.latitude() - 40.748333).abs() < 1e-6);
+/// assert!((latlon.longitude() - -73.985278).abs() < 1e-6);
+/// ```
+impl From<&UtmUps> for LatLon {
+    fn from(value: &UtmUps) -> Self {
+        value.to_latlon()
     }
 }
 
 impl Display for LatLon {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let mut buf = ryu::Buffer::new();
         let lat = buf.format(self.latitude);
         let mut buf = ryu::Buffer::new();
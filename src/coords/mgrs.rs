@@ -1,9 +1,12 @@
-use std::{fmt::Display, str::FromStr};
+use alloc::{format, string::{String, ToString}, vec::Vec};
+use core::{fmt::Display, str::FromStr};
+
+#[cfg(not(feature = "std"))]
+use num::traits::Float;
 
-use lazy_static::lazy_static;
 use num::Integer;
 
-use crate::{Error, utm::{zonespec::{MINUTMZONE, MAXUTMZONE, UPS, self}, UtmUps}, utility::{dms, GeoMath}, ThisOrThat, latlon::LatLon};
+use crate::{Error, utm::{self, zonespec::{MINUTMZONE, MAXUTMZONE, UPS, self}, UtmUps}, utility::{dms, GeoMath}, ThisOrThat, latlon::{LatLon, EARTH_MEAN_RADIUS_M}, ellipsoid::Ellipsoid, projections::{transverse_mercator::TransverseMercator, polar_stereographic::PolarStereographic}};
 
 const HEMISPHERES: &str = "SN";
 const UTMCOLS: &[&str] = &["ABCDEFGH", "JKLMNPQR", "STUVWXYZ"];
@@ -29,51 +32,148 @@ pub(crate) const UPSEASTING: i32= 20;
 pub(crate) const UTMEASTING: i32= 5;
 pub(crate) const UTM_N_SHIFT: i32= (MAXUTM_S_ROW - MINUTM_N_ROW) * TILE;
 
-const MIN_EASTING: [i32; 4] = [
-    MINUPS_S_IND,
-    MINUPS_N_IND,
-    MINUTMCOL,
-    MINUTMCOL,
-];
-
-const MAX_EASTING: [i32; 4] = [
-    MAXUPS_S_IND,
-    MAXUPS_N_IND,
-    MAXUTMCOL,
-    MAXUTMCOL,
-];
-
-const MIN_NORTHING: [i32; 4] = [
-    MINUPS_S_IND,
-    MINUPS_N_IND,
-    MINUTM_S_ROW,
-    MINUTM_S_ROW - MAXUTM_S_ROW - MINUTM_N_ROW,
-];
-
-const MAX_NORTHING: [i32; 4] = [
-    MAXUPS_S_IND,
-    MAXUPS_N_IND,
-    MAXUTM_N_ROW + MAXUTM_S_ROW - MINUTM_N_ROW,
-    MAXUTM_N_ROW,
-];
-
 pub(crate) const BASE: i32= 10;
 pub(crate) const UTM_ROW_PERIOD: i32 = 20;
 pub(crate) const UTM_EVEN_ROW_SHIFT: i32= 5;
 pub(crate) const MAX_PRECISION: i32= 5 + 6;
+/// Precision of an MGRS coordinate consisting of only the grid zone designator
+pub(crate) const GRID_ZONE_PRECISION: i32 = -1;
+/// Precision of the finest (1 m) squares in the MGRS standard
+pub(crate) const MAX_MEANINGFUL_PRECISION: i32 = 5;
 pub(crate) const MULT: i32= 1_000_000;
+/// Length of the longest MGRS string: zone, band, 100 km square and 11 digits
+/// each of easting and northing
+const MAX_STRING_LEN: usize = 2 + 3 + 2 * MAX_PRECISION as usize;
+
+/// The precision of an [`Mgrs`] coordinate as the size of its grid squares,
+/// for self-documenting code in place of the integer precisions taken by e.g.
+/// [`Mgrs::from_latlon`]. Converts to and from those with [`From`]/[`TryFrom`].
+/// 
+/// The precisions finer than [`M1`](MgrsPrecision::M1) are extensions to the
+/// MGRS standard, see [`Mgrs::set_precision`].
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::{LatLon, Mgrs, MgrsPrecision};
+/// 
+/// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+/// 
+/// let mgrs = Mgrs::from_latlon_precision(&coord, MgrsPrecision::M10);
+/// assert_eq!(mgrs.to_string(), "18TWL85661131");
+/// assert_eq!(mgrs.precision_enum(), MgrsPrecision::M10);
+/// 
+/// assert_eq!(i32::from(MgrsPrecision::M10), 4);
+/// assert_eq!(MgrsPrecision::try_from(4).unwrap(), MgrsPrecision::M10);
+/// assert!(MgrsPrecision::try_from(12).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MgrsPrecision {
+    /// Only the grid zone designator, e.g. `18T` (precision `-1`)
+    GridZone = -1,
+    /// 100 km squares, e.g. `18TWL` (precision `0`)
+    Km100 = 0,
+    /// 10 km squares (precision `1`)
+    Km10 = 1,
+    /// 1 km squares (precision `2`)
+    Km1 = 2,
+    /// 100 m squares (precision `3`)
+    M100 = 3,
+    /// 10 m squares (precision `4`)
+    M10 = 4,
+    /// 1 m squares, the finest in the MGRS standard (precision `5`)
+    M1 = 5,
+    /// 10 cm squares (precision `6`)
+    Cm10 = 6,
+    /// 1 cm squares (precision `7`)
+    Cm1 = 7,
+    /// 1 mm squares (precision `8`)
+    Mm1 = 8,
+    /// 100 µm squares (precision `9`)
+    Um100 = 9,
+    /// 10 µm squares (precision `10`)
+    Um10 = 10,
+    /// 1 µm squares (precision `11`)
+    Um1 = 11,
+}
+
+impl From<MgrsPrecision> for i32 {
+    fn from(value: MgrsPrecision) -> Self {
+        value as i32
+    }
+}
+
+impl TryFrom<i32> for MgrsPrecision {
+    type Error = Error;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            -1 => Ok(MgrsPrecision::GridZone),
+            0 => Ok(MgrsPrecision::Km100),
+            1 => Ok(MgrsPrecision::Km10),
+            2 => Ok(MgrsPrecision::Km1),
+            3 => Ok(MgrsPrecision::M100),
+            4 => Ok(MgrsPrecision::M10),
+            5 => Ok(MgrsPrecision::M1),
+            6 => Ok(MgrsPrecision::Cm10),
+            7 => Ok(MgrsPrecision::Cm1),
+            8 => Ok(MgrsPrecision::Mm1),
+            9 => Ok(MgrsPrecision::Um100),
+            10 => Ok(MgrsPrecision::Um10),
+            11 => Ok(MgrsPrecision::Um1),
+            _ => Err(Error::InvalidPrecision(value)),
+        }
+    }
+}
 
 /// Representation of a WGS84 
 /// [Military Grid Reference System](https://en.wikipedia.org/wiki/Military_Grid_Reference_System)
 /// point. Stored internally as a [`UtmUps`] point with a precision.
+/// 
+/// Two [`Mgrs`] values are equal (and hash the same) if they have the same
+/// precision and name the same grid square at that precision, i.e. if they
+/// have the same string representation. This makes them suitable for
+/// deduplicating points into grid cells.
+/// 
+/// ```
+/// use std::collections::HashSet;
+/// 
+/// use geoconvert::Mgrs;
+/// 
+/// let a = Mgrs::create(18, true, 585664.1, 4511315.4, 5).unwrap();
+/// let b = Mgrs::create(18, true, 585664.9, 4511315.9, 5).unwrap();
+/// let c = Mgrs::create(18, true, 585665.1, 4511315.4, 5).unwrap();
+/// 
+/// assert_eq!(a, b);
+/// assert_ne!(a, c);
+/// assert_eq!(a.to_string(), b.to_string());
+/// 
+/// let cells: HashSet<Mgrs> = [a, b, c].into_iter().collect();
+/// assert_eq!(cells.len(), 2);
+/// ```
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Mgrs {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub(crate) utm: UtmUps,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_precision"))]
     pub(crate) precision: i32,
 }
 
+/// Deserializes the precision of an [`Mgrs`], rejecting one out of range
+#[cfg(feature = "serde")]
+fn deserialize_precision<'de, D>(deserializer: D) -> Result<i32, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let precision = <i32 as serde::Deserialize>::deserialize(deserializer)?;
+
+    MgrsPrecision::try_from(precision)
+        .map(i32::from)
+        .map_err(serde::de::Error::custom)
+}
+
 impl Mgrs {
     /// Tries to create a MGRS point from its constituent parts. Validates the
     /// arguments to ensure a valid MGRS point can be created. You most likely
@@ -83,7 +183,9 @@ impl Mgrs {
     /// # Errors
     /// 
     /// Returns [`Error::InvalidMgrs`] if the position is invalid.
-    /// Returns [`Error::InvalidPrecision`] if the precision is not in range `[1, 11]`.
+    /// Returns [`Error::InvalidCoord`] if the easting or northing is `NaN` or
+    /// infinite.
+    /// Returns [`Error::InvalidPrecision`] if the precision is not in range `[-1, 11]`.
     /// 
     /// # Usage
     /// 
@@ -107,6 +209,9 @@ impl Mgrs {
     /// 
     /// let invalid_coord_zone_too_big = Mgrs::create(70, true, 585664.121, 4511315.422, 6);
     /// assert!(invalid_coord_zone_too_big.is_err());
+    /// 
+    /// let invalid_precision = Mgrs::create(18, true, 585664.121, 4511315.422, 12);
+    /// assert!(invalid_precision.is_err());
     /// ```
     pub fn create(zone: i32, northp: bool, easting: f64, northing: f64, precision: i32) -> Result<Mgrs, Error> {
         // Make sure zone is a valid value
@@ -114,9 +219,15 @@ impl Mgrs {
             return Err(Error::InvalidZone(zone));
         }
 
-        let utmp = zone != zonespec::UPS;
+        if !(GRID_ZONE_PRECISION..=MAX_PRECISION).contains(&precision) {
+            return Err(Error::InvalidPrecision(precision));
+        }
+
+        if !(easting.is_finite() && northing.is_finite()) {
+            return Err(Error::InvalidCoord(format!("Easting {easting} and northing {northing} must be finite")));
+        }
 
-        check_coords(utmp, northp, easting, northing)?;
+        utm::check_coords(zone, northp, easting, northing, true)?;
 
         Ok(Mgrs {
             utm: UtmUps::new(zone, northp, easting, northing),
@@ -124,6 +235,49 @@ impl Mgrs {
         })
     }
 
+    /// Decomposes the coordinate into `(zone, northp, easting, northing,
+    /// precision)`, in the same order as the arguments of
+    /// [`create`](#method.create). The reverse of [`from_parts`](#method.from_parts).
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let coord = Mgrs::create(18, true, 585664.0, 4511315.0, 5).unwrap();
+    /// let (zone, northp, easting, northing, precision) = coord.into_parts();
+    /// 
+    /// assert_eq!(zone, 18);
+    /// assert!(northp);
+    /// assert_eq!((easting, northing), (585664.0, 4511315.0));
+    /// assert_eq!(precision, 5);
+    /// ```
+    pub fn into_parts(self) -> (i32, bool, f64, f64, i32) {
+        (self.utm.zone, self.utm.northp, self.utm.easting, self.utm.northing, self.precision)
+    }
+
+    /// Tries to create a coordinate from `(zone, northp, easting, northing,
+    /// precision)`, validated the same way as [`create`](#method.create), and
+    /// with the precision checked like [`set_precision`](#method.set_precision).
+    /// The reverse of [`into_parts`](#method.into_parts).
+    /// 
+    /// # Errors
+    /// 
+    /// See [`create`](#method.create).
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let coord = Mgrs::parse_str("18TWL8566411315").unwrap();
+    /// assert_eq!(Mgrs::from_parts(coord.into_parts()).unwrap().to_string(), "18TWL8566411315");
+    /// 
+    /// assert!(Mgrs::from_parts((61, true, 585664.0, 4511315.0, 5)).is_err());
+    /// assert!(Mgrs::from_parts((18, true, 585664.0, 4511315.0, 12)).is_err());
+    /// ```
+    pub fn from_parts((zone, northp, easting, northing, precision): (i32, bool, f64, f64, i32)) -> Result<Mgrs, Error> {
+        Mgrs::create(zone, northp, easting, northing, precision)
+    }
+
     /// Returns whether the MGRS is stored as UTM or UPS.
     /// 
     /// # Example
@@ -208,9 +362,73 @@ impl Mgrs {
         self.precision
     }
 
+    /// Returns the precision as an [`MgrsPrecision`].
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::{Mgrs, MgrsPrecision};
+    /// 
+    /// let coord = Mgrs::parse_str("18TWL856641113154").unwrap();
+    /// assert_eq!(coord.precision_enum(), MgrsPrecision::Cm10);
+    /// 
+    /// let coord = Mgrs::parse_str("18T").unwrap();
+    /// assert_eq!(coord.precision_enum(), MgrsPrecision::GridZone);
+    /// ```
+    pub fn precision_enum(&self) -> MgrsPrecision {
+        match self.precision {
+            -1 => MgrsPrecision::GridZone,
+            0 => MgrsPrecision::Km100,
+            1 => MgrsPrecision::Km10,
+            2 => MgrsPrecision::Km1,
+            3 => MgrsPrecision::M100,
+            4 => MgrsPrecision::M10,
+            5 => MgrsPrecision::M1,
+            6 => MgrsPrecision::Cm10,
+            7 => MgrsPrecision::Cm1,
+            8 => MgrsPrecision::Mm1,
+            9 => MgrsPrecision::Um100,
+            10 => MgrsPrecision::Um10,
+            11 => MgrsPrecision::Um1,
+            // Every way of making an Mgrs checks or clamps the precision
+            precision => unreachable!("MGRS precision {precision} out of range"),
+        }
+    }
+
+    /// Returns whether the coordinate only consists of the grid zone designator
+    /// (e.g. `18T`), i.e. has a precision of `-1`. This is the case when parsing
+    /// a string without the 100 km square letters.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let coord = Mgrs::parse_str("18T").unwrap();
+    /// assert!(coord.grid_zone_only());
+    /// assert_eq!(coord.precision(), -1);
+    /// assert_eq!(coord.to_string(), "18T");
+    /// 
+    /// let coord = Mgrs::parse_str("18TWL").unwrap();
+    /// assert!(!coord.grid_zone_only());
+    /// assert_eq!(coord.precision(), 0);
+    /// assert_eq!(coord.to_string(), "18TWL");
+    /// ```
+    #[inline]
+    pub fn grid_zone_only(&self) -> bool {
+        self.precision == GRID_ZONE_PRECISION
+    }
+
     /// Set the precision.
     /// 
-    /// Must be in range `[1, 11]`.
+    /// Must be in range `[-1, 11]`. A precision of `0` outputs only the grid zone
+    /// designator and 100 km square (e.g. `18TWL`), and `-1` outputs only the grid
+    /// zone designator (e.g. `18T`).
+    /// 
+    /// Precisions `1` to `5` (10 km down to 1 m) are the ones defined by the MGRS
+    /// standard. Precisions `6` to `11` (10 cm down to 1 μm) are an extension for
+    /// sub-meter grid references, as used by [GeographicLib](https://geographiclib.sourceforge.io/C++/doc/index.html) and some survey
+    /// systems, and round trip through [`Display`] and [`FromStr`] like the
+    /// standard ones. See [`max_meaningful_precision`](#method.max_meaningful_precision)
+    /// for when the extra digits are worth displaying.
     /// 
     /// # Errors
     /// 
@@ -224,10 +442,19 @@ impl Mgrs {
     /// coord.set_precision(7);
     /// 
     /// assert_eq!(coord.precision(), 7);
+    /// 
+    /// coord.set_precision(0);
+    /// assert_eq!(coord.to_string(), "18TWL");
+    /// 
+    /// coord.set_precision(-1);
+    /// assert_eq!(coord.to_string(), "18T");
+    /// 
+    /// assert!(coord.set_precision(-2).is_err());
+    /// assert!(coord.set_precision(12).is_err());
     /// ```
     #[inline]
     pub fn set_precision(&mut self, precision: i32) -> Result<(), Error> {
-        if !(1..=11).contains(&precision) {
+        if !(GRID_ZONE_PRECISION..=MAX_PRECISION).contains(&precision) {
             return Err(Error::InvalidPrecision(precision));
         }
 
@@ -235,22 +462,564 @@ impl Mgrs {
         Ok(())
     }
 
+    /// Sets the precision like [`set_precision`](#method.set_precision), but
+    /// clamps it to the valid range `[-1, 11]` instead of failing, e.g. for a
+    /// UI slider that might overshoot.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let mut coord = Mgrs::parse_str("18TWL856641113154").unwrap();
+    /// 
+    /// coord.clamp_precision(3);
+    /// assert_eq!(coord.to_string(), "18TWL856113");
+    /// 
+    /// coord.clamp_precision(20);
+    /// assert_eq!(coord.precision(), 11);
+    /// 
+    /// coord.clamp_precision(-5);
+    /// assert_eq!(coord.to_string(), "18T");
+    /// ```
+    #[inline]
+    pub fn clamp_precision(&mut self, precision: i32) {
+        self.precision = precision.clamp(GRID_ZONE_PRECISION, MAX_PRECISION);
+    }
+
+    /// Returns the finest precision worth displaying for most purposes: `5`,
+    /// i.e. 1 m squares, the finest precision defined by the MGRS standard.
+    /// 
+    /// Higher precisions (up to `11`, or 1 μm) are supported, and conversions
+    /// are accurate to them, but the extra digits are only meaningful if the
+    /// source coordinates are. Positions from a typical GPS receiver are only
+    /// good to a few meters, so digits beyond this precision are just noise.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::{LatLon, Mgrs};
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// let mgrs = coord.to_mgrs(Mgrs::max_meaningful_precision());
+    /// 
+    /// assert_eq!(mgrs.to_string(), "18TWL8566411315");
+    /// ```
+    #[inline]
+    pub const fn max_meaningful_precision() -> i32 {
+        MAX_MEANINGFUL_PRECISION
+    }
+
+    /// Returns the side length in meters of the grid square the coordinate is
+    /// truncated to at the current precision, e.g. for showing `±10 m` style
+    /// accuracy labels. Precision `0` is a 100 km square, and each extra digit
+    /// divides it by 10, so precision `5` is 1 m.
+    /// 
+    /// At precision `-1` only the grid zone is given, which isn't a square;
+    /// this returns 1000 km, the nominal extent of the eastings in a zone.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let mut coord = Mgrs::parse_str("18TWL8566411315").unwrap();
+    /// assert_eq!(coord.resolution_meters(), 1.0);
+    /// 
+    /// coord.set_precision(4);
+    /// assert_eq!(coord.resolution_meters(), 10.0);
+    /// 
+    /// coord.set_precision(1);
+    /// assert_eq!(coord.resolution_meters(), 10_000.0);
+    /// 
+    /// coord.set_precision(0);
+    /// assert_eq!(coord.resolution_meters(), 100_000.0);
+    /// ```
+    #[inline]
+    pub fn resolution_meters(&self) -> f64 {
+        f64::from(TILE) / 10_f64.powi(self.precision)
+    }
+
+    /// Returns a copy with a different precision. Accepts the same range as
+    /// [`set_precision`](#method.set_precision). Only the precision used for
+    /// output changes: lowering it truncates the grid reference that is displayed,
+    /// but the underlying easting and northing are preserved, so raising it again
+    /// restores the original digits.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`Error::InvalidPrecision`]: `precision` is not in the valid range
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let coord = Mgrs::parse_str("18TWL856641113154").unwrap();
+    /// 
+    /// let coarse = coord.at_precision(2).unwrap();
+    /// assert_eq!(coarse.to_string(), "18TWL8511");
+    /// assert_eq!(coarse.at_precision(6).unwrap().to_string(), "18TWL856641113154");
+    /// 
+    /// // The original is unchanged
+    /// assert_eq!(coord.precision(), 6);
+    /// 
+    /// assert!(coord.at_precision(12).is_err());
+    /// ```
+    pub fn at_precision(&self, precision: i32) -> Result<Mgrs, Error> {
+        let mut coord = *self;
+        coord.set_precision(precision)?;
+
+        Ok(coord)
+    }
+
+    /// Returns the lower of the two coordinates' precisions, i.e. the finest
+    /// precision at which both can be compared.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let a = Mgrs::parse_str("18TWL856641113154").unwrap();
+    /// let b = Mgrs::parse_str("18TWL8511").unwrap();
+    /// 
+    /// assert_eq!(a.common_precision(&b), 2);
+    /// assert!(a.same_cell(&b, a.common_precision(&b)));
+    /// ```
+    #[inline]
+    pub fn common_precision(&self, other: &Mgrs) -> i32 {
+        self.precision.min(other.precision)
+    }
+
+    /// Returns whether both coordinates fall in the same grid square at
+    /// `precision`, regardless of their own precisions. The same as comparing
+    /// the two [`at_precision(precision)`](#method.at_precision) with `==`, but
+    /// without going through [`Result`]. `precision` is clamped to the valid
+    /// range `[-1, 11]`.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let a = Mgrs::parse_str("18TWL856641113154").unwrap();
+    /// let b = Mgrs::parse_str("18TWL8569911399").unwrap();
+    /// 
+    /// assert!(a.same_cell(&b, 2));
+    /// assert!(a.same_cell(&b, 3));
+    /// assert!(!a.same_cell(&b, 4));
+    /// 
+    /// assert!(a.same_cell(&b, -1));
+    /// assert!(!a.same_cell(&Mgrs::parse_str("19TCG").unwrap(), -1));
+    /// ```
+    pub fn same_cell(&self, other: &Mgrs, precision: i32) -> bool {
+        let precision = precision.clamp(GRID_ZONE_PRECISION, MAX_PRECISION);
+
+        self.cell(precision) == other.cell(precision)
+    }
+
+    /// Appends the MGRS string (as formatted by [`Display`]) to `buf`, without
+    /// allocating a new [`String`] for each coordinate, e.g. when writing out
+    /// large datasets.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let coord = Mgrs::parse_str("18TWL856641113154").unwrap();
+    /// 
+    /// let mut buf = String::from("position: ");
+    /// coord.write_to(&mut buf);
+    /// assert_eq!(buf, "position: 18TWL856641113154");
+    /// ```
+    pub fn write_to(&self, buf: &mut String) {
+        let (mgrs_str, len) = self.format_bytes();
+
+        buf.extend(mgrs_str[..len].iter().map(|&c| char::from(c)));
+    }
+
+    /// Formats the coordinate as an MGRS string with lowercase letters, e.g. for
+    /// systems or URLs that prefer lowercase grid references. The same as
+    /// formatting with `{:#}`. [`parse_str`](Mgrs::parse_str) accepts either case.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let coord = Mgrs::parse_str("18TWL856641113154").unwrap();
+    /// 
+    /// assert_eq!(coord.to_string_lowercase(), "18twl856641113154");
+    /// assert_eq!(format!("{coord:#}"), "18twl856641113154");
+    /// // Uppercase by default
+    /// assert_eq!(coord.to_string(), "18TWL856641113154");
+    /// 
+    /// assert_eq!(Mgrs::parse_str(&coord.to_string_lowercase()).unwrap(), coord);
+    /// ```
+    pub fn to_string_lowercase(&self) -> String {
+        format!("{self:#}")
+    }
+
+    /// Formats each coordinate as an MGRS string, as by [`Display`].
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let coords = [
+    ///     Mgrs::parse_str("18TWL856641113154").unwrap(),
+    ///     Mgrs::parse_str("ZGC2677330083").unwrap(),
+    /// ];
+    /// 
+    /// assert_eq!(Mgrs::format_many(&coords), ["18TWL856641113154", "ZGC2677330083"]);
+    /// ```
+    pub fn format_many(points: &[Mgrs]) -> Vec<String> {
+        points
+            .iter()
+            .map(|point| {
+                let mut buf = String::with_capacity(MAX_STRING_LEN);
+                point.write_to(&mut buf);
+                buf
+            })
+            .collect()
+    }
+
+    /// Returns the 100 km grid square containing this coordinate, e.g. `18TWL`,
+    /// for coarsening a position before sharing it.
+    /// 
+    /// Unlike [`at_precision(0)`](#method.at_precision), which only changes what
+    /// is displayed, the position itself is discarded: the result is placed in
+    /// the middle of the square, as when parsing a 5 character grid reference. If
+    /// a latitude band boundary crosses the square, the point is moved north or
+    /// south within the square so that the band letter is kept. Grid zone only
+    /// values have no square and are returned unchanged.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let coord = Mgrs::parse_str("18TWL856641113154").unwrap();
+    /// let square = coord.hundred_km_square();
+    /// 
+    /// assert_eq!(square.to_string(), "18TWL");
+    /// assert_eq!(square.precision(), 0);
+    /// assert_eq!(square.easting(), 550000.0);
+    /// assert_eq!(square.northing(), 4550000.0);
+    /// 
+    /// // The precise position can't be recovered
+    /// assert_eq!(square.at_precision(6).unwrap().to_string(), "18TWL500000500000");
+    /// ```
+    pub fn hundred_km_square(&self) -> Mgrs {
+        /// Northings within the square (in km) to try, starting from the middle
+        const OFFSETS: [f64; 11] = [50.0, 45.0, 55.0, 35.0, 65.0, 25.0, 75.0, 15.0, 85.0, 5.0, 95.0];
+
+        if self.grid_zone_only() {
+            return *self;
+        }
+
+        let tile = f64::from(TILE);
+        let x0 = (self.utm.easting / tile).floor() * tile;
+        let y0 = (self.utm.northing / tile).floor() * tile;
+
+        let square = |offset: f64| Mgrs {
+            utm: UtmUps::new(self.utm.zone, self.utm.northp, x0 + tile / 2.0, y0 + offset * 1000.0),
+            precision: 0,
+        };
+
+        // UPS squares never cross a band boundary
+        if self.utm.zone == UPS {
+            return square(OFFSETS[0]);
+        }
+
+        let band = to_latitude_band(self.utm.to_latlon().latitude);
+
+        OFFSETS.into_iter()
+            .map(square)
+            .find(|square| to_latitude_band(square.utm.to_latlon().latitude) == band)
+            .unwrap_or_else(|| square(OFFSETS[0]))
+    }
+
     /// Parses a string as MGRS. Assumes the string is _only_ composed of
-    /// the MGRS coordinate (e.g. no preceding/trailing whitespace) and there
-    /// are no spaces in the string. Example valid strings:
+    /// the MGRS coordinate. The point is placed in the middle of the grid
+    /// square; see [`parse_str_corner`](#method.parse_str_corner) for the
+    /// southwest corner. Whitespace is ignored, so the spaced grouping commonly
+    /// output by mapping tools is accepted, but if the easting and northing are
+    /// given as separate groups they must have the same number of digits.
+    /// Example valid strings:
     /// 
     /// * `27UXQ0314512982`
     /// * `YXL6143481146`
+    /// * `4Q FJ 1234 5678`
     /// 
     /// # Errors
     /// 
     /// * [`Error::InvalidMgrs`]: the string couldn't be parsed to a valid MGRS coordinate.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let coord = Mgrs::parse_str("18T WL 85664 11315").unwrap();
+    /// assert_eq!(coord.to_string(), "18TWL8566411315");
+    /// 
+    /// assert!(Mgrs::parse_str("18T WL 856641 1315").is_err());
+    /// ```
+    /// 
+    /// Parsing is lenient: letters may be lowercase, and a grid zone only
+    /// (`18T`), a 100 km square with no digits (`18TWL`) and up to 11 digits each
+    /// of easting and northing (sub-meter precision, as in
+    /// [GeographicLib](https://geographiclib.sourceforge.io/C++/doc/classGeographicLib_1_1MGRS.html))
+    /// are all accepted. Use [`parse_str_strict`](#method.parse_str_strict) to
+    /// only accept standard grid references.
     pub fn parse_str(mgrs_str: &str) -> Result<Mgrs, Error> {
         Self::from_str(mgrs_str)
     }
 
+    /// Parses a string as MGRS like [`parse_str`](#method.parse_str), but only
+    /// accepts a standard grid reference, for ingesting untrusted input. On top of
+    /// the checks done by [`parse_str`](#method.parse_str), the string must
+    /// include the 100 km square and between 1 and 5 digits each of easting and
+    /// northing (10 km to 1 m precision). Case and whitespace are still ignored.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`Error::InvalidMgrs`]: the string couldn't be parsed to a valid MGRS
+    ///   coordinate, or isn't a standard grid reference.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let coord = Mgrs::parse_str_strict("18TWL8566411315").unwrap();
+    /// assert_eq!(coord.precision(), 5);
+    /// 
+    /// assert!(Mgrs::parse_str_strict("18T WL 8566 1131").is_ok());
+    /// 
+    /// // Accepted by parse_str, but not by parse_str_strict
+    /// for value in ["18T", "18TWL", "18TWL856641113154"] {
+    ///     assert!(Mgrs::parse_str(value).is_ok());
+    ///     assert!(Mgrs::parse_str_strict(value).is_err());
+    /// }
+    /// ```
+    pub fn parse_str_strict(mgrs_str: &str) -> Result<Mgrs, Error> {
+        let coord = Self::from_str(mgrs_str)?;
+
+        if coord.grid_zone_only() {
+            return Err(Error::InvalidMgrs(format!("Missing 100 km square in {mgrs_str}")));
+        }
+
+        if !(1..=5).contains(&coord.precision) {
+            return Err(Error::InvalidMgrs(format!(
+                "Expected 1 to 5 digits each of easting and northing, got {} in {mgrs_str}",
+                coord.precision,
+            )));
+        }
+
+        Ok(coord)
+    }
+
+    /// Parses a string as MGRS like [`parse_str`](#method.parse_str), but places
+    /// the point at the southwest (lower-left) corner of the grid square rather
+    /// than in its middle. This is the convention used by applications that
+    /// treat a grid reference as truncated coordinates, e.g. `18TWL8511` as
+    /// easting `585000` and northing `4511000` in the 1 km square.
+    /// 
+    /// The two conventions differ by half a square in each of easting and
+    /// northing, i.e. by about `0.7 * 100 km / 10^precision` on the ground. Both
+    /// give back the same string when formatted at the parsed precision, but
+    /// since the corner is on the edge of the square, converting it to another
+    /// coordinate system and back may land it in a neighboring square. A grid
+    /// zone only string (e.g. `18T`) gives the same point with either convention.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`Error::InvalidMgrs`]: the string couldn't be parsed to a valid MGRS coordinate.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let corner = Mgrs::parse_str_corner("18TWL8511").unwrap();
+    /// assert_eq!(corner.easting(), 585000.0);
+    /// assert_eq!(corner.northing(), 4511000.0);
+    /// 
+    /// let center = Mgrs::parse_str("18TWL8511").unwrap();
+    /// assert_eq!(center.easting(), 585500.0);
+    /// assert_eq!(center.northing(), 4511500.0);
+    /// 
+    /// // Both name the same square
+    /// assert_eq!(corner, center);
+    /// assert_eq!(corner.to_string(), "18TWL8511");
+    /// ```
+    pub fn parse_str_corner(mgrs_str: &str) -> Result<Mgrs, Error> {
+        Self::parse_mgrs(mgrs_str, false)
+    }
+
+    /// Parses the truncated form of MGRS, with only the easting and northing
+    /// digits (e.g. `8566411315`), given the grid zone designator and 100 km
+    /// square they are in (e.g. `18TWL`) separately. Some legacy systems and map
+    /// sheets only give the digits, relying on the grid zone and square being
+    /// known from context.
+    /// 
+    /// `gzd` is the grid zone designator followed by the 100 km square letters,
+    /// and `numeric` is an even number of digits, the first half being the
+    /// easting and the second half the northing. Either may contain spaces, as
+    /// in [`parse_str`](#method.parse_str). The result is the same as parsing the
+    /// two joined together with [`parse_str`](#method.parse_str), so it is at the
+    /// center of the square named by the digits.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`Error::InvalidMgrs`]: `gzd` isn't a valid grid zone designator and
+    ///   100 km square (e.g. it is missing the square letters, or has digits),
+    ///   `numeric` is empty or has anything other than digits, or the digits
+    ///   aren't a valid easting and northing.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let coord = Mgrs::parse_str_with_gzd("18TWL", "8566411315").unwrap();
+    /// assert_eq!(coord, Mgrs::parse_str("18TWL8566411315").unwrap());
+    /// 
+    /// let coord = Mgrs::parse_str_with_gzd("18T WL", "85664 11315").unwrap();
+    /// assert_eq!(coord.to_string(), "18TWL8566411315");
+    /// 
+    /// // Missing the 100 km square
+    /// assert!(Mgrs::parse_str_with_gzd("18T", "8566411315").is_err());
+    /// // Digits in the grid zone designator
+    /// assert!(Mgrs::parse_str_with_gzd("18TWL85", "6411315").is_err());
+    /// // Letters in the digits, or an odd number of digits
+    /// assert!(Mgrs::parse_str_with_gzd("18TWL", "WL8566411315").is_err());
+    /// assert!(Mgrs::parse_str_with_gzd("18TWL", "856641131").is_err());
+    /// assert!(Mgrs::parse_str_with_gzd("18TWL", "").is_err());
+    /// ```
+    pub fn parse_str_with_gzd(gzd: &str, numeric: &str) -> Result<Mgrs, Error> {
+        if Self::parse_mgrs(gzd, true)?.precision != 0 {
+            return Err(Error::InvalidMgrs(format!("Expected a grid zone designator and 100 km square (e.g. 18TWL), got {gzd}")));
+        }
+
+        if numeric.trim().is_empty() || !numeric.bytes().all(|c| c.is_ascii_digit() || c.is_ascii_whitespace()) {
+            return Err(Error::InvalidMgrs(format!("Expected only the digits of the easting and northing, got {numeric}")));
+        }
+
+        Self::parse_mgrs(&format!("{gzd} {numeric}"), true)
+    }
+
+    /// Parses a [United States National Grid](https://www.fgdc.gov/usng) (USNG)
+    /// reference, e.g. `18S UJ 23487 06483`. USNG uses the same grid and lettering
+    /// as MGRS, and is parsed like [`parse_str`](#method.parse_str), except that
+    /// at most 5 digits each of easting and northing (1 m) are accepted.
+    /// 
+    /// USNG is defined on the NAD83 datum rather than WGS84. No datum
+    /// transformation is applied: the grid is computed the same way on both
+    /// (their ellipsoids differ by less than a millimeter here), so converting
+    /// a USNG reference to [`LatLon`] gives NAD83 latitude and longitude. In the
+    /// conterminous US these differ from WGS84 by about 1-2 m, which changes
+    /// over time with plate motion.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`Error::InvalidMgrs`]: the string couldn't be parsed to a valid USNG
+    ///   reference.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let coord = Mgrs::parse_usng("18S UJ 23487 06483").unwrap();
+    /// assert_eq!(coord.to_string(), "18SUJ2348706483");
+    /// assert_eq!(coord.to_usng_string(), "18S UJ 23487 06483");
+    /// 
+    /// // Leading zeros in the zone are optional
+    /// assert_eq!(Mgrs::parse_usng("4Q FJ 1234 5678").unwrap(), Mgrs::parse_usng("04QFJ12345678").unwrap());
+    /// 
+    /// assert!(Mgrs::parse_usng("18S UJ 234870 064830").is_err());
+    /// ```
+    pub fn parse_usng(usng_str: &str) -> Result<Mgrs, Error> {
+        let coord = Self::from_str(usng_str)?;
+
+        if coord.precision > MAX_MEANINGFUL_PRECISION {
+            return Err(Error::InvalidMgrs(format!(
+                "Expected at most {MAX_MEANINGFUL_PRECISION} digits each of easting and northing for USNG, got {} in {usng_str}",
+                coord.precision,
+            )));
+        }
+
+        Ok(coord)
+    }
+
+    /// Formats the coordinate as a [United States National Grid](https://www.fgdc.gov/usng)
+    /// (USNG) reference, with the grid zone, 100 km square, easting and northing
+    /// separated by spaces and no leading zero in the zone, e.g.
+    /// `4Q FJ 12345 67890`. See [`parse_usng`](#method.parse_usng) for how the
+    /// NAD83 datum of USNG is handled.
+    /// 
+    /// USNG only covers the UTM zones; UPS coordinates are formatted the same way
+    /// (e.g. `Z GC 26773 30083`).
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let coord = Mgrs::parse_str("04QFJ1234567890").unwrap();
+    /// assert_eq!(coord.to_usng_string(), "4Q FJ 12345 67890");
+    /// 
+    /// assert_eq!(coord.at_precision(0).unwrap().to_usng_string(), "4Q FJ");
+    /// assert_eq!(coord.at_precision(-1).unwrap().to_usng_string(), "4Q");
+    /// ```
+    pub fn to_usng_string(&self) -> String {
+        let (mgrs_str, len) = self.format_bytes();
+        let mgrs_str = &mgrs_str[..len];
+
+        let gzd_len = (self.utm.zone != UPS).ternary(3, 1);
+        let (gzd, rest) = mgrs_str.split_at(gzd_len.min(len));
+
+        let mut usng = String::with_capacity(len + 3);
+        let gzd = gzd.strip_prefix(b"0").unwrap_or(gzd);
+        usng.extend(gzd.iter().map(|&c| char::from(c)));
+
+        if !rest.is_empty() {
+            let (square, digits) = rest.split_at(2);
+            let (easting, northing) = digits.split_at(digits.len() / 2);
+
+            for group in [square, easting, northing] {
+                if !group.is_empty() {
+                    usng.push(' ');
+                    usng.extend(group.iter().map(|&c| char::from(c)));
+                }
+            }
+        }
+
+        usng
+    }
+
+    /// Lazily parses a sequence of MGRS strings, such as the lines of a file.
+    /// Each item is trimmed and blank items are skipped. Parse errors are
+    /// returned per item, so one bad line doesn't stop the rest from being parsed.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let input = "18TWL856641113154\n\n  YXL6143481146  \nnot mgrs\n";
+    /// 
+    /// let parsed: Vec<_> = Mgrs::parse_many(input.lines()).collect();
+    /// 
+    /// assert_eq!(parsed.len(), 3);
+    /// assert_eq!(parsed[0].as_ref().unwrap().to_string(), "18TWL856641113154");
+    /// assert_eq!(parsed[1].as_ref().unwrap().to_string(), "YXL6143481146");
+    /// assert!(parsed[2].is_err());
+    /// ```
+    pub fn parse_many<'a>(input: impl Iterator<Item = &'a str> + 'a) -> impl Iterator<Item = Result<Mgrs, Error>> + 'a {
+        input
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(Mgrs::from_str)
+    }
+
     /// Converts from [`LatLon`] to [`Mgrs`]
     /// 
+    /// `precision` is clamped to the range `[-1, 11]`. Use
+    /// [`from_latlon_precision`](#method.from_latlon_precision) to pass one
+    /// that is always in range.
+    /// 
     /// # Usage
     /// 
     /// ```
@@ -262,14 +1031,82 @@ impl Mgrs {
     /// let converted = LatLon::from_mgrs(&coord_mgrs);
     /// 
     /// // Check if the converted coordinate is accurate to 6 decimals (same as reference)
-    /// assert!((converted.latitude() - coord.latitude()).abs() < 1e-6);
-    /// assert!((converted.longitude() - coord.longitude()).abs() < 1e-6);
+    /// assert!(converted.approx_eq(&coord, 1e-6));
     /// ```
     pub fn from_latlon(value: &LatLon, precision: i32) -> Mgrs {
         Mgrs {
             utm: UtmUps::from_latlon(value),
-            precision,
+            precision: precision.clamp(GRID_ZONE_PRECISION, MAX_PRECISION),
+        }
+    }
+
+    /// Converts from [`LatLon`] to [`Mgrs`] like [`from_latlon`](#method.from_latlon),
+    /// with the precision given as an [`MgrsPrecision`].
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, Mgrs, MgrsPrecision};
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// 
+    /// let converted = Mgrs::from_latlon_precision(&coord, MgrsPrecision::M1);
+    /// assert_eq!(converted, Mgrs::from_latlon(&coord, 5));
+    /// assert_eq!(converted.to_string(), "18TWL8566411315");
+    /// ```
+    pub fn from_latlon_precision(value: &LatLon, precision: MgrsPrecision) -> Mgrs {
+        Self::from_latlon(value, precision.into())
+    }
+
+    /// Converts from [`LatLon`] to [`Mgrs`] like [`from_latlon`](#method.from_latlon),
+    /// but forcing the point into the given UTM zone instead of the one it
+    /// naturally falls in, e.g. to keep points either side of a zone boundary on
+    /// one consistent grid. See [`UtmUps::from_latlon_zone`].
+    /// 
+    /// The zone must be the point's natural zone or one of the neighboring zones
+    /// (zones `60` and `1` are neighbors, see [`UtmUps::to_zone`]). The point
+    /// must also be within the `[100, 900]` km easting range of MGRS in that zone,
+    /// which near the equator only reaches about 0.6° into the neighboring zone.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`Error::InvalidPrecision`]: `precision` is not in the range `[-1, 11]`
+    /// * [`Error::InvalidZone`]: the point is UPS, or `zone` is not its natural
+    ///   zone or a neighbor
+    /// * [`Error::InvalidMgrs`]: the point is outside the MGRS range of the zone
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, Mgrs};
+    /// 
+    /// // Naturally in zone 18, just east of the boundary with zone 17
+    /// let coord = LatLon::create(40.0, -77.9).unwrap();
+    /// assert_eq!(coord.to_mgrs(5).to_string(), "18STK5243831786");
+    /// 
+    /// let forced = Mgrs::from_latlon_zone(&coord, 17, 5).unwrap();
+    /// assert_eq!(forced.to_string(), "17SQE6463732361");
+    /// assert!(forced.to_latlon().haversine(&coord) < 1.0);
+    /// 
+    /// // Not a neighbor
+    /// assert!(Mgrs::from_latlon_zone(&coord, 16, 5).is_err());
+    /// 
+    /// // Too far into zone 18 for the MGRS grid of zone 17
+    /// let coord = LatLon::create(40.0, -76.0).unwrap();
+    /// assert!(Mgrs::from_latlon_zone(&coord, 17, 5).is_err());
+    /// ```
+    pub fn from_latlon_zone(value: &LatLon, zone: i32, precision: i32) -> Result<Mgrs, Error> {
+        if !(GRID_ZONE_PRECISION..=MAX_PRECISION).contains(&precision) {
+            return Err(Error::InvalidPrecision(precision));
         }
+
+        let utm = UtmUps::from_latlon(value).to_zone(zone)?;
+        utm::check_coords(utm.zone, utm.northp, utm.easting, utm.northing, true)?;
+
+        Ok(Mgrs {
+            utm,
+            precision,
+        })
     }
 
     /// Converts from [`Mgrs`] to [`LatLon`]
@@ -292,6 +1129,8 @@ impl Mgrs {
     
     /// Converts from [`UtmUps`] to [`Mgrs`]
     /// 
+    /// `precision` is clamped to the range `[-1, 11]`.
+    /// 
     /// # Usage
     /// 
     /// ```
@@ -312,7 +1151,7 @@ impl Mgrs {
     pub fn from_utmups(value: &UtmUps, precision: i32) -> Mgrs {
         Mgrs {
             utm: *value,
-            precision,
+            precision: precision.clamp(GRID_ZONE_PRECISION, MAX_PRECISION),
         }
     }
 
@@ -331,12 +1170,371 @@ impl Mgrs {
     /// // Check if the converted coordinate is accurate to 6 decimals (same as reference)
     /// assert_eq!(coord_utm.zone(), converted.zone());
     /// assert_eq!(coord_utm.is_north(), converted.is_north());
-    /// assert!((coord_utm.easting() - converted.easting()).abs() < 1e-2);
-    /// assert!((coord_utm.northing() - converted.northing()).abs() < 1e-2);
+    /// assert!(coord_utm.approx_eq(&converted, 1e-2));
     /// ```
     pub fn to_utmups(&self) -> UtmUps {
         self.utm
     }
+
+    /// Returns a representative point for the area named by this coordinate at
+    /// its current precision:
+    /// * With a precision of `0` or more, the center of the grid square (see
+    ///   [`bounds`](#method.bounds))
+    /// * For a grid zone only coordinate (e.g. `18T`), a point on the central
+    ///   meridian of the zone near the middle of the latitude band, rounded to
+    ///   100 km (or 400 km east or west of the pole for UPS). This is the point
+    ///   [`parse_str`](#method.parse_str) gives for a grid zone only string.
+    /// 
+    /// Unlike [`to_latlon`](#method.to_latlon), which converts the underlying
+    /// easting and northing at full precision, this only depends on the grid
+    /// reference that is displayed. They agree for coordinates parsed with
+    /// [`parse_str`](#method.parse_str), but not for coordinates converted from
+    /// [`LatLon`] and then truncated to a lower precision.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, Mgrs};
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// let mgrs = coord.to_mgrs(2);
+    /// assert_eq!(mgrs.to_string(), "18TWL8511");
+    /// 
+    /// // The center of the 1 km square, rather than the original point
+    /// assert!(mgrs.to_latlon().approx_eq(&coord, 1e-9));
+    /// let center = mgrs.center_latlon();
+    /// assert!(center.approx_eq(&Mgrs::parse_str("18TWL8511").unwrap().to_latlon(), 1e-9));
+    /// 
+    /// // The grid zone only
+    /// let gzd = coord.to_mgrs(-1);
+    /// assert_eq!(gzd.to_string(), "18T");
+    /// assert!(gzd.center_latlon().approx_eq(&Mgrs::parse_str("18T").unwrap().to_latlon(), 1e-9));
+    /// assert!((gzd.center_latlon().longitude() - -75.0).abs() < 1e-9);
+    /// ```
+    pub fn center_latlon(&self) -> LatLon {
+        let utmp = self.utm.zone != UPS;
+
+        if self.grid_zone_only() {
+            // The band letter is the last character, and always in the set
+            let (mgrs_str, len) = self.format_bytes();
+            let band_idx = utmp.ternary(LATBAND, UPSBAND)
+                .bytes()
+                .position(|band| band == mgrs_str[len - 1])
+                .unwrap_or_default() as i32;
+            let (x, y) = grid_zone_center(self.utm.zone, band_idx);
+
+            return UtmUps::new(self.utm.zone, band_idx >= utmp.ternary(10, 2), x, y).to_latlon();
+        }
+
+        let (x0, y0, cell) = self.cell_origin();
+
+        UtmUps::new(self.utm.zone, self.utm.northp, x0 + cell / 2.0, y0 + cell / 2.0).to_latlon()
+    }
+
+    /// Returns the southwest and northeast corners of the grid square named by
+    /// this coordinate at its current precision.
+    /// 
+    /// The square is found by truncating the easting and northing to the cell
+    /// size (`100 km / 10^precision`) to get the lower-left corner, then adding
+    /// the cell size to get the upper-right corner. A precision of `0` or less
+    /// is treated as the 100 km square.
+    /// 
+    /// Note that grid squares are aligned with the UTM/UPS grid, not with lines
+    /// of latitude and longitude, so the returned points are the grid corners
+    /// rather than a lat/lon bounding box.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let coord = Mgrs::parse_str("18TWL8566411315").unwrap();
+    /// let (sw, ne) = coord.bounds();
+    /// 
+    /// let sw_utm = sw.to_utmups();
+    /// let ne_utm = ne.to_utmups();
+    /// 
+    /// assert!((sw_utm.easting() - 585664.0).abs() < 1e-6);
+    /// assert!((sw_utm.northing() - 4511315.0).abs() < 1e-6);
+    /// assert!((ne_utm.easting() - 585665.0).abs() < 1e-6);
+    /// assert!((ne_utm.northing() - 4511316.0).abs() < 1e-6);
+    /// 
+    /// assert!(sw.latitude() < ne.latitude());
+    /// assert!(sw.longitude() < ne.longitude());
+    /// ```
+    pub fn bounds(&self) -> (LatLon, LatLon) {
+        let (x0, y0, cell) = self.cell_origin();
+
+        let sw = UtmUps::new(self.utm.zone, self.utm.northp, x0, y0);
+        let ne = UtmUps::new(self.utm.zone, self.utm.northp, x0 + cell, y0 + cell);
+
+        (sw.to_latlon(), ne.to_latlon())
+    }
+
+    /// Returns the 8 grid squares adjacent to the one named by this coordinate,
+    /// at the same precision, in the order north, northeast, east, southeast,
+    /// south, southwest, west, northwest. Useful for spatial queries such as
+    /// finding the cells touching a given cell.
+    /// 
+    /// Each neighbor is found by stepping one square from the middle of this
+    /// square on the grid, so the 100 km square letters roll over as usual. If
+    /// the step leaves the zone (or crosses between UTM and UPS), the neighbor is
+    /// instead the square of the zone the new position is actually in. Since the
+    /// grids of neighboring zones don't line up, two neighbors across a zone
+    /// boundary may be the same square. The returned [`Mgrs`] values lie in the
+    /// middle of their squares.
+    /// 
+    /// A grid zone only coordinate is treated as its 100 km square, and its
+    /// neighbors have a precision of `0`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::Mgrs;
+    /// 
+    /// let coord = Mgrs::parse_str("18TWL8566411315").unwrap();
+    /// let names: Vec<String> = coord.neighbors().iter().map(ToString::to_string).collect();
+    /// 
+    /// assert_eq!(names, [
+    ///     "18TWL8566411316",
+    ///     "18TWL8566511316",
+    ///     "18TWL8566511315",
+    ///     "18TWL8566511314",
+    ///     "18TWL8566411314",
+    ///     "18TWL8566311314",
+    ///     "18TWL8566311315",
+    ///     "18TWL8566311316",
+    /// ]);
+    /// 
+    /// // Rolling over the 100 km square letters
+    /// let coord = Mgrs::parse_str("18TWL99").unwrap();
+    /// assert_eq!(coord.neighbors()[1].to_string(), "18TXM00");
+    /// 
+    /// // Crossing into zone 19
+    /// let coord = Mgrs::parse_str("18TYK").unwrap();
+    /// assert_eq!(coord.neighbors()[2].zone(), 19);
+    /// ```
+    pub fn neighbors(&self) -> [Mgrs; 8] {
+        const STEPS: [(f64, f64); 8] = [
+            (0.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 0.0),
+            (1.0, -1.0),
+            (0.0, -1.0),
+            (-1.0, -1.0),
+            (-1.0, 0.0),
+            (-1.0, 1.0),
+        ];
+
+        let precision = self.precision.max(0);
+        let (x0, y0, cell) = self.cell_origin();
+
+        STEPS.map(|(dx, dy)| {
+            let x = x0 + cell * (0.5 + dx);
+            let y = y0 + cell * (0.5 + dy);
+
+            match UtmUps::create(self.utm.zone, self.utm.northp, x, y) {
+                Ok(utm) if utm.is_zone_valid_for_latitude() => Mgrs { utm, precision },
+                _ => {
+                    let coord = UtmUps::new(self.utm.zone, self.utm.northp, x, y).to_latlon();
+                    let utm = UtmUps::from_latlon(&coord);
+                    let (x0, y0, _) = Mgrs { utm, precision }.cell_origin();
+
+                    Mgrs {
+                        utm: UtmUps::new(utm.zone, utm.northp, x0 + cell / 2.0, y0 + cell / 2.0),
+                        precision,
+                    }
+                }
+            }
+        })
+    }
+
+    /// Returns every grid square at the given precision whose area intersects the
+    /// lat/lon rectangle with southwest corner `sw` and northeast corner `ne`.
+    /// Useful for building MGRS-indexed tiles or spatial caches.
+    /// 
+    /// The rectangle is split along UTM/UPS zone boundaries (including the Norway
+    /// and Svalbard exceptions) and the equator, and each part is walked on its
+    /// zone's grid. If `sw` is east of `ne`, the rectangle crosses the
+    /// antimeridian. Squares that only touch the rectangle's edge are not included.
+    /// 
+    /// Squares are ordered by zone, then hemisphere (south first), then from
+    /// south to north and west to east. The returned [`Mgrs`] values lie inside
+    /// their squares, so their band letters are those of the covered area.
+    /// 
+    /// Note that the number of squares grows with the square of `10^precision`,
+    /// so large rectangles should only be used with low precisions. Rectangles
+    /// covering more than about 1,000,000 squares (estimated from their size on
+    /// the ground) are rejected rather than walked.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidPrecision`] if the precision is not in range `[0, 11]`.
+    /// Returns [`Error::InvalidCoord`] if `sw` is not south of `ne`, or they
+    /// have the same longitude.
+    /// Returns [`Error::TooManyItems`] if the rectangle covers too many squares.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{Error, LatLon, Mgrs};
+    /// 
+    /// // Straddles the boundary between zones 17 and 18
+    /// let sw = LatLon::create(40.5, -78.5).unwrap();
+    /// let ne = LatLon::create(40.8, -77.5).unwrap();
+    /// 
+    /// let squares = Mgrs::squares_in_bbox(&sw, &ne, 0).unwrap();
+    /// let names: Vec<String> = squares.iter().map(ToString::to_string).collect();
+    /// 
+    /// assert_eq!(names, ["17TQE", "17TQF", "18TTK", "18TTL"]);
+    /// 
+    /// // Every point in the rectangle is in one of the squares
+    /// let point = LatLon::create(40.6, -78.0).unwrap();
+    /// assert!(squares.contains(&point.to_mgrs(0)));
+    /// 
+    /// assert!(Mgrs::squares_in_bbox(&ne, &sw, 0).is_err());
+    /// assert!(Mgrs::squares_in_bbox(&sw, &ne, 12).is_err());
+    /// 
+    /// // A 1 m grid over the whole rectangle is far too many squares
+    /// assert!(matches!(Mgrs::squares_in_bbox(&sw, &ne, 5), Err(Error::TooManyItems { .. })));
+    /// ```
+    pub fn squares_in_bbox(sw: &LatLon, ne: &LatLon, precision: i32) -> Result<Vec<Mgrs>, Error> {
+        if !(0..=MAX_PRECISION).contains(&precision) {
+            return Err(Error::InvalidPrecision(precision));
+        }
+
+        if sw.latitude >= ne.latitude || sw.longitude.eps_eq(ne.longitude) {
+            return Err(Error::InvalidCoord(format!("Bounding box from ({sw}) to ({ne}) is empty")));
+        }
+
+        let size = f64::from(TILE) / f64::from(BASE).powi(precision);
+
+        // Split the rectangle at the antimeridian if it crosses it
+        let mut lon_ranges = Vec::with_capacity(2);
+        if sw.longitude < ne.longitude {
+            lon_ranges.push((sw.longitude, ne.longitude));
+        } else {
+            lon_ranges.push((sw.longitude, f64::from(dms::HD)));
+            lon_ranges.push((-f64::from(dms::HD), ne.longitude));
+        }
+
+        // The parts of the rectangle in each zone and hemisphere
+        let mut parts = Vec::new();
+
+        for (band_lat0, band_lat1) in BBOX_LAT_BANDS {
+            let lat0 = sw.latitude.max(band_lat0);
+            let lat1 = ne.latitude.min(band_lat1);
+            if lat0 >= lat1 {
+                continue;
+            }
+
+            let northp = band_lat0 >= 0.0;
+
+            for (zone, zone_lon0, zone_lon1) in zone_longitudes(band_lat0) {
+                for &(range_lon0, range_lon1) in &lon_ranges {
+                    let lon0 = range_lon0.max(zone_lon0);
+                    let lon1 = range_lon1.min(zone_lon1);
+                    if lon0 < lon1 {
+                        parts.push((zone, northp, lat0, lat1, lon0, lon1));
+                    }
+                }
+            }
+        }
+
+        let estimate: f64 = parts.iter()
+            .map(|&(_, _, lat0, lat1, lon0, lon1)| {
+                // The widest parallel of the part
+                let lat = if lat0 <= 0.0 && lat1 >= 0.0 { 0.0 } else { lat0.abs().min(lat1.abs()) };
+
+                ((arc_length(lat1 - lat0, 0.0) / size).ceil() + 1.0) * ((arc_length(lon1 - lon0, lat) / size).ceil() + 1.0)
+            })
+            .sum();
+
+        #[allow(clippy::cast_precision_loss)]
+        if estimate > MAX_BBOX_SQUARES as f64 {
+            return Err(Error::TooManyItems {
+                limit: MAX_BBOX_SQUARES,
+                msg: format!("Bounding box from ({sw}) to ({ne}) covers too many squares at precision {precision}"),
+            });
+        }
+
+        let tm = TransverseMercator::utm(Ellipsoid::WGS84);
+        let ps = PolarStereographic::ups(Ellipsoid::WGS84);
+
+        let mut squares = Vec::new();
+
+        for (zone, northp, lat0, lat1, lon0, lon1) in parts {
+            let outline = bbox_outline(lat0, lat1, lon0, lon1, size, |lat, lon| {
+                utm::to_grid(zone, northp, lat, lon, &tm, &ps)
+            });
+
+            walk_grid(&outline, size, |row, col, x, y| {
+                squares.push((
+                    (zone, northp, row, col),
+                    Mgrs { utm: UtmUps::new(zone, northp, x, y), precision },
+                ));
+            });
+        }
+
+        // Squares straddling a latitude band boundary are found from both sides
+        squares.sort_by_key(|(key, _)| *key);
+        squares.dedup_by_key(|(key, _)| *key);
+
+        Ok(squares.into_iter().map(|(_, square)| square).collect())
+    }
+
+    /// Returns the easting and northing of the lower-left corner of the grid
+    /// square named by this coordinate, and the size of the square, as
+    /// `(x0, y0, size)`. A precision of `0` or less is treated as the 100 km square.
+    pub(crate) fn cell_origin(&self) -> (f64, f64, f64) {
+        let cell = f64::from(TILE) / f64::from(BASE).powi(self.precision.max(0));
+
+        let x0 = (self.utm.easting / cell).floor() * cell;
+        let y0 = (self.utm.northing / cell).floor() * cell;
+
+        (x0, y0, cell)
+    }
+
+    /// Returns a key identifying the grid square containing this coordinate at
+    /// `precision`: the zone, hemisphere and the easting/northing indices of the
+    /// square (or the latitude band index if only the grid zone is named).
+    fn cell(&self, precision: i32) -> (i32, bool, i64, i64) {
+        let utmp = self.utm.zone != zonespec::UPS;
+        let (northp, easting, northing) = utm::check_coords(self.utm.zone, self.utm.northp, self.utm.easting, self.utm.northing, true)
+            .unwrap_or((self.utm.northp, self.utm.easting, self.utm.northing));
+
+        if precision == GRID_ZONE_PRECISION {
+            let band = if utmp {
+                i64::from(to_latitude_band(self.utm.to_latlon().latitude))
+            } else {
+                i64::from(easting >= f64::from(UPSEASTING * TILE))
+            };
+
+            return (self.utm.zone, northp, band, 0);
+        }
+
+        #[allow(clippy::cast_sign_loss)]
+        let d = i64::from(MULT) * i64::from(TILE) / i64::from(BASE).pow(precision.clamp(0, MAX_PRECISION) as u32);
+        let ix = (easting * f64::from(MULT)).floor() as i64;
+        let iy = (northing * f64::from(MULT)).floor() as i64;
+
+        (self.utm.zone, northp, ix.div_euclid(d), iy.div_euclid(d))
+    }
+}
+
+impl PartialEq for Mgrs {
+    fn eq(&self, other: &Self) -> bool {
+        self.precision == other.precision && self.cell(self.precision) == other.cell(other.precision)
+    }
+}
+
+impl Eq for Mgrs {}
+
+impl core::hash::Hash for Mgrs {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.precision.hash(state);
+        self.cell(self.precision).hash(state);
+    }
 }
 
 fn utm_row(band_idx: i32, col_idx: i32, row_idx: i32) -> i32 {
@@ -377,7 +1575,7 @@ fn utm_row(band_idx: i32, col_idx: i32, row_idx: i32) -> i32 {
         94
     };
 
-    let base_row = (min_row + max_row) / 2 - UTM_ROW_PERIOD / 2;
+    let base_row = i32::midpoint(min_row, max_row) - UTM_ROW_PERIOD / 2;
     // Offset row_idx by the multiple of UTM_ROW_PERIOD which brings it as close as
     // possible to the center of the latitude band, (min_row + max_row) / 2.
     // (Add MAXUTM_S_ROW = 5 * UTM_ROW_PERIOD to ensure operand is positive.0)
@@ -414,9 +1612,27 @@ fn utm_row(band_idx: i32, col_idx: i32, row_idx: i32) -> i32 {
 impl FromStr for Mgrs {
     type Err = Error;
 
-    #[allow(clippy::too_many_lines)]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let value = s.to_ascii_uppercase();
+        Self::parse_mgrs(s, true)
+    }
+}
+
+impl Mgrs {
+    /// Parses an MGRS string. If `centerp` is set, the point is placed in the
+    /// middle of the grid square, otherwise at its southwest corner.
+    #[allow(clippy::too_many_lines)]
+    fn parse_mgrs(s: &str, centerp: bool) -> Result<Self, Error> {
+        // Allow the common spaced grouping (e.g. "4Q FJ 1234 5678"), but if the
+        // easting and northing are separate groups they must be the same length
+        let mut groups = s.split_whitespace().rev();
+        if let (Some(last), Some(second_last)) = (groups.next(), groups.next()) {
+            let is_digits = |group: &str| group.bytes().all(|c| c.is_ascii_digit());
+            if is_digits(last) && is_digits(second_last) && last.len() != second_last.len() {
+                return Err(Error::InvalidMgrs(format!("Easting and northing have different numbers of digits in {s}")));
+            }
+        }
+
+        let value = s.split_whitespace().collect::<String>().to_ascii_uppercase();
         let mut p = 0;
         let len = value.len();
         if !value.is_ascii() {
@@ -495,26 +1711,11 @@ impl FromStr for Mgrs {
         let northp = band_idx >= utmp.ternary(10, 2);
 
         if p == len { // Grid zone only (ignore centerp)
-            // Approx length of a degree of meridian arc in units of tile
-            let deg = (f64::from(UTM_N_SHIFT)) / f64::from(dms::QD * TILE);
-            let (x, y) = if utmp {
-                // Pick central meridian except for 31V
-                let x = f64::from(TILE) * (zone == 31 && band_idx == 17).ternary(4.0, 5.0);
-                // TODO: continue from here
-                let y_add = northp.ternary(0.0, f64::from(UTM_N_SHIFT));
-                let y = (8.0 * (f64::from(band_idx) - 9.5) * deg + 0.5).floor() * f64::from(TILE) + y_add;
-
-                (x, y)
-            } else {
-                let x_cond = band_idx.is_odd().ternary(1.0, -1.0);
-                let x = (x_cond * (4.0 * deg + 0.5).floor() + f64::from(UPSEASTING)) * f64::from(TILE);
-                let y = f64::from(UPSEASTING * TILE);
-                (x, y)
-            };
+            let (x, y) = grid_zone_center(zone, band_idx);
 
             return Ok(Mgrs {
                 utm: UtmUps::new(zone, northp, x, y),
-                precision: -1
+                precision: GRID_ZONE_PRECISION,
             })
         } else if len - p < 2 {
             return Err(Error::InvalidMgrs(format!("Missing row letter in {value}")));
@@ -701,28 +1902,34 @@ impl FromStr for Mgrs {
         }
 
         let precision = (len - p) / 2;
-        let mut unit = 1;
-        let mut x = col_idx;
-        let mut y = row_idx;
+
+        if precision > MAX_PRECISION as usize {
+            return Err(Error::InvalidMgrs(format!("More than {} digits in {}", 2*MAX_PRECISION, &value[p..])));
+        }
+
+        // 11 digits past the 100 km square overflow an i32
+        let mut unit = 1_i64;
+        let mut x = i64::from(col_idx);
+        let mut y = i64::from(row_idx);
 
         for i in 0..precision {
-            unit *= BASE;
+            unit *= i64::from(BASE);
             let x_char = chars[p + i];
             let x_idx = if x_char.is_ascii_digit() {
-                i32::from(x_char - b'0')
+                i64::from(x_char - b'0')
             } else {
                 return Err(Error::InvalidMgrs(format!("Encountered a non-digit in {}", &value[p..])));
             };
 
             let y_char = chars[p + i + precision];
             let y_idx = if y_char.is_ascii_digit() {
-                i32::from(y_char - b'0')
+                i64::from(y_char - b'0')
             } else {
                 return Err(Error::InvalidMgrs(format!("Encountered a non-digit in {}", &value[p..])));
             };
             
-            x = BASE * x + x_idx;
-            y = BASE * y + y_idx;
+            x = i64::from(BASE) * x + x_idx;
+            y = i64::from(BASE) * y + y_idx;
         }
 
         if (len - p) % 2 == 1 {
@@ -733,19 +1940,14 @@ impl FromStr for Mgrs {
             return Err(Error::InvalidMgrs(format!("Not an even number of digits in {}", &value[p..])));
         }
 
-        if precision > MAX_PRECISION as usize {
-            return Err(Error::InvalidMgrs(format!("More than {} digits in {}", 2*MAX_PRECISION, &value[p..])));
-        }
-
-        let centerp = true;
         if centerp {
             unit *= 2;
             x = 2 * x + 1;
             y = 2 * y + 1;
         }
 
-        let x = (f64::from(TILE) * f64::from(x)) / f64::from(unit);
-        let y = (f64::from(TILE) * f64::from(y)) / f64::from(unit);
+        let x = (f64::from(TILE) * x as f64) / unit as f64;
+        let y = (f64::from(TILE) * y as f64) / unit as f64;
 
         Ok(Self {
             utm: UtmUps::new(
@@ -759,82 +1961,274 @@ impl FromStr for Mgrs {
     }
 }
 
-pub(crate) fn to_latitude_band(lat: f64) -> i32 {
-    let lat_int = lat.floor() as i32;
-    (-10).max(9.min((lat_int + 80) / 8 - 10))
+/// Returns the representative `(x, y)` of a grid zone, given the index of its
+/// band letter in [`LATBAND`] (UTM) or [`UPSBAND`] (UPS). For UTM this is on the
+/// central meridian (or at 400 km easting in 31V) at the middle of the latitude
+/// band, rounded to 100 km. For UPS it is 400 km east or west of the pole.
+fn grid_zone_center(zone: i32, band_idx: i32) -> (f64, f64) {
+    // Approx length of a degree of meridian arc in units of tile
+    let deg = (f64::from(UTM_N_SHIFT)) / f64::from(dms::QD * TILE);
+    let utmp = zone != UPS;
+
+    if utmp {
+        // Pick central meridian except for 31V
+        let x = f64::from(TILE) * (zone == 31 && band_idx == 17).ternary(4.0, 5.0);
+        let y_add = (band_idx >= 10).ternary(0.0, f64::from(UTM_N_SHIFT));
+        let y = (8.0 * (f64::from(band_idx) - 9.5) * deg + 0.5).floor() * f64::from(TILE) + y_add;
+
+        (x, y)
+    } else {
+        let x_cond = band_idx.is_odd().ternary(1.0, -1.0);
+        let x = (x_cond * (4.0 * deg + 0.5).floor() + f64::from(UPSEASTING)) * f64::from(TILE);
+        let y = f64::from(UPSEASTING * TILE);
+        (x, y)
+    }
 }
 
-pub(crate) fn check_coords(utmp: bool, northp: bool, x: f64, y: f64) -> Result<(bool, f64, f64), Error> {
-    lazy_static! {
-        static ref ANG_EPS: f64 = 1_f64 * 2_f64.powi(-(f64::DIGITS as i32 - 25));
+/// Latitude ranges within which the UTM/UPS zones have fixed longitude limits,
+/// split at the equator
+pub(crate) const BBOX_LAT_BANDS: [(f64, f64); 7] = [
+    (-90.0, -80.0),
+    (-80.0, 0.0),
+    (0.0, 56.0),
+    (56.0, 64.0),
+    (64.0, 72.0),
+    (72.0, 84.0),
+    (84.0, 90.0),
+];
+
+/// Most squares [`Mgrs::squares_in_bbox`] will return
+const MAX_BBOX_SQUARES: usize = 1_000_000;
+
+/// Returns the zones and their longitude limits as `(zone, lon0, lon1)` within
+/// the range of [`BBOX_LAT_BANDS`] starting at `lat0`
+pub(crate) fn zone_longitudes(lat0: f64) -> Vec<(i32, f64, f64)> {
+    if !(-80.0..84.0).contains(&lat0) {
+        return Vec::from([(UPS, -f64::from(dms::HD), f64::from(dms::HD))]);
     }
 
-    let x_int = (x / f64::from(TILE)).floor() as i32;
-    let y_int = (y / f64::from(TILE)).floor() as i32;
-    let ind = utmp.ternary(2, 0) + northp.ternary(1, 0);
+    let standard = |zone: i32| (zone, f64::from(6 * zone - 186), f64::from(6 * zone - 180));
+
+    if lat0.eps_eq(56.0) {
+        // The Norway exception
+        (MINUTMZONE..=MAXUTMZONE)
+            .map(|zone| match zone {
+                31 => (31, 0.0, 3.0),
+                32 => (32, 3.0, 12.0),
+                _ => standard(zone),
+            })
+            .collect()
+    } else if lat0.eps_eq(72.0) {
+        // The Svalbard exception
+        (MINUTMZONE..=MAXUTMZONE)
+            .filter_map(|zone| match zone {
+                31 => Some((31, 0.0, 9.0)),
+                33 => Some((33, 9.0, 21.0)),
+                35 => Some((35, 21.0, 33.0)),
+                37 => Some((37, 33.0, 42.0)),
+                32 | 34 | 36 => None,
+                _ => Some(standard(zone)),
+            })
+            .collect()
+    } else {
+        (MINUTMZONE..=MAXUTMZONE).map(standard).collect()
+    }
+}
 
-    let mut x_new = x;
-    let mut y_new = y;
+/// Length in meters of an arc of `degrees` along a parallel at `lat` (or along
+/// a meridian, for a `lat` of `0`), on a sphere of Earth's mean radius
+fn arc_length(degrees: f64, lat: f64) -> f64 {
+    degrees.to_radians() * EARTH_MEAN_RADIUS_M * lat.to_radians().cos()
+}
 
-    if !(MIN_EASTING[ind]..MAX_EASTING[ind]).contains(&x_int) {
-        if x_int == MAX_EASTING[ind] && x.eps_eq(f64::from(MAX_EASTING[ind] * TILE)) {
-            x_new -= *ANG_EPS;
-        } else {
-            return Err(Error::InvalidMgrs(
-                format!(
-                    "Easting {:.2}km not in MGRS/{} range for {} hemisphere [{:.2}km, {:.2}km]",
-                    x / 1000.0,
-                    utmp.ternary("UTM", "UPS"),
-                    northp.ternary("N", "S"),
-                    MIN_EASTING[ind] * (TILE / 1000),
-                    MAX_EASTING[ind] * (TILE / 1000),
-                )
-            ));
-        }
-    }
-
-    if !(MIN_NORTHING[ind]..MAX_NORTHING[ind]).contains(&y_int) {
-        if y_int == MAX_NORTHING[ind] && y.eps_eq(f64::from(MAX_NORTHING[ind] * TILE)) {
-            y_new -= *ANG_EPS;
-        } else {
-            return Err(Error::InvalidMgrs(
-                format!(
-                    "Northing {:.2}km not in MGRS/{} range for {} hemisphere [{:.2}km, {:.2}km]",
-                    y / 1000.0,
-                    utmp.ternary("UTM", "UPS"),
-                    northp.ternary("N", "S"),
-                    MIN_NORTHING[ind] * (TILE / 1000),
-                    MAX_NORTHING[ind] * (TILE / 1000),
-                )
-            ));
-        }
-    }
-
-    let (northp_new, y_new) = if utmp {
-        if northp && y_int < MINUTM_S_ROW {
-            (false, y_new + f64::from(UTM_N_SHIFT))
-        } else if !northp && y_int >= MAXUTM_S_ROW {
-            if y.eps_eq(f64::from(MAXUTM_S_ROW * TILE)) {
-                (northp, y_new - *ANG_EPS)
-            } else {
-                (true, y - f64::from(UTM_N_SHIFT))
+/// Projects the outline of a lat/lon rectangle, following its edges closely
+/// enough that the result is accurate to well under a grid square of `size`:
+/// each edge is split into pieces no longer than a square, and into at least
+/// 16 pieces to follow its curvature at low precisions.
+fn bbox_outline(lat0: f64, lat1: f64, lon0: f64, lon1: f64, size: f64, project: impl Fn(f64, f64) -> (f64, f64)) -> Vec<(f64, f64)> {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let steps = |length: f64| ((length / size).ceil() as usize).max(16);
+    let lat_steps = steps(arc_length(lat1 - lat0, 0.0));
+    let south_steps = steps(arc_length(lon1 - lon0, lat0));
+    let north_steps = steps(arc_length(lon1 - lon0, lat1));
+
+    let mut outline = Vec::with_capacity(2 * lat_steps + south_steps + north_steps);
+
+    #[allow(clippy::cast_precision_loss)]
+    let lerp = |from: f64, to: f64, i: usize, n: usize| from + (to - from) * (i as f64 / n as f64);
+
+    for i in 0..south_steps {
+        outline.push(project(lat0, lerp(lon0, lon1, i, south_steps)));
+    }
+    for i in 0..lat_steps {
+        outline.push(project(lerp(lat0, lat1, i, lat_steps), lon1));
+    }
+    for i in 0..north_steps {
+        outline.push(project(lat1, lerp(lon1, lon0, i, north_steps)));
+    }
+    for i in 0..lat_steps {
+        outline.push(project(lerp(lat1, lat0, i, lat_steps), lon0));
+    }
+
+    outline
+}
+
+/// A line segment between two grid points
+type Segment = ((f64, f64), (f64, f64));
+
+/// Calls `visit` with the row and column of every grid square of `size` that
+/// the polygon `outline` covers part of, along with a point in that part.
+fn walk_grid(outline: &[(f64, f64)], size: f64, mut visit: impl FnMut(i64, i64, f64, f64)) {
+    let (y_min, y_max) = outline.iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &(_, y)| (lo.min(y), hi.max(y)));
+
+    #[allow(clippy::cast_possible_truncation)]
+    let (row0, row1) = {
+        let row0 = (y_min / size).floor() as i64;
+        (row0, row0.max((y_max / size).ceil() as i64 - 1))
+    };
+
+    let mut pieces = Vec::new();
+    let mut middle = Vec::new();
+    let mut intervals: Vec<(f64, f64)> = Vec::new();
+
+    for row in row0..=row1 {
+        #[allow(clippy::cast_precision_loss)]
+        let (y0, y1) = (
+            y_min.max(row as f64 * size),
+            y_max.min((row + 1) as f64 * size),
+        );
+
+        pieces.clear();
+        strip_boundary(outline, y0, y1, &mut pieces);
+
+        middle.clear();
+        line_pieces(outline, f64::midpoint(y0, y1), &mut middle);
+
+        // The covered area spans the same eastings as its boundary
+        intervals.clear();
+        intervals.extend(pieces.iter().map(|&((ax, _), (bx, _))| (ax.min(bx), ax.max(bx))));
+        intervals.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut last_col = i64::MIN;
+        let mut i = 0;
+        while i < intervals.len() {
+            let (x0, mut x1) = intervals[i];
+            while i + 1 < intervals.len() && intervals[i + 1].0 <= x1 {
+                i += 1;
+                x1 = x1.max(intervals[i].1);
             }
-        } else {
-            (northp, y_new)
+            i += 1;
+
+            #[allow(clippy::cast_possible_truncation)]
+            let (col0, col1) = {
+                let col0 = (x0 / size).floor() as i64;
+                (col0.max(last_col + 1), col0.max((x1 / size).ceil() as i64 - 1))
+            };
+
+            for col in col0..=col1 {
+                #[allow(clippy::cast_precision_loss)]
+                let (cx0, cx1) = (col as f64 * size, (col + 1) as f64 * size);
+
+                let (x, y) = point_between(&middle, cx0, cx1)
+                    .or_else(|| point_between(&pieces, cx0, cx1))
+                    .unwrap_or((f64::midpoint(cx0, cx1), f64::midpoint(y0, y1)));
+
+                visit(row, col, x, y);
+            }
+
+            last_col = last_col.max(col1);
         }
-    } else {
-        (northp, y_new)
-    };
+    }
+}
+
+/// Appends the boundary of the part of the polygon `outline` between northings
+/// `y0` and `y1`: the polygon's edges within the strip and the parts of the
+/// lines `y = y0` and `y = y1` inside the polygon.
+#[allow(clippy::float_cmp)]
+fn strip_boundary(outline: &[(f64, f64)], y0: f64, y1: f64, pieces: &mut Vec<Segment>) {
+    let edges = outline.iter().zip(outline.iter().cycle().skip(1));
+
+    for (&(ax, ay), &(bx, by)) in edges {
+        if ay.max(by) < y0 || ay.min(by) > y1 {
+            continue;
+        }
+
+        let x_at = |y: f64| (ay == by).ternary_lazy(|| ax, || ax + (bx - ax) * (y - ay) / (by - ay));
+        let (ya, yb) = (ay.clamp(y0, y1), by.clamp(y0, y1));
+
+        pieces.push(((x_at(ya), ya), (x_at(yb), yb)));
+    }
 
-    Ok((northp_new, x_new, y_new))
+    line_pieces(outline, y0, pieces);
+    line_pieces(outline, y1, pieces);
 }
 
-impl Display for Mgrs {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        lazy_static! {
-            static ref ANG_EPS: f64 = 1_f64 * 2_f64.powi(-(f64::MANTISSA_DIGITS as i32 - 7));
+/// Appends the parts of the line at northing `y` that are inside the polygon `outline`
+fn line_pieces(outline: &[(f64, f64)], y: f64, pieces: &mut Vec<Segment>) {
+    let mut crossings: Vec<f64> = outline.iter()
+        .zip(outline.iter().cycle().skip(1))
+        .filter(|(&(_, ay), &(_, by))| (ay <= y) != (by <= y))
+        .map(|(&(ax, ay), &(bx, by))| ax + (bx - ax) * (y - ay) / (by - ay))
+        .collect();
+    crossings.sort_by(f64::total_cmp);
+
+    pieces.extend(crossings.chunks_exact(2).map(|pair| ((pair[0], y), (pair[1], y))));
+}
+
+/// Returns a point on one of the segments with an easting in `[x0, x1)`
+#[allow(clippy::float_cmp)]
+fn point_between(pieces: &[Segment], x0: f64, x1: f64) -> Option<(f64, f64)> {
+    pieces.iter().find_map(|&((ax, ay), (bx, by))| {
+        if ax == bx {
+            return (x0 <= ax && ax < x1).then_some((ax, f64::midpoint(ay, by)));
         }
 
+        let lo = ax.min(bx).max(x0);
+        let hi = ax.max(bx).min(x1);
+        let x = f64::midpoint(lo, hi);
+
+        (lo < hi).then_some((x, ay + (by - ay) * (x - ax) / (bx - ax)))
+    })
+}
+
+pub(crate) fn to_latitude_band(lat: f64) -> i32 {
+    let lat_int = lat.floor() as i32;
+    (-10).max(9.min((lat_int + 80) / 8 - 10))
+}
+
+/// Parses an MGRS string. Equivalent to [`str::parse`] via [`FromStr`].
+/// 
+/// # Errors
+/// 
+/// Returns an error if the string is not a valid MGRS coordinate.
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::Mgrs;
+/// 
+/// let coord = Mgrs::try_from("18TWL8566411315").unwrap();
+/// assert_eq!(coord.zone(), 18);
+/// assert_eq!(coord.precision(), 5);
+/// 
+/// let coord: Result<Mgrs, _> = "18TWL856641131".try_into();
+/// assert!(coord.is_err());
+/// ```
+impl TryFrom<&str> for Mgrs {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Mgrs {
+    /// Formats the coordinate as ASCII into a fixed size buffer, returning the
+    /// buffer and the length of the string in it
+    fn format_bytes(&self) -> ([u8; MAX_STRING_LEN], usize) {
+        let ang_eps = 1_f64 * 2_f64.powi(-(f64::MANTISSA_DIGITS as i32 - 7));
+
         let lat = if self.utm.zone > 0 {
             // Does a rough estimate for latitude determine the latitude band?
             let y_est = self.utm.northp.ternary_lazy(|| self.utm.northing, || self.utm.northing - f64::from(UTM_N_SHIFT));
@@ -866,10 +2260,10 @@ impl Display for Mgrs {
         
         // Other Forward call
         let utmp = self.utm.zone != 0;
-        let (northp, easting, northing) = check_coords(utmp, self.utm.northp, self.utm.easting, self.utm.northing)
+        let (northp, easting, northing) = utm::check_coords(self.utm.zone, self.utm.northp, self.utm.easting, self.utm.northing, true)
             .expect("Invalid coords; please report this to the library author");
-        // Create pre-allocated string of the correct length
-        let mut mgrs_str = [0u8; 2 + 3 + 2*MAX_PRECISION as usize];
+        // Create pre-allocated string of the maximum length
+        let mut mgrs_str = [0u8; MAX_STRING_LEN];
         let zone = self.utm.zone - 1;
         let mut z: usize = utmp.ternary(2, 0);
 
@@ -893,8 +2287,10 @@ impl Display for Mgrs {
 
         #[allow(clippy::cast_sign_loss)]
         if utmp {
-            // Correct fuzziness in latitude near equator
-            let band_idx = (lat.abs() < *ANG_EPS).ternary_lazy(|| northp.ternary(0, -1), || to_latitude_band(lat));
+            // Correct fuzziness in latitude near equator. Uses the hemisphere the
+            // point was given in, since check_coords moves northings on the
+            // equator to the southern hemisphere
+            let band_idx = (lat.abs() < ang_eps).ternary_lazy(|| self.utm.northp.ternary(0, -1), || to_latitude_band(lat));
             let col_idx = xh - MINUTMCOL;
             let row_idx = utm_row(band_idx, col_idx, yh % UTM_ROW_PERIOD);
 
@@ -905,22 +2301,26 @@ impl Display for Mgrs {
 
             mgrs_str[z] = LATBAND.as_bytes()[(10 + band_idx) as usize];
             z += 1;
-            mgrs_str[z] = UTMCOLS[(zone % 3) as usize].as_bytes()[col_idx as usize];
-            z += 1;
-            let idx = (yh + zone.is_odd().ternary(UTM_EVEN_ROW_SHIFT, 0)) % UTM_ROW_PERIOD;
-            mgrs_str[z] = UTMROW.as_bytes()[idx as usize];
-            z += 1;
+            if !self.grid_zone_only() {
+                mgrs_str[z] = UTMCOLS[(zone % 3) as usize].as_bytes()[col_idx as usize];
+                z += 1;
+                let idx = (yh + zone.is_odd().ternary(UTM_EVEN_ROW_SHIFT, 0)) % UTM_ROW_PERIOD;
+                mgrs_str[z] = UTMROW.as_bytes()[idx as usize];
+                z += 1;
+            }
         } else {
             let eastp = xh >= UPSEASTING;
             let band_idx: usize = northp.ternary(2, 0) + eastp.ternary(1, 0);
             mgrs_str[z] = UPSBAND.as_bytes()[band_idx];
             z += 1;
-            let idx = xh - eastp.ternary(UPSEASTING, northp.ternary(MINUPS_N_IND, MINUPS_S_IND));
-            mgrs_str[z] = UPSCOLS[band_idx].as_bytes()[idx as usize];
-            z += 1;
-            let idx = yh - northp.ternary(MINUPS_N_IND, MINUPS_S_IND);
-            mgrs_str[z] = UPSROWS[usize::from(northp)].as_bytes()[idx as usize];
-            z += 1;
+            if !self.grid_zone_only() {
+                let idx = xh - eastp.ternary(UPSEASTING, northp.ternary(MINUPS_N_IND, MINUPS_S_IND));
+                mgrs_str[z] = UPSCOLS[band_idx].as_bytes()[idx as usize];
+                z += 1;
+                let idx = yh - northp.ternary(MINUPS_N_IND, MINUPS_S_IND);
+                mgrs_str[z] = UPSROWS[usize::from(northp)].as_bytes()[idx as usize];
+                z += 1;
+            }
         }
 
         if self.precision > 0 {
@@ -940,6 +2340,28 @@ impl Display for Mgrs {
             }
         }
 
-        write!(f, "{}", String::from_utf8_lossy(&mgrs_str).trim_end_matches('\0'))
+        #[allow(clippy::cast_sign_loss)]
+        let len = z + 2 * self.precision.max(0) as usize;
+
+        (mgrs_str, len)
+    }
+}
+
+/// Formats the coordinate as an MGRS string, e.g. `18TWL856641113154`. See
+/// [`write_to`](Mgrs::write_to) to append to an existing [`String`] instead.
+/// 
+/// The letters are uppercase, following the MGRS convention. The alternate
+/// flag (`{:#}`) formats them in lowercase instead, e.g. `18twl856641113154`,
+/// see [`to_string_lowercase`](Mgrs::to_string_lowercase).
+impl Display for Mgrs {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (mut mgrs_str, len) = self.format_bytes();
+
+        if f.alternate() {
+            mgrs_str[..len].make_ascii_lowercase();
+        }
+
+        // Only ever contains ASCII
+        f.write_str(core::str::from_utf8(&mgrs_str[..len]).map_err(|_| core::fmt::Error)?)
     }
 }
@@ -0,0 +1,185 @@
+use alloc::format;
+use core::{fmt::Display, str::FromStr};
+
+use crate::{Error, latlon::LatLon, mgrs::Mgrs, utm::UtmUps};
+
+/// A point in any of the supported coordinate systems, for code that handles
+/// coordinates of mixed types (e.g. parsed from heterogeneous input).
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::{LatLon, Position};
+/// 
+/// let positions: Vec<Position> = ["40.748333 -73.985278", "18n 585664.121 4511315.422", "18TWL856641113154"]
+///     .into_iter()
+///     .map(|value| value.parse().unwrap())
+///     .collect();
+/// 
+/// assert!(matches!(positions[0], Position::LatLon(_)));
+/// assert!(matches!(positions[1], Position::UtmUps(_)));
+/// assert!(matches!(positions[2], Position::Mgrs(_)));
+/// 
+/// let reference = LatLon::create(40.748333, -73.985278).unwrap();
+/// for position in positions {
+///     assert!(position.to_latlon().haversine(&reference) < 1.0);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Position {
+    LatLon(LatLon),
+    UtmUps(UtmUps),
+    Mgrs(Mgrs),
+}
+
+impl Position {
+    /// Converts the position to [`LatLon`].
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{Mgrs, Position};
+    /// 
+    /// let position = Position::from(Mgrs::parse_str("18TWL856641113154").unwrap());
+    /// let coord = position.to_latlon();
+    /// 
+    /// assert!((coord.latitude() - 40.748333).abs() < 1e-6);
+    /// assert!((coord.longitude() - -73.985278).abs() < 1e-6);
+    /// ```
+    pub fn to_latlon(&self) -> LatLon {
+        match self {
+            Position::LatLon(coord) => *coord,
+            Position::UtmUps(coord) => coord.to_latlon(),
+            Position::Mgrs(coord) => coord.to_latlon(),
+        }
+    }
+
+    /// Converts the position to [`UtmUps`].
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, Position};
+    /// 
+    /// let position = Position::from(LatLon::create(40.748333, -73.985278).unwrap());
+    /// let coord = position.to_utmups();
+    /// 
+    /// assert_eq!(coord.zone(), 18);
+    /// assert!((coord.easting() - 585664.121).abs() < 1e-3);
+    /// assert!((coord.northing() - 4511315.422).abs() < 1e-3);
+    /// ```
+    pub fn to_utmups(&self) -> UtmUps {
+        match self {
+            Position::LatLon(coord) => coord.to_utmups(),
+            Position::UtmUps(coord) => *coord,
+            Position::Mgrs(coord) => coord.to_utmups(),
+        }
+    }
+
+    /// Converts the position to [`Mgrs`] with the given precision. An [`Mgrs`]
+    /// position keeps its location but takes on the new precision.
+    /// `precision` is clamped to the range `[-1, 11]`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, Mgrs, Position};
+    /// 
+    /// let position = Position::from(LatLon::create(40.748333, -73.985278).unwrap());
+    /// assert_eq!(position.to_mgrs(6).to_string(), "18TWL856641113154");
+    /// 
+    /// let position = Position::from(Mgrs::parse_str("18TWL856641113154").unwrap());
+    /// assert_eq!(position.to_mgrs(3).to_string(), "18TWL856113");
+    /// ```
+    pub fn to_mgrs(&self, precision: i32) -> Mgrs {
+        match self {
+            Position::LatLon(coord) => coord.to_mgrs(precision),
+            Position::UtmUps(coord) => coord.to_mgrs(precision),
+            Position::Mgrs(coord) => coord.to_utmups().to_mgrs(precision),
+        }
+    }
+}
+
+impl From<LatLon> for Position {
+    fn from(value: LatLon) -> Self {
+        Position::LatLon(value)
+    }
+}
+
+impl From<UtmUps> for Position {
+    fn from(value: UtmUps) -> Self {
+        Position::UtmUps(value)
+    }
+}
+
+impl From<Mgrs> for Position {
+    fn from(value: Mgrs) -> Self {
+        Position::Mgrs(value)
+    }
+}
+
+/// Parses a string as whichever coordinate type it is written in:
+/// 
+/// * [`UtmUps`]: a zone and hemisphere, easting and northing, e.g. `18n 585664.121 4511315.422`
+/// * [`LatLon`]: a latitude/longitude pair in any format accepted by [`LatLon`]'s
+///   [`FromStr`], e.g. `40.748333 -73.985278` or `40°44'54"N 73°59'07"W`
+/// * [`Mgrs`]: anything else, e.g. `18TWL856641113154`
+/// 
+/// # Errors
+/// 
+/// Returns [`Error::InvalidCoord`] if the string isn't a valid coordinate of any type.
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::Position;
+/// 
+/// let position: Position = "40°44'54\"N 73°59'07\"W".parse().unwrap();
+/// assert!(matches!(position, Position::LatLon(_)));
+/// 
+/// let position: Position = "4Q FJ 1234 5678".parse().unwrap();
+/// assert!(matches!(position, Position::Mgrs(_)));
+/// 
+/// assert!("not a coordinate".parse::<Position>().is_err());
+/// ```
+impl FromStr for Position {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if looks_like_utmups(s) {
+            return s.parse::<UtmUps>().map(Position::UtmUps);
+        }
+
+        if let Ok(coord) = s.parse::<LatLon>() {
+            return Ok(Position::LatLon(coord));
+        }
+
+        s.parse::<Mgrs>()
+            .map(Position::Mgrs)
+            .map_err(|_| Error::InvalidCoord(format!("\"{s}\" is not a valid lat/lon, UTM/UPS or MGRS coordinate")))
+    }
+}
+
+/// Whether the string has the `<zone><hemisphere> <easting> <northing>` shape
+/// of a UTM/UPS coordinate
+fn looks_like_utmups(value: &str) -> bool {
+    let mut parts = value.split_whitespace();
+
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(zone_hemi), Some(_), Some(_), None) => zone_hemi
+            .strip_suffix(['n', 'N', 's', 'S'])
+            .is_some_and(|zone| zone.len() <= 2 && zone.bytes().all(|c| c.is_ascii_digit())),
+        _ => false,
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Position::LatLon(coord) => coord.fmt(f),
+            Position::UtmUps(coord) => coord.fmt(f),
+            Position::Mgrs(coord) => coord.fmt(f),
+        }
+    }
+}
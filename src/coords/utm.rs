@@ -1,4 +1,10 @@
-use crate::{latlon::LatLon, utility::{dms, GeoMath}, mgrs::{to_latitude_band, self, Mgrs}, Error, ThisOrThat, projections::{transverse_mercator::TransverseMercator, polar_stereographic::PolarStereographic}};
+use alloc::{format, string::ToString, vec::Vec};
+use core::str::FromStr;
+
+#[cfg(not(feature = "std"))]
+use num::traits::Float;
+
+use crate::{latlon::{LatLon, wrap_longitude}, utility::{dms, GeoMath}, mgrs::{to_latitude_band, self, Mgrs}, Error, ThisOrThat, map_projection, projections::{transverse_mercator::TransverseMercator, polar_stereographic::PolarStereographic, wgs84}, ellipsoid::Ellipsoid};
 
 pub(crate) mod zonespec {
     pub(crate) const INVALID: i32 = -4;
@@ -62,6 +68,57 @@ const MAX_NORTHING: [i32; 4] = [
 /// automatically determine whether it should be UTM/UPS. It becomes a UPS coordinate
 /// if the latitude is outside the range `[-84,84]`. A zone value of `0`
 /// designates UPS.
+/// 
+/// The poles are represented by the UPS point at easting and northing
+/// `2,000,000`. Since longitude is undefined there, converting a pole back to
+/// [`LatLon`] always gives a longitude of `0`.
+/// 
+/// Equality compares all fields exactly, consistently with the ordering below
+/// (so a northing of `0.0` differs from `-0.0`). Use [`approx_eq`](#method.approx_eq)
+/// to compare within a tolerance. Hashing agrees with equality, so points can
+/// be used as `HashMap` keys:
+/// 
+/// ```
+/// use std::collections::HashSet;
+/// 
+/// use geoconvert::UtmUps;
+/// 
+/// let a = UtmUps::create(18, true, 585664.0, 4511315.0).unwrap();
+/// let b = UtmUps::create(18, true, 585664.0, 4511315.0).unwrap();
+/// let c = UtmUps::create(18, true, 585664.5, 4511315.0).unwrap();
+/// 
+/// let points: HashSet<UtmUps> = [a, b, c].into_iter().collect();
+/// assert_eq!(points.len(), 2);
+/// assert!(points.contains(&c));
+/// ```
+/// 
+/// 
+/// Points are ordered by zone, then hemisphere (south first), then easting,
+/// then northing, comparing the floats with [`f64::total_cmp`]. This gives a
+/// deterministic order for sorting and grouping points by zone, but says
+/// nothing about the geographic distance between points.
+/// 
+/// ```
+/// use geoconvert::UtmUps;
+/// 
+/// let mut points = vec![
+///     UtmUps::create(18, true, 585664.0, 4511315.0).unwrap(),
+///     UtmUps::create(17, true, 585664.0, 4511315.0).unwrap(),
+///     UtmUps::create(18, false, 585664.0, 4511315.0).unwrap(),
+///     UtmUps::create(18, true, 585664.0, 4511314.0).unwrap(),
+///     UtmUps::create(18, true, 585663.0, 4511316.0).unwrap(),
+/// ];
+/// points.sort();
+/// 
+/// let sorted: Vec<String> = points.iter().map(ToString::to_string).collect();
+/// assert_eq!(sorted, [
+///     "17n 585664 4511315",
+///     "18s 585664 4511315",
+///     "18n 585663 4511316",
+///     "18n 585664 4511314",
+///     "18n 585664 4511315",
+/// ]);
+/// ```
 #[allow(clippy::module_name_repetitions)]
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -76,6 +133,31 @@ pub struct UtmUps {
 impl UtmUps {
     /// Internal-only constructor that doesn't check the coordinate
     pub(crate) fn new(zone: i32, northp: bool, easting: f64, northing: f64) -> UtmUps {
+        Self::from_raw(zone, northp, easting, northing)
+    }
+
+    /// Creates a UTM or UPS point from its constituent parts without checking
+    /// them, for already-projected coordinates known to be valid (e.g. from a
+    /// trusted database) where the checks in [`create`](#method.create) would be
+    /// wasted in a hot loop. Unlike [`create`](#method.create), a northing
+    /// across the equator is not normalized to the other hemisphere. Being a
+    /// `const fn`, it can also be used to define constants.
+    /// 
+    /// The result of any conversion of a point that [`create`](#method.create)
+    /// would reject is unspecified: it may be meaningless, or panic. Use
+    /// [`validate`](#method.validate) to check the point later.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::UtmUps;
+    /// 
+    /// const EMPIRE_STATE_BUILDING: UtmUps = UtmUps::from_raw(18, true, 585664.121, 4511315.422);
+    /// 
+    /// assert_eq!(EMPIRE_STATE_BUILDING, UtmUps::create(18, true, 585664.121, 4511315.422).unwrap());
+    /// assert!(EMPIRE_STATE_BUILDING.validate().is_ok());
+    /// ```
+    pub const fn from_raw(zone: i32, northp: bool, easting: f64, northing: f64) -> UtmUps {
         Self {
             zone,
             northp,
@@ -84,18 +166,52 @@ impl UtmUps {
         }
     }
 
+    /// Checks that the point is one that [`create`](#method.create) would
+    /// accept, for points created with [`from_raw`](#method.from_raw).
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidZone`] if the zone is outside the range `[0, 60]`.
+    /// Returns [`Error::UtmOutOfRange`] if the easting or northing is outside
+    /// the valid range for the zone and hemisphere.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::UtmUps;
+    /// 
+    /// assert!(UtmUps::from_raw(18, true, 585664.121, 4511315.422).validate().is_ok());
+    /// 
+    /// assert!(UtmUps::from_raw(61, true, 585664.121, 4511315.422).validate().is_err());
+    /// assert!(UtmUps::from_raw(18, true, 5_856_641.21, 4511315.422).validate().is_err());
+    /// assert!(UtmUps::from_raw(0, true, 585664.121, 4511315.422).validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        Self::create(self.zone, self.northp, self.easting, self.northing).map(|_| ())
+    }
+
     /// Tries to create a UTM or UPS point from its constituent parts. Zone
     /// of `0` designates UPS, otherwise it is UTM.
     /// 
+    /// As in [GeographicLib](https://geographiclib.sourceforge.io/C++/doc/index.html), a UTM northing may extend across the equator into the
+    /// other hemisphere. Such points are normalized to the hemisphere they are
+    /// actually in: a northern point with a negative northing becomes a southern
+    /// point with `10,000,000` added to the northing, and a southern point with a
+    /// northing of `10,000,000` or more becomes a northern point with `10,000,000`
+    /// subtracted. UPS points are never normalized.
+    /// 
     /// # Errors
     /// 
     /// Returns [`Error::InvalidZone`] if the zone is outside the range `[0, 60]`.
-    /// Returns [`Error::InvalidCoord`] if the coordinate is otherwise invalid.
+    /// Returns [`Error::InvalidCoord`] if the easting or northing is `NaN` or
+    /// infinite.
+    /// Returns [`Error::UtmOutOfRange`] if the easting or northing is outside
+    /// the valid range for the zone and hemisphere.
     /// 
     /// # Usage
     /// 
     /// ```
-    /// use geoconvert::UtmUps;
+    /// use geoconvert::{Error, UtmUps};
     /// 
     /// let coord = UtmUps::create(18, true, 585664.121, 4511315.422);
     /// 
@@ -113,6 +229,20 @@ impl UtmUps {
     /// 
     /// let invalid_coord_zone_too_big = UtmUps::create(70, true, 585664.121, 4511315.422);
     /// assert!(invalid_coord_zone_too_big.is_err());
+    /// 
+    /// // Just south of the equator, given relative to the northern hemisphere
+    /// let coord = UtmUps::create(18, true, 500000.0, -1000.0).unwrap();
+    /// assert!(!coord.is_north());
+    /// assert_eq!(coord.northing(), 9_999_000.0);
+    /// 
+    /// // Just north of the equator, given relative to the southern hemisphere
+    /// let coord = UtmUps::create(18, false, 500000.0, 10_001_000.0).unwrap();
+    /// assert!(coord.is_north());
+    /// assert_eq!(coord.northing(), 1000.0);
+    /// 
+    /// // The error gives the valid ranges of the easting and northing
+    /// let err = UtmUps::create(18, true, 2_000_000.0, 4511315.422).unwrap_err();
+    /// assert!(matches!(err, Error::UtmOutOfRange { easting: 2_000_000.0, easting_limits: (0.0, 1_000_000.0), .. }));
     /// ```
     pub fn create(zone: i32, northp: bool, easting: f64, northing: f64) -> Result<UtmUps, Error> {
         // Make sure zone is a valid value
@@ -120,13 +250,55 @@ impl UtmUps {
             return Err(Error::InvalidZone(zone));
         }
 
-        let utmp = zone != zonespec::UPS;
+        if !(easting.is_finite() && northing.is_finite()) {
+            return Err(Error::InvalidCoord(format!("Easting {easting} and northing {northing} must be finite")));
+        }
 
-        check_coords(utmp, northp, easting, northing, false)?;
+        let (northp, easting, northing) = check_coords(zone, northp, easting, northing, false)?;
 
         Ok(UtmUps::new(zone, northp, easting, northing))
     }
 
+    /// Decomposes the point into `(zone, northp, easting, northing)`, in the
+    /// same order as the arguments of [`create`](#method.create). The reverse
+    /// of [`from_parts`](#method.from_parts).
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::UtmUps;
+    /// 
+    /// let coord = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
+    /// let (zone, northp, easting, northing) = coord.into_parts();
+    /// 
+    /// assert_eq!(zone, 18);
+    /// assert!(northp);
+    /// assert_eq!((easting, northing), (585664.121, 4511315.422));
+    /// ```
+    pub fn into_parts(self) -> (i32, bool, f64, f64) {
+        (self.zone, self.northp, self.easting, self.northing)
+    }
+
+    /// Tries to create a point from `(zone, northp, easting, northing)`,
+    /// validated and normalized the same way as [`create`](#method.create).
+    /// The reverse of [`into_parts`](#method.into_parts).
+    /// 
+    /// # Errors
+    /// 
+    /// See [`create`](#method.create).
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::UtmUps;
+    /// 
+    /// let coord = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
+    /// assert_eq!(UtmUps::from_parts(coord.into_parts()).unwrap(), coord);
+    /// 
+    /// assert!(UtmUps::from_parts((61, true, 585664.121, 4511315.422)).is_err());
+    /// ```
+    pub fn from_parts((zone, northp, easting, northing): (i32, bool, f64, f64)) -> Result<UtmUps, Error> {
+        UtmUps::create(zone, northp, easting, northing)
+    }
+
     /// Returns the UTM zone.
     /// 
     /// # Example
@@ -153,6 +325,60 @@ impl UtmUps {
         self.northp
     }
 
+    /// Returns whether the coordinate is UPS (zone `0`) rather than UTM.
+    /// 
+    /// UPS coordinates are on one of two polar stereographic projections, picked
+    /// by [`is_north`](#method.is_north), with the pole at easting and northing
+    /// `2,000,000`. See [`ups_bounds`](#method.ups_bounds) for their ranges.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::{LatLon, UtmUps};
+    /// 
+    /// let coord = LatLon::create(86.0, 10.0).unwrap().to_utmups();
+    /// assert!(coord.is_ups());
+    /// assert!(coord.is_north());
+    /// 
+    /// let coord = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
+    /// assert!(!coord.is_ups());
+    /// ```
+    pub fn is_ups(&self) -> bool {
+        self.zone == zonespec::UPS
+    }
+
+    /// Returns the range of UPS eastings and northings in the northern
+    /// (`northp`) or southern hemisphere, as
+    /// `(min_easting, max_easting, min_northing, max_northing)` in meters.
+    /// 
+    /// These cover the polar regions north of 84° and south of 80°, with a
+    /// margin to overlap with UTM. They're the same for eastings and northings,
+    /// centered on the pole at `2,000,000`, and wider in the south since the
+    /// southern region is larger. [`create`](#method.create) accepts up to 100 km
+    /// outside of them.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::{LatLon, UtmUps};
+    /// 
+    /// assert_eq!(UtmUps::ups_bounds(true), (1_300_000.0, 2_700_000.0, 1_300_000.0, 2_700_000.0));
+    /// assert_eq!(UtmUps::ups_bounds(false), (800_000.0, 3_200_000.0, 800_000.0, 3_200_000.0));
+    /// 
+    /// let coord = LatLon::create(84.0, 45.0).unwrap().to_utmups();
+    /// let (min_e, max_e, min_n, max_n) = UtmUps::ups_bounds(coord.is_north());
+    /// assert!((min_e..=max_e).contains(&coord.easting()));
+    /// assert!((min_n..=max_n).contains(&coord.northing()));
+    /// ```
+    pub fn ups_bounds(northp: bool) -> (f64, f64, f64, f64) {
+        let ind = northp.ternary(1, 0);
+
+        (
+            f64::from(MIN_EASTING[ind]),
+            f64::from(MAX_EASTING[ind]),
+            f64::from(MIN_NORTHING[ind]),
+            f64::from(MAX_NORTHING[ind]),
+        )
+    }
+
     /// Returns the UTM easting.
     /// 
     /// # Example
@@ -179,208 +405,1539 @@ impl UtmUps {
         self.northing
     }
 
-    /// Converts from [`LatLon`] to [`UtmUps`]
+    /// Returns the UTM easting rounded to an [`f32`], for storing large numbers
+    /// of points in less memory.
     /// 
-    /// # Usage
+    /// An `f32` only has 24 bits of precision, so eastings (at most about
+    /// `2,000,000` for UTM, `4,000,000` for UPS) are only kept to within `0.0625` m
+    /// for UTM and `0.125` m for UPS.
     /// 
+    /// # Example
     /// ```
-    /// use geoconvert::{LatLon, UtmUps};
+    /// use geoconvert::UtmUps;
     /// 
-    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
-    /// let coord_utm = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
+    /// let coord = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
+    /// assert_eq!(coord.easting_f32(), 585664.125);
+    /// ```
+    pub fn easting_f32(&self) -> f32 {
+        self.easting as f32
+    }
+
+    /// Returns the UTM northing rounded to an [`f32`], for storing large numbers
+    /// of points in less memory.
     /// 
-    /// let converted = coord.to_utmups();
+    /// An `f32` only has 24 bits of precision, so northings of up to
+    /// `10,000,000` are only kept to within `0.5` m, which is usually more than
+    /// the error of the source data but is noticeable at MGRS precisions of 1 m
+    /// or finer.
     /// 
-    /// assert_eq!(converted.zone(), coord_utm.zone());
-    /// assert_eq!(converted.is_north(), coord_utm.is_north());
-    /// // Check if the converted coordinate is accurate to 3 decimals (same as reference)
-    /// assert!((converted.easting() - coord_utm.easting()).abs() < 1e-3);
-    /// assert!((converted.northing() - coord_utm.northing()).abs() < 1e-3);
+    /// # Example
     /// ```
-    pub fn from_latlon(value: &LatLon) -> UtmUps {
-        let northp = value.is_north();
-        // STANDARD specifies, by default, interpret whether it should be UTM or UPS
-        // TODO: Maybe do something if the zone is invalid?
-        let zone = standard_zone(value.latitude, value.longitude, zonespec::STANDARD);
+    /// use geoconvert::UtmUps;
+    /// 
+    /// let coord = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
+    /// assert_eq!(coord.northing_f32(), 4511315.5);
+    /// ```
+    pub fn northing_f32(&self) -> f32 {
+        self.northing as f32
+    }
 
-        let utmp = zone != zonespec::UPS;
-        let (mut x, mut y) = if utmp {
-            let lon0 = central_meridian(zone);
+    /// Returns the longitude of the zone's central meridian in degrees, i.e.
+    /// `6 * zone - 183`, or `None` for UPS, which has no central meridian.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::UtmUps;
+    /// 
+    /// let coord = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
+    /// assert_eq!(coord.central_meridian(), Some(-75.0));
+    /// 
+    /// let coord = UtmUps::create(0, true, 2_000_000.0, 2_000_000.0).unwrap();
+    /// assert_eq!(coord.central_meridian(), None);
+    /// ```
+    pub fn central_meridian(&self) -> Option<f64> {
+        (self.zone != zonespec::UPS).then(|| central_meridian(self.zone))
+    }
 
-            TransverseMercator::utm().from_latlon(lon0, value.latitude, value.longitude)
-        } else {
-            PolarStereographic::ups().from_latlon(northp, value.latitude, value.longitude)
-        };
+    /// Returns the false easting and northing `(x0, y0)` in meters added to the
+    /// projected coordinates for this point's zone and hemisphere, so the raw
+    /// projected coordinates are `(easting - x0, northing - y0)`.
+    /// * UTM: `500,000` easting, and `0` (north) or `10,000,000` (south) northing
+    /// * UPS: `2,000,000` easting and northing
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::UtmUps;
+    /// 
+    /// let coord = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
+    /// assert_eq!(coord.false_origin(), (500_000.0, 0.0));
+    /// 
+    /// let coord = UtmUps::create(56, false, 334368.6, 6250948.3).unwrap();
+    /// assert_eq!(coord.false_origin(), (500_000.0, 10_000_000.0));
+    /// 
+    /// let coord = UtmUps::create(0, true, 2_000_000.0, 2_000_000.0).unwrap();
+    /// assert_eq!(coord.false_origin(), (2_000_000.0, 2_000_000.0));
+    /// ```
+    pub fn false_origin(&self) -> (f64, f64) {
+        let utmp = self.zone != zonespec::UPS;
 
-        let ind = utmp.ternary(2, 0) + northp.ternary(1, 0);
-        x += f64::from(FALSE_EASTING[ind]);
-        y += f64::from(FALSE_NORTHING[ind]);
+        let ind = utmp.ternary(2, 0) + self.northp.ternary(1, 0);
 
-        UtmUps {
-            zone,
-            northp,
-            northing: y,
-            easting: x,
-        }
+        (f64::from(FALSE_EASTING[ind]), f64::from(FALSE_NORTHING[ind]))
     }
 
-    /// Converts from [`UtmUps`] to [`LatLon`]
+    /// Returns whether the zone is the standard one for the point's latitude and
+    /// longitude, taking the Norway (band V) and Svalbard (band X) exceptions into
+    /// account. For example, zone 32 only exists in band V between 3°E and 12°E,
+    /// and zones 32, 34 and 36 don't exist in band X. UPS (zone `0`) is only
+    /// standard south of 80°S and north of 84°N.
     /// 
-    /// # Usage
+    /// [`create`](#method.create) doesn't require this, since points are
+    /// commonly expressed in a neighbouring zone on purpose (see
+    /// [`from_latlon_zone`](#method.from_latlon_zone)). Points within about
+    /// 0.1 mm of a zone boundary are considered valid for both zones.
     /// 
+    /// # Example
     /// ```
     /// use geoconvert::{LatLon, UtmUps};
     /// 
-    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
-    /// let coord_utm = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
+    /// let coord = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
+    /// assert!(coord.is_zone_valid_for_latitude());
     /// 
-    /// let converted = LatLon::from_utmups(&coord_utm);
+    /// // Bergen, Norway is in zone 32 because of the Norway exception
+    /// let bergen = LatLon::create(60.39, 5.32).unwrap();
+    /// assert!(bergen.to_utmups().is_zone_valid_for_latitude());
+    /// assert!(!UtmUps::from_latlon_zone(&bergen, 31).unwrap().is_zone_valid_for_latitude());
     /// 
-    /// // Check if the converted coordinate is accurate to 6 decimals (same as reference)
-    /// assert!((converted.latitude() - coord.latitude()).abs() < 1e-6);
-    /// assert!((converted.longitude() - coord.longitude()).abs() < 1e-6);
+    /// // Zone 32 doesn't exist in Svalbard
+    /// let svalbard = LatLon::create(78.22, 8.0).unwrap();
+    /// assert_eq!(svalbard.to_utmups().zone(), 31);
+    /// assert!(!UtmUps::from_latlon_zone(&svalbard, 32).unwrap().is_zone_valid_for_latitude());
     /// ```
-    pub fn to_latlon(&self) -> LatLon {
-        let utmp = self.zone != zonespec::UPS;
+    pub fn is_zone_valid_for_latitude(&self) -> bool {
+        // About 0.1mm, to allow for round-off for points on a zone boundary
+        const EPS: f64 = 1e-9;
 
-        let ind = utmp.ternary(2, 0) + self.northp.ternary(1, 0);
+        let coord = self.to_latlon();
 
-        let x = self.easting - f64::from(FALSE_EASTING[ind]);
-        let y = self.northing - f64::from(FALSE_NORTHING[ind]);
+        [-EPS, 0.0, EPS].iter().any(|dlat| {
+            [-EPS, 0.0, EPS].iter().any(|dlon| {
+                let lat = (coord.latitude + dlat).clamp(-f64::from(dms::QD), f64::from(dms::QD));
+                let lon = wrap_longitude(coord.longitude + dlon);
 
-        if utmp {
-            TransverseMercator::utm().to_latlon(central_meridian(self.zone), x, y)
-        } else {
-            PolarStereographic::ups().to_latlon(self.northp, x, y)
-        }
+                standard_zone(lat, lon, zonespec::STANDARD) == self.zone
+            })
+        })
     }
 
-    /// Converts from [`Mgrs`] to [`UtmUps`]
+    /// Returns a copy of this point with a different easting, validated the same
+    /// way as [`create`](#method.create). Consumes and returns `Self` so calls
+    /// can be chained.
     /// 
-    /// # Usage
+    /// # Errors
     /// 
+    /// Returns [`Error::UtmOutOfRange`] if the easting is outside the valid
+    /// range for the zone.
+    /// 
+    /// # Example
     /// ```
-    /// use geoconvert::{Mgrs, UtmUps};
+    /// use geoconvert::UtmUps;
     /// 
-    /// let coord = Mgrs::parse_str("18TWL856641113154").unwrap();
-    /// let coord_utm = UtmUps::create(18, true, 585664.15, 4511315.45).unwrap();
+    /// let coord = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap()
+    ///     .with_easting(585000.0).unwrap()
+    ///     .with_northing(4511000.0).unwrap();
     /// 
-    /// let converted = coord.to_utmups();
+    /// assert_eq!(coord.easting(), 585000.0);
+    /// assert_eq!(coord.northing(), 4511000.0);
     /// 
-    /// // Check if the converted coordinate is accurate to 6 decimals (same as reference)
-    /// assert_eq!(coord_utm.zone(), converted.zone());
-    /// assert_eq!(coord_utm.is_north(), converted.is_north());
-    /// assert!((coord_utm.easting() - converted.easting()).abs() < 1e-2);
-    /// assert!((coord_utm.northing() - converted.northing()).abs() < 1e-2);
+    /// assert!(coord.with_easting(-1000.0).is_err());
     /// ```
-    pub fn from_mgrs(value: &Mgrs) -> UtmUps {
-        value.utm
+    pub fn with_easting(self, easting: f64) -> Result<UtmUps, Error> {
+        UtmUps::create(self.zone, self.northp, easting, self.northing)
     }
 
-    /// Converts from [`UtmUps`] to [`Mgrs`]
+    /// Returns a copy of this point with a different northing, validated the
+    /// same way as [`create`](#method.create). Consumes and returns `Self` so
+    /// calls can be chained.
     /// 
-    /// # Usage
+    /// # Errors
     /// 
-    /// ```
-    /// use geoconvert::{Mgrs, UtmUps};
+    /// Returns [`Error::UtmOutOfRange`] if the northing is outside the valid
+    /// range for the zone and hemisphere.
     /// 
-    /// let coord = Mgrs::parse_str("18TWL856641113154").unwrap();
-    /// let coord_utm = UtmUps::create(18, true, 585664.15, 4511315.45).unwrap();
+    /// # Example
+    /// ```
+    /// use geoconvert::UtmUps;
     /// 
-    /// let converted = Mgrs::from_utmups(&coord_utm, 6);
+    /// let coord = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
     /// 
-    /// // Check if the converted coordinate is accurate to 6 decimals (same as reference)
-    /// assert_eq!(coord.zone(), converted.zone());
-    /// assert_eq!(coord.is_north(), converted.is_north());
-    /// assert!((coord.easting() - converted.easting()).abs() < 1e-2);
-    /// assert!((coord.northing() - converted.northing()).abs() < 1e-2);
-    /// assert_eq!(coord.precision(), converted.precision());
+    /// assert_eq!(coord.with_northing(4500000.0).unwrap().northing(), 4500000.0);
+    /// assert!(coord.with_northing(20_000_000.0).is_err());
     /// ```
-    pub fn to_mgrs(&self, precision: i32) -> Mgrs {
-        Mgrs {
-            utm: *self,
-            precision,
-        }
+    pub fn with_northing(self, northing: f64) -> Result<UtmUps, Error> {
+        UtmUps::create(self.zone, self.northp, self.easting, northing)
     }
-}
 
-pub(crate) fn central_meridian(zone: i32) -> f64 {
-    6.0 * f64::from(zone) - 183.
-}
+    /// Returns the point moved by `d_easting` meters east and `d_northing` meters
+    /// north on the grid, validated the same way as [`create`](#method.create).
+    /// 
+    /// The result stays in the original zone, even if it moves past the zone's
+    /// edge, so the offset is exact on the grid. A UTM point moved across the
+    /// equator is normalized to the other hemisphere like in
+    /// [`create`](#method.create). The offsets are grid distances, which differ
+    /// from distances on the ground by the scale factor (see
+    /// [`convergence_and_scale`](#method.convergence_and_scale)).
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::UtmOutOfRange`] if the moved point is outside the valid
+    /// range for the zone and hemisphere.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::UtmUps;
+    /// 
+    /// let coord = UtmUps::create(18, true, 585664.0, 4511315.0).unwrap();
+    /// 
+    /// let moved = coord.offset(100.0, -50.0).unwrap();
+    /// assert_eq!(moved.easting(), 585764.0);
+    /// assert_eq!(moved.northing(), 4511265.0);
+    /// assert_eq!(coord.planar_distance(&moved).unwrap(), 100_f64.hypot(50.0));
+    /// 
+    /// // Across the equator
+    /// let coord = UtmUps::create(31, true, 500000.0, 1000.0).unwrap();
+    /// let moved = coord.offset(0.0, -3000.0).unwrap();
+    /// assert!(!moved.is_north());
+    /// assert_eq!(moved.northing(), 9_998_000.0);
+    /// assert_eq!(moved.zone(), 31);
+    /// 
+    /// assert!(coord.offset(-1_000_000.0, 0.0).is_err());
+    /// ```
+    pub fn offset(&self, d_easting: f64, d_northing: f64) -> Result<UtmUps, Error> {
+        UtmUps::create(self.zone, self.northp, self.easting + d_easting, self.northing + d_northing)
+    }
 
-// Map lat/lon to zone in either UTM or UPS based on position.
-fn standard_zone(lat: f64, lon: f64, setzone: i32) -> i32 {
-    if setzone >= zonespec::MINZONE || setzone == zonespec::INVALID {
-        return setzone;
+    /// Returns whether two points are in the same zone and hemisphere, and their
+    /// eastings and northings are each within `tol_m` meters. Use
+    /// [`LatLon::approx_eq`] to compare in degrees instead, e.g. for points in
+    /// different zones.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::UtmUps;
+    /// 
+    /// let coord = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
+    /// let converted = coord.to_latlon().to_utmups();
+    /// 
+    /// assert!(coord.approx_eq(&converted, 1e-6));
+    /// assert!(!coord.approx_eq(&UtmUps::create(18, true, 585664.2, 4511315.422).unwrap(), 1e-3));
+    /// assert!(!coord.approx_eq(&UtmUps::create(19, true, 585664.121, 4511315.422).unwrap(), 1e-3));
+    /// ```
+    pub fn approx_eq(&self, other: &UtmUps, tol_m: f64) -> bool {
+        self.zone == other.zone
+            && self.northp == other.northp
+            && (self.easting - other.easting).abs() <= tol_m
+            && (self.northing - other.northing).abs() <= tol_m
     }
 
-    if setzone == zonespec::UTM || ((-80_f64)..84.0).contains(&lat) {
-        let mut lon_int = lon.ang_normalize().floor() as i32;
-        if lon_int == dms::HD {
-            lon_int = -dms::HD;
+    /// Returns the same location expressed relative to the given hemisphere, by
+    /// adding or subtracting the `10,000,000` m offset between the northern and
+    /// southern hemisphere false northings. This is the reverse of the
+    /// normalization done by [`create`](#method.create), for when a point near
+    /// the equator has to be given on the other hemisphere's grid.
+    /// 
+    /// A southern point moved to the northern hemisphere has a negative northing,
+    /// and a northern point moved to the southern hemisphere has a northing of
+    /// `10,000,000` or more. UPS points are returned unchanged, since the two
+    /// polar grids are unrelated.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::UtmUps;
+    /// 
+    /// let coord = UtmUps::create(31, true, 500000.0, 1000.0).unwrap();
+    /// 
+    /// let south = coord.to_hemisphere(false);
+    /// assert!(!south.is_north());
+    /// assert_eq!(south.northing(), 10_001_000.0);
+    /// assert!(south.to_latlon().approx_eq(&coord.to_latlon(), 1e-12));
+    /// 
+    /// assert_eq!(south.to_hemisphere(true), coord);
+    /// assert_eq!(coord.to_hemisphere(true), coord);
+    /// ```
+    pub fn to_hemisphere(&self, northp: bool) -> UtmUps {
+        if self.zone == zonespec::UPS || self.northp == northp {
+            return *self;
         }
 
-        let mut zone = (lon_int + 186) / 6;
-        let band = to_latitude_band(lat);
-        // The Norway exception
-        if band == 7 && zone == 31 && lon_int >= 3 {
-            zone = 32;
-        }
-        // The Svalbard exception
-        else if band == 9 && (0..42).contains(&lon_int) {
-            zone = 2 * ((lon_int + 183) / 12) + 1;
-        }
+        let shift = f64::from(mgrs::UTM_N_SHIFT);
 
-        zone
-    } else {
-        zonespec::UPS
+        UtmUps::new(self.zone, northp, self.easting, self.northing + northp.ternary(-shift, shift))
     }
-}
 
-pub(crate) fn check_coords(utmp: bool, northp: bool, x: f64, y: f64, mgrs_limits: bool) -> Result<(), Error> {
-    let slop = f64::from(mgrs::TILE);
+    /// Reprojects the point into the given UTM zone by converting it to [`LatLon`]
+    /// and back with [`from_latlon_zone`](#method.from_latlon_zone). This is
+    /// useful for bringing data that straddles a zone boundary onto a single
+    /// grid.
+    /// 
+    /// The target zone must be the point's own zone or one of its two neighbors
+    /// (zones `60` and `1` are neighbors). Distortion grows quickly away from a
+    /// zone's central meridian: the scale error is at most 0.1% within a zone,
+    /// but reaches about 0.3% at the `[0, 1,000,000]` easting limit, which near
+    /// the equator is only about 1.5° into the neighboring zone.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidZone`] if the point is UPS, or the target zone is
+    /// not a UTM zone adjacent to the point's zone. Returns
+    /// [`Error::UtmOutOfRange`] if the point can't be represented in the target
+    /// zone.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, UtmUps};
+    /// 
+    /// // Naturally in zone 18, just east of the boundary with zone 17
+    /// let coord = LatLon::create(40.0, -77.9).unwrap().to_utmups();
+    /// assert_eq!(coord.zone(), 18);
+    /// 
+    /// let converted = coord.to_zone(17).unwrap();
+    /// assert_eq!(converted.zone(), 17);
+    /// assert!(converted.easting() > 500_000.0);
+    /// assert!(converted.to_zone(18).unwrap().approx_eq(&coord, 1e-6));
+    /// 
+    /// // Zones 60 and 1 are adjacent
+    /// let coord = LatLon::create(10.0, 179.5).unwrap().to_utmups();
+    /// assert_eq!(coord.to_zone(1).unwrap().zone(), 1);
+    /// 
+    /// // Not adjacent
+    /// assert!(coord.to_zone(58).is_err());
+    /// assert!(coord.to_zone(0).is_err());
+    /// ```
+    pub fn to_zone(&self, zone: i32) -> Result<UtmUps, Error> {
+        let steps = (zone - self.zone).rem_euclid(zonespec::MAXUTMZONE);
+
+        if self.zone == zonespec::UPS
+            || !(zonespec::MINUTMZONE..=zonespec::MAXUTMZONE).contains(&zone)
+            || !matches!(steps, 0 | 1 | 59)
+        {
+            return Err(Error::InvalidZone(zone));
+        }
+
+        Self::from_latlon_zone(&self.to_latlon(), zone)
+    }
+
+    /// Returns whether the point is within `overlap_km` kilometers of the edge of
+    /// its UTM zone, so that the neighboring zone's representation (see
+    /// [`adjacent_zone`](#method.adjacent_zone)) is also usable, e.g. when
+    /// stitching adjacent map sheets. An overlap of about 40 km is common.
+    /// 
+    /// The zone edges account for the Norway and Svalbard exceptions (see
+    /// [`zone_longitude_bounds_at_latitude`](#method.zone_longitude_bounds_at_latitude)).
+    /// The distance is the geodesic distance to the point on the zone edge at
+    /// the same latitude. UPS points are never in an overlap.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// // About 8.5 km east of the boundary between zones 17 and 18
+    /// let coord = LatLon::create(40.0, -77.9).unwrap().to_utmups();
+    /// assert!(coord.in_overlap_zone(40.0));
+    /// assert!(!coord.in_overlap_zone(5.0));
+    /// 
+    /// // In the middle of zone 18
+    /// let coord = LatLon::create(40.0, -75.0).unwrap().to_utmups();
+    /// assert!(!coord.in_overlap_zone(40.0));
+    /// 
+    /// // UPS
+    /// let coord = LatLon::create(85.0, -75.0).unwrap().to_utmups();
+    /// assert!(!coord.in_overlap_zone(40.0));
+    /// ```
+    pub fn in_overlap_zone(&self, overlap_km: f64) -> bool {
+        self.nearest_zone_edge()
+            .is_some_and(|(distance, _)| distance <= overlap_km * 1000.0)
+    }
+
+    /// Returns the UTM zone across the nearest edge of the point's zone, or
+    /// [`None`] for UPS points. Together with [`in_overlap_zone`](#method.in_overlap_zone),
+    /// this tells whether the point has a usable representation in another zone,
+    /// and which. Zones 60 and 1 are neighbors across the antimeridian.
+    /// 
+    /// The Norway and Svalbard exceptions are accounted for, so in Svalbard the
+    /// neighbor of zone 33 is zone 31 or 35. [`to_zone`](#method.to_zone) only
+    /// accepts zones numbered next to the point's zone, so it can't convert to
+    /// those neighbors; use [`from_latlon_zone`](#method.from_latlon_zone).
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let coord = LatLon::create(40.0, -77.9).unwrap().to_utmups();
+    /// assert_eq!(coord.adjacent_zone(), Some(17));
+    /// 
+    /// let coord = LatLon::create(40.0, -72.5).unwrap().to_utmups();
+    /// assert_eq!(coord.adjacent_zone(), Some(19));
+    /// 
+    /// // Across the antimeridian
+    /// let coord = LatLon::create(10.0, 179.5).unwrap().to_utmups();
+    /// assert_eq!(coord.adjacent_zone(), Some(1));
+    /// 
+    /// // Svalbard
+    /// let coord = LatLon::create(78.0, 10.0).unwrap().to_utmups();
+    /// assert_eq!(coord.zone(), 33);
+    /// assert_eq!(coord.adjacent_zone(), Some(31));
+    /// 
+    /// // UPS
+    /// let coord = LatLon::create(85.0, -75.0).unwrap().to_utmups();
+    /// assert_eq!(coord.adjacent_zone(), None);
+    /// ```
+    pub fn adjacent_zone(&self) -> Option<i32> {
+        let (_, edge) = self.nearest_zone_edge()?;
+        let lat = self.to_latlon().latitude;
+
+        Some(standard_zone(lat, edge, zonespec::UTM))
+    }
+
+    /// Returns the geodesic distance in meters to the nearest edge of the point's
+    /// UTM zone (at the same latitude), and a longitude just across that edge,
+    /// or [`None`] for UPS points.
+    fn nearest_zone_edge(&self) -> Option<(f64, f64)> {
+        if self.zone == zonespec::UPS {
+            return None;
+        }
+
+        let coord = self.to_latlon();
+        let (west, east) = Self::zone_longitude_bounds_at_latitude(self.zone, coord.latitude)
+            .or_else(|_| Self::zone_longitude_bounds(self.zone))
+            .ok()?;
+
+        let distance_to = |lon: f64| coord.geodesic_distance(&LatLon::new(coord.latitude, wrap_longitude(lon)));
+        let (west_distance, east_distance) = (distance_to(west), distance_to(east));
+
+        // The zone edges are on whole degrees, so step half a degree across
+        Some((west_distance <= east_distance).ternary(
+            (west_distance, west - 0.5),
+            (east_distance, east + 0.5),
+        ))
+    }
+
+    /// Returns the straight-line distance in meters between two points on the
+    /// grid, i.e. from the differences of their eastings and northings. This is
+    /// the grid distance, which differs from the distance on the ground by the
+    /// scale factor (see [`convergence_and_scale`](#method.convergence_and_scale)),
+    /// i.e. by up to about 0.1% within a UTM zone.
+    /// 
+    /// The grids of different zones are unrelated, so this fails rather than
+    /// giving a meaningless answer if the points are in different zones. UTM
+    /// points in the same zone but different hemispheres are compared on the
+    /// same grid (see [`to_hemisphere`](#method.to_hemisphere)). Use
+    /// [`distance_via_latlon`](#method.distance_via_latlon) for points that may
+    /// be in different zones.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::ZoneMismatch`] if the points are in different zones, or
+    /// are UPS points in different hemispheres.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::UtmUps;
+    /// 
+    /// let a = UtmUps::create(18, true, 585000.0, 4511000.0).unwrap();
+    /// let b = UtmUps::create(18, true, 588000.0, 4515000.0).unwrap();
+    /// assert_eq!(a.planar_distance(&b).unwrap(), 5000.0);
+    /// 
+    /// // Either side of the equator
+    /// let a = UtmUps::create(31, true, 500000.0, 1000.0).unwrap();
+    /// let b = UtmUps::create(31, false, 500000.0, 9_999_000.0).unwrap();
+    /// assert_eq!(a.planar_distance(&b).unwrap(), 2000.0);
+    /// 
+    /// // Different zones
+    /// let c = UtmUps::create(19, true, 585000.0, 4511000.0).unwrap();
+    /// assert!(a.planar_distance(&c).is_err());
+    /// ```
+    pub fn planar_distance(&self, other: &UtmUps) -> Result<f64, Error> {
+        let other = other.to_hemisphere(self.northp);
+
+        if self.zone != other.zone || self.northp != other.northp {
+            return Err(Error::ZoneMismatch(format!(
+                "{}{} and {}{}",
+                self.zone,
+                self.northp.ternary("n", "s"),
+                other.zone,
+                other.northp.ternary("n", "s"),
+            )));
+        }
+
+        Ok((other.easting - self.easting).hypot(other.northing - self.northing))
+    }
+
+    /// Returns the distance in meters between two points along the WGS84
+    /// ellipsoid, by converting both to [`LatLon`] and using
+    /// [`LatLon::geodesic_distance`]. Unlike [`planar_distance`](#method.planar_distance),
+    /// this works for points in any zones, and gives the true distance on the
+    /// ground rather than the grid distance.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, UtmUps};
+    /// 
+    /// // Either side of the boundary between zones 17 and 18
+    /// let a = LatLon::create(40.0, -78.01).unwrap();
+    /// let b = LatLon::create(40.0, -77.99).unwrap();
+    /// let (a_utm, b_utm) = (a.to_utmups(), b.to_utmups());
+    /// 
+    /// assert_ne!(a_utm.zone(), b_utm.zone());
+    /// assert!(a_utm.planar_distance(&b_utm).is_err());
+    /// 
+    /// let distance = a_utm.distance_via_latlon(&b_utm);
+    /// assert!((distance - a.geodesic_distance(&b)).abs() < 1e-6);
+    /// assert!((distance - 1707.0).abs() < 1.0);
+    /// ```
+    pub fn distance_via_latlon(&self, other: &UtmUps) -> f64 {
+        self.to_latlon().geodesic_distance(&other.to_latlon())
+    }
+
+    /// Converts from [`LatLon`] to [`UtmUps`]
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, UtmUps};
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// let coord_utm = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
+    /// 
+    /// let converted = coord.to_utmups();
+    /// 
+    /// assert_eq!(converted.zone(), coord_utm.zone());
+    /// assert_eq!(converted.is_north(), coord_utm.is_north());
+    /// // Check if the converted coordinate is accurate to 3 decimals (same as reference)
+    /// assert!(converted.approx_eq(&coord_utm, 1e-3));
+    /// ```
+    pub fn from_latlon(value: &LatLon) -> UtmUps {
+        wgs84::with_projections(|tm, ps| Self::from_latlon_with_projections(value, tm, ps))
+    }
+
+    /// Converts from [`LatLon`] to [`UtmUps`] using a reference ellipsoid other
+    /// than WGS84. The zone and the UTM/UPS limits are the same as for WGS84. The
+    /// result does not record the ellipsoid, so it must be converted back with
+    /// [`to_latlon_with_ellipsoid`](#method.to_latlon_with_ellipsoid) using the same one.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{Ellipsoid, LatLon, UtmUps};
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// 
+    /// let converted = UtmUps::from_latlon_with_ellipsoid(&coord, Ellipsoid::CLARKE1866);
+    /// 
+    /// assert_eq!(converted.zone(), 18);
+    /// // Clarke 1866 is noticeably different from WGS84
+    /// assert!((converted.northing() - coord.to_utmups().northing()).abs() > 10.0);
+    /// 
+    /// let back = converted.to_latlon_with_ellipsoid(Ellipsoid::CLARKE1866);
+    /// assert!(back.approx_eq(&coord, 1e-9));
+    /// ```
+    pub fn from_latlon_with_ellipsoid(value: &LatLon, ellipsoid: Ellipsoid) -> UtmUps {
+        Self::from_latlon_with_projections(
+            value,
+            &TransverseMercator::utm(ellipsoid),
+            &PolarStereographic::ups(ellipsoid),
+        )
+    }
+
+    /// Converts from [`LatLon`] to [`UtmUps`] with already-constructed projections,
+    /// so that converting many points only sets up the projections once.
+    pub(crate) fn from_latlon_with_projections(value: &LatLon, tm: &TransverseMercator, ps: &PolarStereographic) -> UtmUps {
+        // STANDARD specifies, by default, interpret whether it should be UTM or UPS
+        // TODO: Maybe do something if the zone is invalid?
+        let zone = standard_zone(value.latitude, value.longitude, zonespec::STANDARD);
+
+        Self::project(value, zone, tm, ps)
+    }
+
+    /// Converts from [`LatLon`] to [`UtmUps`], forcing the point into the given
+    /// UTM zone instead of the one it naturally falls in. This is the equivalent
+    /// of [GeoConvert](https://geographiclib.sourceforge.io/C++/doc/GeoConvert.1.html)'s `-z` option, and is useful for keeping every point of a
+    /// study area that straddles a zone boundary on one consistent grid.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidZone`] if the zone is outside the range `[1, 60]`.
+    /// Returns [`Error::UtmOutOfRange`] if the point is too far from the zone's
+    /// central meridian to be represented in it.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, UtmUps};
+    /// 
+    /// // Naturally in zone 18, just east of the boundary with zone 17
+    /// let coord = LatLon::create(40.0, -77.9).unwrap();
+    /// assert_eq!(coord.to_utmups().zone(), 18);
+    /// 
+    /// let converted = UtmUps::from_latlon_zone(&coord, 17).unwrap();
+    /// assert_eq!(converted.zone(), 17);
+    /// assert!(converted.easting() > 500_000.0);
+    /// 
+    /// // Still represents the same point
+    /// let back = converted.to_latlon();
+    /// assert!(back.approx_eq(&coord, 1e-9));
+    /// 
+    /// assert!(UtmUps::from_latlon_zone(&coord, 0).is_err());
+    /// assert!(UtmUps::from_latlon_zone(&coord, 61).is_err());
+    /// // Far too far away from the central meridian of zone 30
+    /// assert!(UtmUps::from_latlon_zone(&coord, 30).is_err());
+    /// ```
+    pub fn from_latlon_zone(value: &LatLon, zone: i32) -> Result<UtmUps, Error> {
+        if !(zonespec::MINUTMZONE..=zonespec::MAXUTMZONE).contains(&zone) {
+            return Err(Error::InvalidZone(zone));
+        }
+
+        let coord = wgs84::with_projections(|tm, ps| Self::project(value, zone, tm, ps));
+
+        let (northp, easting, northing) = check_coords(zone, coord.northp, coord.easting, coord.northing, false)?;
+
+        Ok(UtmUps::new(zone, northp, easting, northing))
+    }
+
+    /// Converts from [`LatLon`] to [`UtmUps`] like [`from_latlon`](#method.from_latlon),
+    /// but always in the standard UTM zone, failing rather than falling back to
+    /// UPS near the poles. UTM is defined for latitudes in `[-80, 84]`.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidRange`] if the latitude is outside `[-80, 84]`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, UtmUps};
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// assert_eq!(UtmUps::try_from_latlon_utm(&coord).unwrap(), coord.to_utmups());
+    /// 
+    /// // UTM all the way to the edges of its range
+    /// let coord = LatLon::create(84.0, 10.0).unwrap();
+    /// assert_eq!(coord.to_utmups().zone(), 0);
+    /// assert_eq!(UtmUps::try_from_latlon_utm(&coord).unwrap().zone(), 33);
+    /// 
+    /// assert!(UtmUps::try_from_latlon_utm(&LatLon::create(84.5, 10.0).unwrap()).is_err());
+    /// assert!(UtmUps::try_from_latlon_utm(&LatLon::create(-80.5, 10.0).unwrap()).is_err());
+    /// ```
+    pub fn try_from_latlon_utm(value: &LatLon) -> Result<UtmUps, Error> {
+        if !(-80.0..=84.0).contains(&value.latitude) {
+            return Err(Error::InvalidRange {
+                coord_type: "LatLon".to_string(),
+                dest_type: "UTM".to_string(),
+                msg: format!("Latitude {} outside of UTM range [-80, 84]", value.latitude),
+            });
+        }
+
+        let zone = standard_zone(value.latitude, value.longitude, zonespec::UTM);
+
+        Ok(wgs84::with_projections(|tm, ps| Self::project(value, zone, tm, ps)))
+    }
+
+    /// Converts from [`LatLon`] to UPS (zone `0`) like [`from_latlon`](#method.from_latlon),
+    /// but even for points that would normally be UTM, e.g. to keep points just
+    /// south of 84° on the same grid as a polar dataset. The counterpart of
+    /// [`try_from_latlon_utm`](#method.try_from_latlon_utm).
+    /// 
+    /// The scale factor of UPS grows away from the pole, from `0.994` at the
+    /// pole to `1` at about 81°, and keeps growing beyond that, so the distortion
+    /// is larger than UTM's further from the pole. Points outside of the range of
+    /// UPS eastings and northings (see [`ups_bounds`](#method.ups_bounds)) are
+    /// rejected. The range reaches about 82.8° in the north and 78.3° in the
+    /// south (and further towards its corners).
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::UtmOutOfRange`] if the point is too far from the pole.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, UtmUps};
+    /// 
+    /// let coord = LatLon::create(86.0, 10.0).unwrap();
+    /// assert_eq!(UtmUps::try_from_latlon_ups(&coord).unwrap(), coord.to_utmups());
+    /// 
+    /// // Normally UTM
+    /// let coord = LatLon::create(83.5, 10.0).unwrap();
+    /// assert_eq!(coord.to_utmups().zone(), 33);
+    /// 
+    /// let ups = UtmUps::try_from_latlon_ups(&coord).unwrap();
+    /// assert_eq!(ups.zone(), 0);
+    /// assert!(ups.to_latlon().approx_eq(&coord, 1e-9));
+    /// 
+    /// assert!(UtmUps::try_from_latlon_ups(&LatLon::create(40.748333, -73.985278).unwrap()).is_err());
+    /// ```
+    pub fn try_from_latlon_ups(value: &LatLon) -> Result<UtmUps, Error> {
+        let coord = wgs84::with_projections(|tm, ps| Self::project(value, zonespec::UPS, tm, ps));
+
+        let (northp, easting, northing) = check_coords(zonespec::UPS, coord.northp, coord.easting, coord.northing, false)?;
+
+        Ok(UtmUps::new(zonespec::UPS, northp, easting, northing))
+    }
+
+    /// Returns the standard 6° wide UTM zone containing the longitude, ignoring
+    /// the Norway and Svalbard exceptions (which depend on latitude) and UPS.
+    /// The longitude is normalized first, so both `180` and `-180` are in zone 1.
+    /// Useful for quickly bucketing points by zone.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::UtmUps;
+    /// 
+    /// assert_eq!(UtmUps::zone_for_longitude(-73.985278), 18);
+    /// assert_eq!(UtmUps::zone_for_longitude(-180.0), 1);
+    /// assert_eq!(UtmUps::zone_for_longitude(180.0), 1);
+    /// assert_eq!(UtmUps::zone_for_longitude(179.9), 60);
+    /// assert_eq!(UtmUps::zone_for_longitude(0.0), 31);
+    /// assert_eq!(UtmUps::zone_for_longitude(366.0), 32);
+    /// 
+    /// // Bergen is in zone 32 because of the Norway exception
+    /// assert_eq!(UtmUps::zone_for_longitude(5.32), 31);
+    /// ```
+    pub fn zone_for_longitude(lon: f64) -> i32 {
+        (longitude_index(lon) + 186) / 6
+    }
+
+    /// Returns the zones touched by the latitude/longitude rectangle from `sw` to
+    /// `ne`, in ascending order, e.g. to find how many zones a region has to be
+    /// split across. The Norway and Svalbard exceptions are accounted for, and
+    /// `0` is included if the rectangle reaches into the UPS regions.
+    /// 
+    /// The edges of the rectangle are included, and the zone boundaries belong
+    /// to the zones to their east and north as in [`LatLon::to_utmups`], so a
+    /// rectangle of a single point gives that point's zone. If `sw` has a larger
+    /// longitude than `ne`, the rectangle crosses the antimeridian. If `sw` is
+    /// north of `ne`, the rectangle is empty and so are the zones.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, UtmUps};
+    /// 
+    /// let bbox = |sw: (f64, f64), ne: (f64, f64)| {
+    ///     UtmUps::zones_in_bbox(&LatLon::create(sw.0, sw.1).unwrap(), &LatLon::create(ne.0, ne.1).unwrap())
+    /// };
+    /// 
+    /// // New York to Chicago
+    /// assert_eq!(bbox((40.7, -87.6), (41.9, -74.0)), [16, 17, 18]);
+    /// 
+    /// // All in zone 32 because of the Norway exception
+    /// assert_eq!(bbox((58.0, 4.0), (62.0, 5.0)), [32]);
+    /// 
+    /// // Zone 32 isn't used around Svalbard
+    /// assert_eq!(bbox((75.0, 8.0), (78.0, 10.0)), [31, 33]);
+    /// 
+    /// // Across the antimeridian
+    /// assert_eq!(bbox((-20.0, 178.0), (-15.0, -178.0)), [1, 60]);
+    /// 
+    /// // Into the north polar region
+    /// assert_eq!(bbox((83.0, 0.5), (85.0, 1.0)), [0, 31]);
+    /// 
+    /// // A single point
+    /// assert_eq!(bbox((40.748333, -73.985278), (40.748333, -73.985278)), [18]);
+    /// ```
+    pub fn zones_in_bbox(sw: &LatLon, ne: &LatLon) -> Vec<i32> {
+        if sw.latitude > ne.latitude {
+            return Vec::new();
+        }
+
+        let hd = f64::from(dms::HD);
+
+        // Split the rectangle at the antimeridian if it crosses it
+        let lon_ranges = if sw.longitude <= ne.longitude {
+            Vec::from([(sw.longitude, ne.longitude)])
+        } else {
+            Vec::from([(sw.longitude, hd), (-hd, ne.longitude)])
+        };
+
+        let mut zones = Vec::new();
+
+        for (band_lat0, band_lat1) in mgrs::BBOX_LAT_BANDS {
+            // The north pole is in the last band
+            let north_of_band = sw.latitude >= band_lat1 && band_lat1 < f64::from(dms::QD);
+            if north_of_band || ne.latitude < band_lat0 {
+                continue;
+            }
+
+            for (zone, zone_lon0, zone_lon1) in mgrs::zone_longitudes(band_lat0) {
+                if lon_ranges.iter().any(|&(lon0, lon1)| lon0 < zone_lon1 && lon1 >= zone_lon0) {
+                    zones.push(zone);
+                }
+            }
+        }
+
+        zones.sort_unstable();
+        zones.dedup();
+
+        zones
+    }
+
+    /// Returns the west and east edges of the standard 6° wide UTM zone in
+    /// degrees of longitude, as `(west, east)`, ignoring the Norway and Svalbard
+    /// exceptions like [`zone_for_longitude`](#method.zone_for_longitude). Use
+    /// [`zone_longitude_bounds_at_latitude`](#method.zone_longitude_bounds_at_latitude)
+    /// to account for them.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidZone`] if the zone is outside the range `[1, 60]`.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::UtmUps;
+    /// 
+    /// assert_eq!(UtmUps::zone_longitude_bounds(18).unwrap(), (-78.0, -72.0));
+    /// assert_eq!(UtmUps::zone_longitude_bounds(1).unwrap(), (-180.0, -174.0));
+    /// assert_eq!(UtmUps::zone_longitude_bounds(60).unwrap(), (174.0, 180.0));
+    /// 
+    /// assert!(UtmUps::zone_longitude_bounds(0).is_err());
+    /// assert!(UtmUps::zone_longitude_bounds(61).is_err());
+    /// ```
+    pub fn zone_longitude_bounds(zone: i32) -> Result<(f64, f64), Error> {
+        if !(zonespec::MINUTMZONE..=zonespec::MAXUTMZONE).contains(&zone) {
+            return Err(Error::InvalidZone(zone));
+        }
+
+        let west = f64::from(6 * zone - 186);
+
+        Ok((west, west + 6.0))
+    }
+
+    /// Returns the west and east edges of the UTM zone at the given latitude in
+    /// degrees of longitude, as `(west, east)`, accounting for the exceptions to
+    /// the standard 6° wide zones:
+    /// * Norway (band V, latitudes `[56, 64)`): zone 31 is narrowed to `[0, 3)`
+    ///   and zone 32 widened to `[3, 12)`
+    /// * Svalbard (band X, latitudes `[72, 84)`): zones 31, 33, 35 and 37 are
+    ///   widened to `[0, 9)`, `[9, 21)`, `[21, 33)` and `[33, 42)`, and zones
+    ///   32, 34 and 36 don't exist
+    /// 
+    /// Outside the UTM latitude range `[-80, 84)` the standard bounds are
+    /// returned.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidZone`] if the zone is outside the range `[1, 60]`,
+    /// or doesn't exist at the latitude.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::UtmUps;
+    /// 
+    /// assert_eq!(UtmUps::zone_longitude_bounds_at_latitude(18, 40.0).unwrap(), (-78.0, -72.0));
+    /// 
+    /// // Norway
+    /// assert_eq!(UtmUps::zone_longitude_bounds_at_latitude(31, 60.0).unwrap(), (0.0, 3.0));
+    /// assert_eq!(UtmUps::zone_longitude_bounds_at_latitude(32, 60.0).unwrap(), (3.0, 12.0));
+    /// 
+    /// // Svalbard
+    /// assert_eq!(UtmUps::zone_longitude_bounds_at_latitude(33, 78.0).unwrap(), (9.0, 21.0));
+    /// assert!(UtmUps::zone_longitude_bounds_at_latitude(34, 78.0).is_err());
+    /// 
+    /// // Outside the exceptions
+    /// assert_eq!(UtmUps::zone_longitude_bounds_at_latitude(32, 50.0).unwrap(), (6.0, 12.0));
+    /// assert_eq!(UtmUps::zone_longitude_bounds_at_latitude(34, 85.0).unwrap(), (18.0, 24.0));
+    /// ```
+    pub fn zone_longitude_bounds_at_latitude(zone: i32, lat: f64) -> Result<(f64, f64), Error> {
+        let bounds = Self::zone_longitude_bounds(zone)?;
+
+        if !((-80_f64)..84.0).contains(&lat) {
+            return Ok(bounds);
+        }
+
+        match (to_latitude_band(lat), zone) {
+            // The Norway exception
+            (7, 31) => Ok((0.0, 3.0)),
+            (7, 32) => Ok((3.0, 12.0)),
+            // The Svalbard exception
+            (9, 31) => Ok((0.0, 9.0)),
+            (9, 33) => Ok((9.0, 21.0)),
+            (9, 35) => Ok((21.0, 33.0)),
+            (9, 37) => Ok((33.0, 42.0)),
+            (9, 32 | 34 | 36) => Err(Error::InvalidZone(zone)),
+            _ => Ok(bounds),
+        }
+    }
+
+    /// Returns the endpoints of the grid lines of a zone (`0` for UPS) in the
+    /// given hemisphere, for drawing the grid on a map. The lines are at every
+    /// multiple of `spacing_m` meters of easting and northing, and are clipped
+    /// to the extent of the grid used by MGRS:
+    /// * UTM: eastings `[100, 900]` km, and northings `[0, 9500]` km in the
+    ///   northern hemisphere and `[1000, 10000]` km in the southern hemisphere
+    /// * UPS: eastings and northings `[1300, 2700]` km in the northern hemisphere
+    ///   and `[800, 3200]` km in the southern hemisphere
+    /// 
+    /// The lines of constant easting come first, from west to east, each going
+    /// from south to north. Then the lines of constant northing, from south to
+    /// north, each going from west to east. The lines are straight on the grid,
+    /// but not in latitude/longitude, so convert points along them rather than
+    /// just the endpoints when drawing them on another projection.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidZone`] if the zone is outside the range `[0, 60]`.
+    /// Returns [`Error::InvalidUtmCoords`] if `spacing_m` is not positive.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::UtmUps;
+    /// 
+    /// let lines: Vec<_> = UtmUps::grid_lines(18, true, 100_000.0).unwrap().collect();
+    /// 
+    /// // 9 lines of constant easting and 96 of constant northing
+    /// assert_eq!(lines.len(), 105);
+    /// 
+    /// let (start, end) = lines[0];
+    /// assert_eq!((start.easting(), start.northing()), (100_000.0, 0.0));
+    /// assert_eq!((end.easting(), end.northing()), (100_000.0, 9_500_000.0));
+    /// 
+    /// let (start, end) = lines[9];
+    /// assert_eq!((start.easting(), start.northing()), (100_000.0, 0.0));
+    /// assert_eq!((end.easting(), end.northing()), (900_000.0, 0.0));
+    /// 
+    /// assert!(UtmUps::grid_lines(61, true, 100_000.0).is_err());
+    /// assert!(UtmUps::grid_lines(18, true, 0.0).is_err());
+    /// ```
+    pub fn grid_lines(zone: i32, northp: bool, spacing_m: f64) -> Result<impl Iterator<Item = (UtmUps, UtmUps)>, Error> {
+        if !(zonespec::MINZONE..=zonespec::MAXZONE).contains(&zone) {
+            return Err(Error::InvalidZone(zone));
+        }
+
+        if !(spacing_m.is_finite() && spacing_m > 0.0) {
+            return Err(Error::InvalidUtmCoords(format!("Grid spacing {spacing_m} must be positive")));
+        }
+
+        let utmp = zone != zonespec::UPS;
+        let ind = utmp.ternary(2, 0) + northp.ternary(1, 0);
+
+        let (min_e, max_e) = (f64::from(MIN_EASTING[ind]), f64::from(MAX_EASTING[ind]));
+        let (min_n, max_n) = (f64::from(MIN_NORTHING[ind]), f64::from(MAX_NORTHING[ind]));
+
+        // The UTM northing limits extend across the equator, so keep to the
+        // requested hemisphere
+        let (min_n, max_n) = match (utmp, northp) {
+            (true, true) => (min_n.max(0.0), max_n),
+            (true, false) => (min_n, max_n.min(f64::from(mgrs::UTM_N_SHIFT))),
+            _ => (min_n, max_n),
+        };
+
+        let steps = |min: f64, max: f64| ((min / spacing_m).ceil() as i64)..=((max / spacing_m).floor() as i64);
+
+        let eastings = steps(min_e, max_e)
+            .map(move |i| {
+                let easting = i as f64 * spacing_m;
+                (UtmUps::new(zone, northp, easting, min_n), UtmUps::new(zone, northp, easting, max_n))
+            });
+
+        let northings = steps(min_n, max_n)
+            .map(move |i| {
+                let northing = i as f64 * spacing_m;
+                (UtmUps::new(zone, northp, min_e, northing), UtmUps::new(zone, northp, max_e, northing))
+            });
+
+        Ok(eastings.chain(northings))
+    }
+
+    /// Projects a point into the given zone, which is not checked.
+    fn project(value: &LatLon, zone: i32, tm: &TransverseMercator, ps: &PolarStereographic) -> UtmUps {
+        let northp = value.is_north();
+        let (x, y) = to_grid(zone, northp, value.latitude, value.longitude, tm, ps);
+
+        UtmUps {
+            zone,
+            northp,
+            northing: y,
+            easting: x,
+        }
+    }
+
+    /// Converts from [`UtmUps`] to [`LatLon`]
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, UtmUps};
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// let coord_utm = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
+    /// 
+    /// let converted = LatLon::from_utmups(&coord_utm);
+    /// 
+    /// // Check if the converted coordinate is accurate to 6 decimals (same as reference)
+    /// assert!(converted.approx_eq(&coord, 1e-6));
+    /// ```
+    pub fn to_latlon(&self) -> LatLon {
+        wgs84::with_projections(|tm, ps| self.unproject(tm, ps))
+    }
+
+    /// Converts from [`UtmUps`] to [`LatLon`] using a reference ellipsoid other
+    /// than WGS84. See [`from_latlon_with_ellipsoid`](#method.from_latlon_with_ellipsoid).
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{Ellipsoid, UtmUps};
+    /// 
+    /// let coord_utm = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
+    /// 
+    /// // GRS80 differs from WGS84 by less than a millimeter here
+    /// let wgs84 = coord_utm.to_latlon();
+    /// let grs80 = coord_utm.to_latlon_with_ellipsoid(Ellipsoid::GRS80);
+    /// 
+    /// assert!(wgs84.haversine(&grs80) < 1e-3);
+    /// ```
+    pub fn to_latlon_with_ellipsoid(&self, ellipsoid: Ellipsoid) -> LatLon {
+        self.unproject(
+            &TransverseMercator::utm(ellipsoid),
+            &PolarStereographic::ups(ellipsoid),
+        )
+    }
+
+    /// Converts back to [`LatLon`] with the given projections.
+    fn unproject(&self, tm: &TransverseMercator, ps: &PolarStereographic) -> LatLon {
+        let utmp = self.zone != zonespec::UPS;
+
+        let (x0, y0) = self.false_origin();
+
+        let x = self.easting - x0;
+        let y = self.northing - y0;
+
+        let coord = if utmp {
+            tm.to_latlon(central_meridian(self.zone), x, y)
+        } else {
+            ps.to_latlon(self.northp, x, y)
+        };
+
+        // The projections give longitudes in (-180, 180], so the antimeridian
+        // has to be moved to -180
+        LatLon::new(coord.latitude, wrap_longitude(coord.longitude))
+    }
+
+    /// Projects a [`LatLon`] with a transverse Mercator projection other than
+    /// UTM's, using the UTM projection math with a custom central meridian
+    /// `lon0` (in degrees) and central scale factor `k0`. Returns `(x, y)` in
+    /// meters relative to the point on the equator at `lon0`, without any false
+    /// easting or northing. Useful for building national grids based on
+    /// transverse Mercator, such as the British National Grid.
+    /// 
+    /// Equivalent to [`map_projection::TransverseMercator::from_latlon`](crate::map_projection::TransverseMercator::from_latlon).
+    /// To project many points with the same parameters, create the
+    /// [`map_projection::TransverseMercator`](crate::map_projection::TransverseMercator)
+    /// once instead.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidProjection`] if `lon0` is outside the range
+    /// `[-180, 180]` or `k0` is not positive.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{Ellipsoid, LatLon, UtmUps};
+    /// 
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    /// 
+    /// // The same as UTM zone 18, without the false easting
+    /// let (x, y) = UtmUps::transverse_mercator_forward(&coord, Ellipsoid::WGS84, -75.0, 0.9996).unwrap();
+    /// let utm = coord.to_utmups();
+    /// assert!((x + 500_000.0 - utm.easting()).abs() < 1e-6);
+    /// assert!((y - utm.northing()).abs() < 1e-6);
+    /// 
+    /// // The British National Grid, with its true origin at 49°N 2°W, using the
+    /// // worked example from the Ordnance Survey's guide (on OSGB36)
+    /// let k0 = 0.9996012717;
+    /// let origin = LatLon::create(49.0, -2.0).unwrap();
+    /// let (_, y0) = UtmUps::transverse_mercator_forward(&origin, Ellipsoid::AIRY1830, -2.0, k0).unwrap();
+    /// 
+    /// let coord = LatLon::create(52.657570306, 1.717921583).unwrap();
+    /// let (x, y) = UtmUps::transverse_mercator_forward(&coord, Ellipsoid::AIRY1830, -2.0, k0).unwrap();
+    /// assert!((x + 400_000.0 - 651_409.903).abs() < 1e-3);
+    /// assert!((y - y0 - 100_000.0 - 313_177.270).abs() < 1e-3);
+    /// 
+    /// assert!(UtmUps::transverse_mercator_forward(&coord, Ellipsoid::AIRY1830, -2.0, 0.0).is_err());
+    /// ```
+    pub fn transverse_mercator_forward(value: &LatLon, ellipsoid: Ellipsoid, lon0: f64, k0: f64) -> Result<(f64, f64), Error> {
+        Ok(map_projection::TransverseMercator::create(ellipsoid, lon0, k0)?.from_latlon(value))
+    }
+
+    /// The inverse of [`transverse_mercator_forward`](#method.transverse_mercator_forward):
+    /// converts `(x, y)` in meters, relative to the point on the equator at the
+    /// central meridian `lon0`, back to a [`LatLon`] for a transverse Mercator
+    /// projection with central scale factor `k0`.
+    /// 
+    /// Equivalent to [`map_projection::TransverseMercator::to_latlon`](crate::map_projection::TransverseMercator::to_latlon).
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidProjection`] if `lon0` is outside the range
+    /// `[-180, 180]` or `k0` is not positive.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{Ellipsoid, LatLon, UtmUps};
+    /// 
+    /// let coord = LatLon::create(51.5, -0.12).unwrap();
+    /// 
+    /// let (x, y) = UtmUps::transverse_mercator_forward(&coord, Ellipsoid::AIRY1830, -2.0, 0.9996012717).unwrap();
+    /// let back = UtmUps::transverse_mercator_reverse(x, y, Ellipsoid::AIRY1830, -2.0, 0.9996012717).unwrap();
+    /// 
+    /// assert!(back.approx_eq(&coord, 1e-9));
+    /// 
+    /// assert!(UtmUps::transverse_mercator_reverse(x, y, Ellipsoid::AIRY1830, 200.0, 0.9996012717).is_err());
+    /// ```
+    pub fn transverse_mercator_reverse(x: f64, y: f64, ellipsoid: Ellipsoid, lon0: f64, k0: f64) -> Result<LatLon, Error> {
+        Ok(map_projection::TransverseMercator::create(ellipsoid, lon0, k0)?.to_latlon(x, y))
+    }
+
+    /// Returns the meridian convergence `gamma` (in degrees) and the point scale
+    /// factor `k` at this point, as `(gamma, k)`, on the WGS84 ellipsoid.
+    /// 
+    /// `gamma` is the bearing of grid north measured clockwise from true north,
+    /// so a true bearing is obtained from a grid bearing by adding `gamma`. `k` is
+    /// the ratio of a small distance on the grid to the same distance on the
+    /// ellipsoid. These match the values output by [GeoConvert](https://geographiclib.sourceforge.io/C++/doc/GeoConvert.1.html)'s
+    /// `-c` option.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, UtmUps};
+    /// 
+    /// // On the central meridian of zone 18, grid north is true north and the
+    /// // scale is the UTM central scale factor
+    /// let coord = LatLon::create(40.0, -75.0).unwrap().to_utmups();
+    /// let (gamma, k) = coord.convergence_and_scale();
+    /// assert!(gamma.abs() < 1e-9);
+    /// assert!((k - 0.9996).abs() < 1e-9);
+    /// 
+    /// // East of the central meridian in the northern hemisphere, grid north
+    /// // is east of true north, and the scale grows away from the central meridian
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap().to_utmups();
+    /// let (gamma, k) = coord.convergence_and_scale();
+    /// assert!(gamma > 0.0);
+    /// assert!(k > 0.9996);
+    /// ```
+    pub fn convergence_and_scale(&self) -> (f64, f64) {
+        let latlon = self.to_latlon();
+
+        let (_, _, gamma, k) = wgs84::with_projections(|tm, ps| {
+            if self.zone == zonespec::UPS {
+                ps.from_latlon_full(self.northp, latlon.latitude, latlon.longitude)
+            } else {
+                tm.from_latlon_full(central_meridian(self.zone), latlon.latitude, latlon.longitude)
+            }
+        });
+
+        (gamma, k)
+    }
+
+    /// Returns the angle between grid north and true north (in degrees), i.e. the
+    /// `gamma` of [`convergence_and_scale`](Self::convergence_and_scale).
+    /// 
+    /// For UPS this is simply the longitude in the northern hemisphere and its
+    /// negation in the southern hemisphere, and is computed directly from the
+    /// easting and northing. At the pole itself it is 0.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let coord = LatLon::create(87.0, 30.0).unwrap().to_utmups();
+    /// assert!((coord.grid_north_offset() - 30.0).abs() < 1e-9);
+    /// 
+    /// let coord = LatLon::create(-87.0, 30.0).unwrap().to_utmups();
+    /// assert!((coord.grid_north_offset() - -30.0).abs() < 1e-9);
+    /// 
+    /// // UTM uses the transverse mercator convergence
+    /// let coord = LatLon::create(40.748333, -73.985278).unwrap().to_utmups();
+    /// assert_eq!(coord.grid_north_offset(), coord.convergence_and_scale().0);
+    /// ```
+    pub fn grid_north_offset(&self) -> f64 {
+        if self.zone != zonespec::UPS {
+            return self.convergence_and_scale().0;
+        }
+
+        let ind = self.northp.ternary(1, 0);
+
+        let x = self.easting - f64::from(FALSE_EASTING[ind]);
+        let y = self.northing - f64::from(FALSE_NORTHING[ind]);
+
+        if x == 0.0 && y == 0.0 {
+            return 0.0;
+        }
+
+        let lon = x.atan2(self.northp.ternary(-y, y)).to_degrees();
+
+        self.northp.ternary(lon, -lon)
+    }
+
+    /// Converts from [`Mgrs`] to [`UtmUps`]
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{Mgrs, UtmUps};
+    /// 
+    /// let coord = Mgrs::parse_str("18TWL856641113154").unwrap();
+    /// let coord_utm = UtmUps::create(18, true, 585664.15, 4511315.45).unwrap();
+    /// 
+    /// let converted = coord.to_utmups();
+    /// 
+    /// // Check if the converted coordinate is accurate to 6 decimals (same as reference)
+    /// assert_eq!(coord_utm.zone(), converted.zone());
+    /// assert_eq!(coord_utm.is_north(), converted.is_north());
+    /// assert!(coord_utm.approx_eq(&converted, 1e-2));
+    /// ```
+    pub fn from_mgrs(value: &Mgrs) -> UtmUps {
+        value.utm
+    }
+
+    /// Converts from [`UtmUps`] to [`Mgrs`]
+    /// 
+    /// `precision` is clamped to the range `[-1, 11]`, see [`Mgrs::from_utmups`].
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{Mgrs, UtmUps};
+    /// 
+    /// let coord = Mgrs::parse_str("18TWL856641113154").unwrap();
+    /// let coord_utm = UtmUps::create(18, true, 585664.15, 4511315.45).unwrap();
+    /// 
+    /// let converted = Mgrs::from_utmups(&coord_utm, 6);
+    /// 
+    /// // Check if the converted coordinate is accurate to 6 decimals (same as reference)
+    /// assert_eq!(coord.zone(), converted.zone());
+    /// assert_eq!(coord.is_north(), converted.is_north());
+    /// assert!((coord.easting() - converted.easting()).abs() < 1e-2);
+    /// assert!((coord.northing() - converted.northing()).abs() < 1e-2);
+    /// assert_eq!(coord.precision(), converted.precision());
+    /// ```
+    pub fn to_mgrs(&self, precision: i32) -> Mgrs {
+        Mgrs::from_utmups(self, precision)
+    }
+
+    /// Converts from [`UtmUps`] to [`Mgrs`] with precision `5`, i.e. 1 m squares.
+    /// See [`LatLon::to_mgrs_default`].
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::UtmUps;
+    /// 
+    /// let coord = UtmUps::create(18, true, 585664.15, 4511315.45).unwrap();
+    /// 
+    /// let converted = coord.to_mgrs_default();
+    /// 
+    /// assert_eq!(converted, coord.to_mgrs(5));
+    /// assert_eq!(converted.to_string(), "18TWL8566411315");
+    /// ```
+    pub fn to_mgrs_default(&self) -> Mgrs {
+        self.to_mgrs(mgrs::MAX_MEANINGFUL_PRECISION)
+    }
+}
+
+/// Projects a lat/lon into the given zone and hemisphere, neither of which is
+/// checked, returning the easting and northing (including the false origin).
+pub(crate) fn to_grid(zone: i32, northp: bool, lat: f64, lon: f64, tm: &TransverseMercator, ps: &PolarStereographic) -> (f64, f64) {
+    let utmp = zone != zonespec::UPS;
+    let (x, y) = if utmp {
+        tm.from_latlon(central_meridian(zone), lat, lon)
+    } else {
+        ps.from_latlon(northp, lat, lon)
+    };
 
     let ind = utmp.ternary(2, 0) + northp.ternary(1, 0);
-    if x < f64::from(MIN_EASTING[ind]) - slop || x > f64::from(MAX_EASTING[ind]) + slop {
-        return Err(Error::InvalidUtmCoords(
-            format!(
-                "Easting {:.2}km not in {}{} range for {} hemisphere [{:.2}km, {:.2}km]",
-                x / 1000.0,
-                mgrs_limits.ternary("MGRS/", ""),
-                utmp.ternary("UTM", "UPS"),
-                northp.ternary("N", "S"),
-                (f64::from(MIN_EASTING[ind]) - slop) / 1000.0,
-                (f64::from(MAX_EASTING[ind]) + slop) / 1000.0,
-            )
-        ));
-    }
-
-    if y < f64::from(MIN_NORTHING[ind]) - slop || y > f64::from(MAX_NORTHING[ind]) + slop {
-        return Err(Error::InvalidUtmCoords(
-            format!(
-                "Northing {:.2}km not in {}{} range for {} hemisphere [{:.2}km, {:.2}km]",
-                y / 1000.0,
-                mgrs_limits.ternary("MGRS/", ""),
-                utmp.ternary("UTM", "UPS"),
-                northp.ternary("N", "S"),
-                (f64::from(MIN_NORTHING[ind]) - slop) / 1000.0,
-                (f64::from(MAX_NORTHING[ind]) + slop) / 1000.0,
-            )
-        ));
-    }
-
-    Ok(())
+
+    (x + f64::from(FALSE_EASTING[ind]), y + f64::from(FALSE_NORTHING[ind]))
 }
 
-impl std::fmt::Display for UtmUps {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}{} {} {}",
-            self.zone,
-            self.northp.ternary("n", "s"),
-            self.easting,
-            self.northing
-        )
+pub(crate) fn central_meridian(zone: i32) -> f64 {
+    6.0 * f64::from(zone) - 183.
+}
+
+/// Returns the whole number of degrees of the longitude, normalized to `[-180, 180)`
+fn longitude_index(lon: f64) -> i32 {
+    let lon_int = lon.ang_normalize().floor() as i32;
+
+    (lon_int == dms::HD).ternary(-dms::HD, lon_int)
+}
+
+// Map lat/lon to zone in either UTM or UPS based on position.
+fn standard_zone(lat: f64, lon: f64, setzone: i32) -> i32 {
+    if setzone >= zonespec::MINZONE || setzone == zonespec::INVALID {
+        return setzone;
+    }
+
+    if setzone == zonespec::UTM || ((-80_f64)..84.0).contains(&lat) {
+        let lon_int = longitude_index(lon);
+
+        let mut zone = (lon_int + 186) / 6;
+        let band = to_latitude_band(lat);
+        // The Norway exception
+        if band == 7 && zone == 31 && lon_int >= 3 {
+            zone = 32;
+        }
+        // The Svalbard exception
+        else if band == 9 && (0..42).contains(&lon_int) {
+            zone = 2 * ((lon_int + 183) / 12) + 1;
+        }
+
+        zone
+    } else {
+        zonespec::UPS
+    }
+}
+
+/// Checks that the easting/northing are within the limits for UTM/UPS,
+/// returning the coordinate normalized to the correct hemisphere as
+/// `(northp, x, y)`.
+/// 
+/// Without `mgrs_limits`, the limits are extended by 100 km of slop and UTM
+/// northings on the wrong side of the equator are moved to the other
+/// hemisphere (see [`UtmUps::create`]). With it, the stricter MGRS limits of
+/// [GeographicLib](https://geographiclib.sourceforge.io/C++/doc/classGeographicLib_1_1MGRS.html)
+/// apply: points exactly on an upper limit are moved just inside it, UTM
+/// northings are normalized by MGRS row (keeping points on the equator in the
+/// southern hemisphere), and errors are reported as [`Error::InvalidMgrs`].
+pub(crate) fn check_coords(zone: i32, northp: bool, x: f64, y: f64, mgrs_limits: bool) -> Result<(bool, f64, f64), Error> {
+    let slop = mgrs_limits.ternary(0.0, f64::from(mgrs::TILE));
+    let ang_eps = 1_f64 * 2_f64.powi(-(f64::DIGITS as i32 - 25));
+
+    let utmp = zone != zonespec::UPS;
+    let ind = utmp.ternary(2, 0) + northp.ternary(1, 0);
+
+    let easting_limits = (f64::from(MIN_EASTING[ind]) - slop, f64::from(MAX_EASTING[ind]) + slop);
+    let northing_limits = (f64::from(MIN_NORTHING[ind]) - slop, f64::from(MAX_NORTHING[ind]) + slop);
+
+    let out_of_range = |axis: &str, value: f64, limits: (f64, f64)| {
+        let msg = format!(
+            "{axis} {:.2}km not in {}{} range for {} hemisphere [{:.2}km, {:.2}km]",
+            value / 1000.0,
+            mgrs_limits.ternary("MGRS/", ""),
+            utmp.ternary("UTM", "UPS"),
+            northp.ternary("N", "S"),
+            limits.0 / 1000.0,
+            limits.1 / 1000.0,
+        );
+
+        if mgrs_limits {
+            Error::InvalidMgrs(msg)
+        } else {
+            Error::UtmOutOfRange {
+                zone,
+                northp,
+                easting: x,
+                northing: y,
+                easting_limits,
+                northing_limits,
+                msg,
+            }
+        }
+    };
+
+    let check = |axis: &str, value: f64, min: i32, max: i32| {
+        let (min_value, max_value) = (f64::from(min), f64::from(max));
+
+        if mgrs_limits {
+            // MGRS ranges don't include their upper limit
+            if (min_value..max_value).contains(&value) {
+                Ok(value)
+            } else if value.eps_eq(max_value) {
+                Ok(value - ang_eps)
+            } else {
+                Err(out_of_range(axis, value, (min_value, max_value)))
+            }
+        } else if (min_value - slop..=max_value + slop).contains(&value) {
+            Ok(value)
+        } else {
+            Err(out_of_range(axis, value, (min_value - slop, max_value + slop)))
+        }
+    };
+
+    let x = check("Easting", x, MIN_EASTING[ind], MAX_EASTING[ind])?;
+    let y = check("Northing", y, MIN_NORTHING[ind], MAX_NORTHING[ind])?;
+
+    let shift = f64::from(mgrs::UTM_N_SHIFT);
+    // MGRS moves northern points below its southernmost northern row to the
+    // southern hemisphere
+    let min_north = mgrs_limits.ternary(f64::from(mgrs::MINUTM_S_ROW * mgrs::TILE), 0.0);
+
+    if utmp && northp && y < min_north {
+        Ok((false, x, y + shift))
+    } else if utmp && !northp && y >= shift {
+        if mgrs_limits && y.eps_eq(shift) {
+            Ok((northp, x, y - ang_eps))
+        } else {
+            Ok((true, x, y - shift))
+        }
+    } else {
+        Ok((northp, x, y))
+    }
+}
+
+/// Converts from [`LatLon`] to [`UtmUps`] using the standard UTM zone. Equivalent
+/// to [`UtmUps::from_latlon`].
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::{LatLon, UtmUps};
+/// 
+/// let coord = LatLon::create(40.748333, -73.985278).unwrap();
+/// let utm: UtmUps = (&coord).into();
+/// 
+/// assert_eq!(utm.zone(), 18);
+/// assert!(utm.is_north());
+/// assert!((utm.easting() - 585664.121).abs() < 1e-3);
+/// assert!((utm.northing() - 4511315.422).abs() < 1e-3);
+/// ```
+impl From<&LatLon> for UtmUps {
+    fn from(value: &LatLon) -> Self {
+        UtmUps::from_latlon(value)
     }
-}
\ No newline at end of file
+}
+
+impl PartialEq for UtmUps {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for UtmUps {}
+
+impl core::hash::Hash for UtmUps {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.zone.hash(state);
+        self.northp.hash(state);
+        self.easting.to_bits().hash(state);
+        self.northing.to_bits().hash(state);
+    }
+}
+
+impl PartialOrd for UtmUps {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UtmUps {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.zone.cmp(&other.zone)
+            .then(self.northp.cmp(&other.northp))
+            .then(self.easting.total_cmp(&other.easting))
+            .then(self.northing.total_cmp(&other.northing))
+    }
+}
+
+/// Formats the point as the zone, hemisphere letter, easting and northing, e.g.
+/// `18n 585664.121 4511315.422`. The easting and northing are printed in full
+/// unless a precision is given, in which case they are rounded to that many
+/// decimal places.
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::UtmUps;
+/// 
+/// let coord = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap();
+/// 
+/// assert_eq!(coord.to_string(), "18n 585664.121 4511315.422");
+/// assert_eq!(format!("{coord:.2}"), "18n 585664.12 4511315.42");
+/// assert_eq!(format!("{coord:.0}"), "18n 585664 4511315");
+/// ```
+impl core::fmt::Display for UtmUps {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let hemisphere = self.northp.ternary("n", "s");
+
+        if let Some(precision) = f.precision() {
+            write!(f, "{}{hemisphere} {:.precision$} {:.precision$}", self.zone, self.easting, self.northing)
+        } else {
+            write!(f, "{}{hemisphere} {} {}", self.zone, self.easting, self.northing)
+        }
+    }
+}
+
+/// Parses a UTM/UPS string in the format output by [`Display`](core::fmt::Display),
+/// e.g. `18n 585664.121 4511315.422`, validating it with [`UtmUps::create`].
+/// The hemisphere letter is case-insensitive, and the zone may be given as `0`
+/// or omitted for UPS (e.g. `n 2000000 2000000`).
+/// 
+/// # Errors
+/// 
+/// Returns [`Error::InvalidUtmCoords`] if the string is malformed,
+/// [`Error::UtmOutOfRange`] if the easting/northing are out of range, and
+/// [`Error::InvalidZone`] if the zone is outside the range `[0, 60]`.
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::UtmUps;
+/// 
+/// let coord: UtmUps = "18N 585664.121 4511315.422".parse().unwrap();
+/// assert_eq!(coord.zone(), 18);
+/// assert!(coord.is_north());
+/// assert_eq!(coord.easting(), 585664.121);
+/// assert_eq!(coord.northing(), 4511315.422);
+/// 
+/// // Round trips with the Display output
+/// assert_eq!(coord.to_string().parse::<UtmUps>().unwrap(), coord);
+/// 
+/// // UPS, with or without the zone
+/// let coord: UtmUps = "s 2000000 2000000".parse().unwrap();
+/// assert_eq!(coord.zone(), 0);
+/// assert!(!coord.is_north());
+/// assert_eq!("0s 2000000 2000000".parse::<UtmUps>().unwrap(), coord);
+/// 
+/// assert!("18 585664 4511315".parse::<UtmUps>().is_err());
+/// assert!("18n 585664".parse::<UtmUps>().is_err());
+/// assert!("18n east 4511315".parse::<UtmUps>().is_err());
+/// ```
+impl FromStr for UtmUps {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = |msg: &str| Error::InvalidUtmCoords(format!("Unable to parse \"{value}\": {msg}"));
+
+        let mut parts = value.split_whitespace();
+        let (Some(zone_hemi), Some(easting), Some(northing), None) = (parts.next(), parts.next(), parts.next(), parts.next()) else {
+            return Err(invalid("expected a zone, easting and northing separated by spaces"));
+        };
+
+        let (zone, northp) = if let Some(zone) = zone_hemi.strip_suffix(['n', 'N']) {
+            (zone, true)
+        } else if let Some(zone) = zone_hemi.strip_suffix(['s', 'S']) {
+            (zone, false)
+        } else {
+            return Err(invalid("zone must end with a hemisphere, 'n' or 's'"));
+        };
+        let zone = if zone.is_empty() {
+            zonespec::UPS
+        } else if zone.len() <= 2 && zone.bytes().all(|c| c.is_ascii_digit()) {
+            zone.parse().map_err(|_| invalid("zone is not a number"))?
+        } else {
+            return Err(invalid("zone is not a number"));
+        };
+        let easting = easting.parse().map_err(|_| invalid("easting is not a number"))?;
+        let northing = northing.parse().map_err(|_| invalid("northing is not a number"))?;
+
+        UtmUps::create(zone, northp, easting, northing)
+    }
+}
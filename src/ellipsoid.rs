@@ -0,0 +1,105 @@
+use alloc::format;
+
+#[cfg(not(feature = "std"))]
+use num::traits::Float;
+
+use crate::{Error, constants::{WGS84_A, WGS84_F, GRS80_A, GRS80_F, CLARKE1866_A, CLARKE1866_F, AIRY1830_A, AIRY1830_F}, ThisOrThat};
+
+/// A reference ellipsoid, defined by its equatorial radius `a` (in meters)
+/// and its flattening `f`. All conversions default to [`Ellipsoid::WGS84`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ellipsoid {
+    pub(crate) a: f64,
+    pub(crate) f: f64,
+}
+
+impl Ellipsoid {
+    /// The WGS84 ellipsoid, used by GPS and by default throughout this library.
+    pub const WGS84: Ellipsoid = Ellipsoid { a: WGS84_A, f: WGS84_F };
+    /// The GRS80 ellipsoid, used by NAD83 and ETRS89.
+    pub const GRS80: Ellipsoid = Ellipsoid { a: GRS80_A, f: GRS80_F };
+    /// The Clarke 1866 ellipsoid, used by NAD27.
+    pub const CLARKE1866: Ellipsoid = Ellipsoid { a: CLARKE1866_A, f: CLARKE1866_F };
+    /// The Airy 1830 ellipsoid, used by OSGB36.
+    pub const AIRY1830: Ellipsoid = Ellipsoid { a: AIRY1830_A, f: AIRY1830_F };
+
+    /// Tries to create an ellipsoid from its equatorial radius `a` (in meters)
+    /// and flattening `f`.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidEllipsoid`] if `a` is not positive or `f` is not
+    /// less than 1.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::Ellipsoid;
+    /// 
+    /// let ellipsoid = Ellipsoid::create(6_378_137.0, 1.0 / 298.257222101);
+    /// assert!(ellipsoid.is_ok());
+    /// 
+    /// let invalid_radius = Ellipsoid::create(-1.0, 0.0);
+    /// assert!(invalid_radius.is_err());
+    /// 
+    /// let invalid_flattening = Ellipsoid::create(6_378_137.0, 1.0);
+    /// assert!(invalid_flattening.is_err());
+    /// ```
+    pub fn create(a: f64, f: f64) -> Result<Ellipsoid, Error> {
+        if !(a.is_finite() && a > 0.0) {
+            Err(Error::InvalidEllipsoid(format!("Equatorial radius {a} must be positive.")))
+        } else if !(f.is_finite() && f < 1.0) {
+            Err(Error::InvalidEllipsoid(format!("Flattening {f} must be less than 1.")))
+        } else {
+            Ok(Ellipsoid { a, f })
+        }
+    }
+
+    /// Returns the equatorial radius in meters.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::Ellipsoid;
+    /// 
+    /// assert_eq!(Ellipsoid::WGS84.a(), 6_378_137.0);
+    /// ```
+    #[inline]
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    /// Returns the flattening.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::Ellipsoid;
+    /// 
+    /// assert_eq!(Ellipsoid::WGS84.f(), 1.0 / 298.257223563);
+    /// ```
+    #[inline]
+    pub fn f(&self) -> f64 {
+        self.f
+    }
+
+    /// Eccentricity squared
+    pub(crate) fn e2(&self) -> f64 {
+        self.f * (2.0 - self.f)
+    }
+
+    /// Signed eccentricity, negative for prolate ellipsoids
+    pub(crate) fn es(&self) -> f64 {
+        (self.f < 0.0).ternary(-1.0, 1.0) * self.e2().abs().sqrt()
+    }
+
+    /// Third flattening
+    pub(crate) fn n(&self) -> f64 {
+        self.f / (2.0 - self.f)
+    }
+}
+
+impl Default for Ellipsoid {
+    fn default() -> Self {
+        Ellipsoid::WGS84
+    }
+}
@@ -0,0 +1,145 @@
+//! Conversions to and from [`geo_types`], enabled by the `geo` feature.
+//! 
+//! Points are mapped with `x` as longitude (or easting) and `y` as latitude
+//! (or northing), following the `geo` convention.
+
+use geo_types::{Coord, Point};
+
+use crate::{Error, latlon::LatLon, utm::UtmUps};
+
+/// Converts a [`LatLon`] to a [`Coord`] with `x = longitude` and `y = latitude`.
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::LatLon;
+/// use geo_types::Coord;
+/// 
+/// let coord = Coord::from(LatLon::create(40.748333, -73.985278).unwrap());
+/// 
+/// assert_eq!(coord.x, -73.985278);
+/// assert_eq!(coord.y, 40.748333);
+/// ```
+impl From<LatLon> for Coord<f64> {
+    fn from(value: LatLon) -> Self {
+        Coord { x: value.longitude, y: value.latitude }
+    }
+}
+
+/// Converts a [`LatLon`] to a [`Point`] with `x = longitude` and `y = latitude`.
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::LatLon;
+/// use geo_types::Point;
+/// 
+/// let point: Point = LatLon::create(40.748333, -73.985278).unwrap().into();
+/// 
+/// assert_eq!(point.x(), -73.985278);
+/// assert_eq!(point.y(), 40.748333);
+/// ```
+impl From<LatLon> for Point<f64> {
+    fn from(value: LatLon) -> Self {
+        Point(value.into())
+    }
+}
+
+/// Converts a [`Coord`] with `x = longitude` and `y = latitude` to a [`LatLon`],
+/// validated the same way as [`LatLon::create`].
+/// 
+/// # Errors
+/// 
+/// Returns [`Error::LatLonOutOfRange`] or [`Error::InvalidCoord`] if the
+/// latitude or longitude is invalid, see [`LatLon::create`].
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::LatLon;
+/// use geo_types::coord;
+/// 
+/// let coord = LatLon::try_from(coord! { x: -73.985278, y: 40.748333 }).unwrap();
+/// 
+/// assert_eq!(coord.latitude(), 40.748333);
+/// assert_eq!(coord.longitude(), -73.985278);
+/// 
+/// // Latitude out of range, likely from swapping x and y
+/// assert!(LatLon::try_from(coord! { x: 40.748333, y: -173.985278 }).is_err());
+/// ```
+impl TryFrom<Coord<f64>> for LatLon {
+    type Error = Error;
+
+    fn try_from(value: Coord<f64>) -> Result<Self, Self::Error> {
+        LatLon::create(value.y, value.x)
+    }
+}
+
+/// Converts a [`Point`] with `x = longitude` and `y = latitude` to a [`LatLon`],
+/// validated the same way as [`LatLon::create`].
+/// 
+/// # Errors
+/// 
+/// Returns [`Error::LatLonOutOfRange`] or [`Error::InvalidCoord`] if the
+/// latitude or longitude is invalid, see [`LatLon::create`].
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::LatLon;
+/// use geo_types::point;
+/// 
+/// let coord: LatLon = point! { x: -73.985278, y: 40.748333 }.try_into().unwrap();
+/// 
+/// assert_eq!(coord.latitude(), 40.748333);
+/// assert_eq!(coord.longitude(), -73.985278);
+/// ```
+impl TryFrom<Point<f64>> for LatLon {
+    type Error = Error;
+
+    fn try_from(value: Point<f64>) -> Result<Self, Self::Error> {
+        value.0.try_into()
+    }
+}
+
+/// Converts a [`UtmUps`] to a [`Coord`] with `x = easting` and `y = northing`.
+/// The zone and hemisphere are dropped, so only points from the same zone and
+/// hemisphere should be combined.
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::UtmUps;
+/// use geo_types::Coord;
+/// 
+/// let coord = Coord::from(UtmUps::create(18, true, 585664.121, 4511315.422).unwrap());
+/// 
+/// assert_eq!(coord.x, 585664.121);
+/// assert_eq!(coord.y, 4511315.422);
+/// ```
+impl From<UtmUps> for Coord<f64> {
+    fn from(value: UtmUps) -> Self {
+        Coord { x: value.easting, y: value.northing }
+    }
+}
+
+/// Converts a [`UtmUps`] to a [`Point`] with `x = easting` and `y = northing`.
+/// The zone and hemisphere are dropped, so only points from the same zone and
+/// hemisphere should be combined.
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::UtmUps;
+/// use geo_types::Point;
+/// 
+/// let point: Point = UtmUps::create(18, true, 585664.121, 4511315.422).unwrap().into();
+/// 
+/// assert_eq!(point.x(), 585664.121);
+/// assert_eq!(point.y(), 4511315.422);
+/// ```
+impl From<UtmUps> for Point<f64> {
+    fn from(value: UtmUps) -> Self {
+        Point(value.into())
+    }
+}
@@ -0,0 +1,167 @@
+use core::f64::consts::PI;
+
+#[cfg(not(feature = "std"))]
+use num::traits::Float;
+
+use crate::{ellipsoid::Ellipsoid, utility::{GeoMath, dms}};
+
+/// Maximum number of iterations of lambda before giving up. Only nearly
+/// antipodal points need anywhere near this many.
+const MAX_ITERATIONS: usize = 200;
+/// Convergence tolerance of lambda in radians (about 0.006mm)
+const TOLERANCE: f64 = 1e-12;
+
+/// Solves the inverse geodesic problem using
+/// [Vincenty's formulae](https://en.wikipedia.org/wiki/Vincenty%27s_formulae).
+/// Takes the two points in degrees and returns the distance between them in
+/// meters along with the forward azimuths at each point in degrees.
+/// 
+/// Vincenty's iteration does not converge for some nearly antipodal points; in
+/// that case the last iterate is used after [`MAX_ITERATIONS`].
+#[allow(clippy::similar_names)]
+#[allow(clippy::many_single_char_names)]
+pub(crate) fn inverse(ellipsoid: Ellipsoid, lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> (f64, f64, f64) {
+    let a = ellipsoid.a;
+    let f = ellipsoid.f;
+    let b = a * (1.0 - f);
+
+    let l = lon1.ang_diff(lon2).to_radians();
+    // Reduced latitudes
+    let (sin_u1, cos_u1) = ((1.0 - f) * lat1.to_radians().tan()).atan().sin_cos();
+    let (sin_u2, cos_u2) = ((1.0 - f) * lat2.to_radians().tan()).atan().sin_cos();
+
+    let mut lambda = l;
+    let mut sin_sigma;
+    let mut cos_sigma;
+    let mut sigma;
+    let mut cos2_alpha;
+    let mut cos_2sigma_m;
+
+    let mut iterations = 0;
+    loop {
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        sin_sigma = (cos_u2 * sin_lambda).hypot(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+
+        cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+
+        if sin_sigma.is_zero() {
+            if cos_sigma > 0.0 {
+                // Coincident points
+                return (0.0, 0.0, 0.0);
+            }
+
+            // Exactly antipodal points, the geodesic runs along a meridian (alpha = 0)
+            let distance = b * big_a((a.powi(2) - b.powi(2)) / b.powi(2)) * PI;
+            return (distance, 0.0, f64::from(dms::HD));
+        }
+
+        sigma = sin_sigma.atan2(cos_sigma);
+
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        cos2_alpha = 1.0 - sin_alpha.powi(2);
+        // Equatorial lines have cos2_alpha == 0
+        cos_2sigma_m = if cos2_alpha.is_zero() { 0.0 } else { cos_sigma - 2.0 * sin_u1 * sin_u2 / cos2_alpha };
+
+        let c = f / 16.0 * cos2_alpha * (4.0 + f * (4.0 - 3.0 * cos2_alpha));
+        let lambda_prev = lambda;
+        lambda = l + (1.0 - c) * f * sin_alpha
+            * (sigma + c * sin_sigma * (cos_2sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))));
+
+        iterations += 1;
+        if (lambda - lambda_prev).abs() < TOLERANCE || iterations >= MAX_ITERATIONS {
+            break;
+        }
+    }
+
+    let u2 = cos2_alpha * (a.powi(2) - b.powi(2)) / b.powi(2);
+    let delta_sigma = delta_sigma(big_b(u2), sin_sigma, cos_sigma, cos_2sigma_m);
+
+    let distance = b * big_a(u2) * (sigma - delta_sigma);
+
+    let (sin_lambda, cos_lambda) = lambda.sin_cos();
+    let azi1 = (cos_u2 * sin_lambda).atan2(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+    let azi2 = (cos_u1 * sin_lambda).atan2(-sin_u1 * cos_u2 + cos_u1 * sin_u2 * cos_lambda);
+
+    (distance, azi1.to_degrees(), azi2.to_degrees())
+}
+
+/// Solves the direct geodesic problem using
+/// [Vincenty's formulae](https://en.wikipedia.org/wiki/Vincenty%27s_formulae).
+/// Takes the starting point and the forward azimuth there in degrees and the
+/// distance in meters, and returns the end point in degrees along with the
+/// forward azimuth there in degrees. The longitude is not normalized.
+#[allow(clippy::similar_names)]
+#[allow(clippy::many_single_char_names)]
+pub(crate) fn direct(ellipsoid: Ellipsoid, lat1: f64, lon1: f64, azi1: f64, distance: f64) -> (f64, f64, f64) {
+    let a = ellipsoid.a;
+    let f = ellipsoid.f;
+    let b = a * (1.0 - f);
+
+    let (sin_alpha1, cos_alpha1) = azi1.to_radians().sin_cos();
+    // Reduced latitude
+    let (sin_u1, cos_u1) = ((1.0 - f) * lat1.to_radians().tan()).atan().sin_cos();
+
+    // Angular distance on the sphere from the equator to the start point
+    let sigma1 = sin_u1.atan2(cos_u1 * cos_alpha1);
+    let sin_alpha = cos_u1 * sin_alpha1;
+    let cos2_alpha = 1.0 - sin_alpha.powi(2);
+
+    let u2 = cos2_alpha * (a.powi(2) - b.powi(2)) / b.powi(2);
+    let big_b = big_b(u2);
+    let sigma0 = distance / (b * big_a(u2));
+
+    let mut sigma = sigma0;
+    let mut sin_sigma;
+    let mut cos_sigma;
+    let mut cos_2sigma_m;
+
+    let mut iterations = 0;
+    loop {
+        cos_2sigma_m = (2.0 * sigma1 + sigma).cos();
+        (sin_sigma, cos_sigma) = sigma.sin_cos();
+
+        let sigma_prev = sigma;
+        sigma = sigma0 + delta_sigma(big_b, sin_sigma, cos_sigma, cos_2sigma_m);
+
+        iterations += 1;
+        if (sigma - sigma_prev).abs() < TOLERANCE || iterations >= MAX_ITERATIONS {
+            break;
+        }
+    }
+
+    (sin_sigma, cos_sigma) = sigma.sin_cos();
+    cos_2sigma_m = (2.0 * sigma1 + sigma).cos();
+
+    let tmp = sin_u1 * sin_sigma - cos_u1 * cos_sigma * cos_alpha1;
+    let lat2 = (sin_u1 * cos_sigma + cos_u1 * sin_sigma * cos_alpha1).atan2((1.0 - f) * sin_alpha.hypot(tmp));
+
+    let lambda = (sin_sigma * sin_alpha1).atan2(cos_u1 * cos_sigma - sin_u1 * sin_sigma * cos_alpha1);
+    let c = f / 16.0 * cos2_alpha * (4.0 + f * (4.0 - 3.0 * cos2_alpha));
+    let l = lambda - (1.0 - c) * f * sin_alpha
+        * (sigma + c * sin_sigma * (cos_2sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))));
+
+    let azi2 = sin_alpha.atan2(-tmp);
+
+    (lat2.to_degrees(), lon1 + l.to_degrees(), azi2.to_degrees())
+}
+
+/// Vincenty's A coefficient for a given u²
+fn big_a(u2: f64) -> f64 {
+    1.0 + u2 / 16384.0 * (4096.0 + u2 * (-768.0 + u2 * (320.0 - 175.0 * u2)))
+}
+
+/// Vincenty's B coefficient for a given u²
+fn big_b(u2: f64) -> f64 {
+    u2 / 1024.0 * (256.0 + u2 * (-128.0 + u2 * (74.0 - 47.0 * u2)))
+}
+
+/// The difference between the angular distances on the auxiliary sphere and
+/// the ellipsoid
+fn delta_sigma(big_b: f64, sin_sigma: f64, cos_sigma: f64, cos_2sigma_m: f64) -> f64 {
+    big_b * sin_sigma * (
+        cos_2sigma_m + big_b / 4.0 * (
+            cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2)) -
+            big_b / 6.0 * cos_2sigma_m * (-3.0 + 4.0 * sin_sigma.powi(2)) * (-3.0 + 4.0 * cos_2sigma_m.powi(2))
+        )
+    )
+}
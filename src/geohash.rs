@@ -0,0 +1,107 @@
+//! [Geohash](https://en.wikipedia.org/wiki/Geohash) encoding and decoding of
+//! [`LatLon`], enabled by the `geohash` feature.
+
+use alloc::{format, string::String};
+
+use crate::{Error, LatLon, ThisOrThat};
+
+/// The geohash base 32 alphabet, which leaves out `a`, `i`, `l` and `o`
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+impl LatLon {
+    /// Encodes the point as a geohash of `len` characters. Each character
+    /// narrows down the cell the point is in, alternating between longitude and
+    /// latitude, e.g. 5 characters is a cell of about 4.9 km by 4.9 km at the
+    /// equator, and 12 characters about 3.7 cm by 1.9 cm.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let coord = LatLon::create(57.64911, 10.40744).unwrap();
+    /// assert_eq!(coord.to_geohash(11), "u4pruydqqvj");
+    /// assert_eq!(coord.to_geohash(5), "u4pru");
+    /// ```
+    pub fn to_geohash(&self, len: usize) -> String {
+        let mut lat_range = (-90.0, 90.0);
+        let mut lon_range = (-180.0, 180.0);
+        let mut geohash = String::with_capacity(len);
+
+        for i in 0..len {
+            let mut idx = 0;
+
+            for bit in 0..5 {
+                // Longitude comes first, and the bits alternate from there
+                let lonp = (5 * i + bit) % 2 == 0;
+                let (value, range) = lonp.ternary((self.longitude, &mut lon_range), (self.latitude, &mut lat_range));
+
+                let mid = f64::midpoint(range.0, range.1);
+                idx <<= 1;
+
+                if value >= mid {
+                    idx |= 1;
+                    range.0 = mid;
+                } else {
+                    range.1 = mid;
+                }
+            }
+
+            geohash.push(char::from(BASE32[idx]));
+        }
+
+        geohash
+    }
+
+    /// Decodes a geohash into the point at the center of its cell. Uppercase
+    /// characters are accepted too.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidGeohash`] if the string is empty or has characters
+    /// outside the geohash alphabet.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::LatLon;
+    /// 
+    /// let coord = LatLon::from_geohash("u4pruydqqvj").unwrap();
+    /// assert!(coord.approx_eq(&LatLon::create(57.64911, 10.40744).unwrap(), 1e-5));
+    /// 
+    /// let coord = LatLon::from_geohash("EZS42").unwrap();
+    /// assert!(coord.approx_eq(&LatLon::create(42.605, -5.603).unwrap(), 1e-3));
+    /// 
+    /// assert!(LatLon::from_geohash("").is_err());
+    /// assert!(LatLon::from_geohash("u4pa").is_err());
+    /// ```
+    pub fn from_geohash(geohash: &str) -> Result<LatLon, Error> {
+        if geohash.is_empty() {
+            return Err(Error::InvalidGeohash(String::from("Geohash is empty")));
+        }
+
+        let mut lat_range = (-90.0, 90.0);
+        let mut lon_range = (-180.0, 180.0);
+
+        for (i, c) in geohash.bytes().enumerate() {
+            let Some(idx) = BASE32.iter().position(|&b| b == c.to_ascii_lowercase()) else {
+                return Err(Error::InvalidGeohash(format!("Invalid character in {geohash}")));
+            };
+
+            for bit in 0..5 {
+                let lonp = (5 * i + bit) % 2 == 0;
+                let range = lonp.ternary(&mut lon_range, &mut lat_range);
+
+                let mid = f64::midpoint(range.0, range.1);
+
+                if idx & (0b10000 >> bit) == 0 {
+                    range.1 = mid;
+                } else {
+                    range.0 = mid;
+                }
+            }
+        }
+
+        LatLon::create(f64::midpoint(lat_range.0, lat_range.1), f64::midpoint(lon_range.0, lon_range.1))
+    }
+}
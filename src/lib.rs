@@ -7,6 +7,10 @@
 //! * [`UtmUps`]
 //! * [`Mgrs`]
 //! 
+//! [`Position`] can hold a point in any of them, and parses strings in any of their formats.
+//! The [`map_projection`] module exposes the underlying transverse Mercator projection for
+//! custom grids, and the [`osgb`] module handles British National Grid references.
+//! 
 //! The implementation of this library is a translation of a subset of 
 //! [GeographicLib](https://geographiclib.sourceforge.io/C++/doc/index.html) from C++ to Rust. Specifically, `geoconvert`
 //! implements some of the functionality of the [GeoConvert](https://geographiclib.sourceforge.io/C++/doc/GeoConvert.1.html) 
@@ -37,7 +41,24 @@
 //! ## Features
 //! 
 //! If you want `serde` compatibility with `Serialize`/`Deserialize`, activate the `serde` feature.
+//! To serialize [`LatLon`] or [`Mgrs`] as strings instead, see the `serde_string` module.
+//! For formats that don't support `#[serde(flatten)]` (such as `bincode` or CSV), serialize
+//! [`Mgrs`] with the `serde_fields` module.
+//! 
+//! The `geo` feature adds conversions between [`LatLon`]/[`UtmUps`] and the
+//! [`geo-types`](https://crates.io/crates/geo-types) `Point` and `Coord` types.
+//! 
+//! The `csv` feature adds the `reader` module, for reading [`LatLon`], [`UtmUps`] or
+//! [`Mgrs`] coordinates from the rows of a CSV file.
+//! 
+//! The `geohash` feature adds [`LatLon::to_geohash`] and [`LatLon::from_geohash`], for
+//! converting to and from [geohashes](https://en.wikipedia.org/wiki/Geohash).
+//! 
+//! The `std` feature is enabled by default. Disabling it (`default-features = false`) builds
+//! the library as `#![no_std]`, only requiring `alloc`, with floating point math provided
+//! by [`libm`](https://crates.io/crates/libm).
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(clippy::pedantic)]
 #![allow(
     // Don't require must_use
@@ -50,11 +71,17 @@
     clippy::cast_possible_truncation
 )]
 
+extern crate alloc;
+
+use alloc::{boxed::Box, string::String};
+
 use thiserror::Error;
 
 mod coords {
+    pub mod coordinate;
     pub mod latlon;
     pub mod mgrs;
+    pub mod position;
     pub mod utm;
 }
 
@@ -62,29 +89,102 @@ pub use coords::*;
 
 pub(crate) mod utility;
 
+pub mod ellipsoid;
+
+pub use ellipsoid::Ellipsoid;
+
+pub mod map_projection;
+
+pub mod osgb;
+
+pub use coordinate::Coordinate;
 pub use latlon::LatLon;
-pub use mgrs::Mgrs;
+pub use mgrs::{Mgrs, MgrsPrecision};
+pub use position::Position;
 pub use utm::UtmUps;
 
 pub(crate) mod projections {
     pub mod transverse_mercator;
     pub mod polar_stereographic;
+    pub mod wgs84;
 }
 
 pub(crate) mod constants;
 
+pub(crate) mod geodesic;
+
+#[cfg(feature = "serde")]
+pub mod serde_string;
+
+#[cfg(feature = "serde")]
+pub mod serde_fields;
+
+#[cfg(feature = "geo")]
+mod geo;
+
+#[cfg(feature = "csv")]
+pub mod reader;
+
+#[cfg(feature = "geohash")]
+mod geohash;
+
 #[derive(Debug, Error)]
 pub enum Error {
-    #[error("The provided precision is outside of range [1, 11]")]
+    #[error("The provided precision is outside of range [-1, 11]")]
     InvalidPrecision(i32),
     #[error("The provided zone is outside the valid range [0, 60]")]
     InvalidZone(i32),
     #[error("Coordinate parameters are not valid: {0}")]
     InvalidCoord(String),
+    /// The latitude or longitude given for a [`LatLon`] is outside of its
+    /// valid range.
+    #[error("Coordinate parameters are not valid: {msg}")]
+    LatLonOutOfRange {
+        lat: f64,
+        lon: f64,
+        msg: String,
+    },
     #[error("MGRS String is invalid: {0}")]
     InvalidMgrs(String),
     #[error("UTM coords are invalid: {0}")]
     InvalidUtmCoords(String),
+    /// The easting or northing given for a [`UtmUps`] is outside of the valid
+    /// range for its zone and hemisphere. `easting_limits` and
+    /// `northing_limits` are the valid `(min, max)` ranges in meters.
+    #[error("UTM coords are invalid: {msg}")]
+    UtmOutOfRange {
+        zone: i32,
+        northp: bool,
+        easting: f64,
+        northing: f64,
+        easting_limits: (f64, f64),
+        northing_limits: (f64, f64),
+        msg: String,
+    },
+    #[error("Ellipsoid parameters are invalid: {0}")]
+    InvalidEllipsoid(String),
+    #[error("Projection parameters are invalid: {0}")]
+    InvalidProjection(String),
+    #[error("OSGB grid reference is invalid: {0}")]
+    InvalidOsgb(String),
+    #[error("Geohash is invalid: {0}")]
+    InvalidGeohash(String),
+    #[error("CSV column is missing: {0}")]
+    MissingColumn(String),
+    /// Reading a CSV failed. With the `csv` feature, the source is a
+    /// `csv::Error`.
+    #[error("Unable to read CSV: {0}")]
+    Csv(#[source] Box<dyn core::error::Error + Send + Sync>),
+    #[error("Points are in different zones: {0}")]
+    ZoneMismatch(String),
+    /// The result would have more than `limit` items, e.g. the squares from
+    /// [`Mgrs::squares_in_bbox`] for a large area at a fine precision. The
+    /// input itself is valid.
+    #[error("Result would have more than {limit} items: {msg}")]
+    TooManyItems {
+        limit: usize,
+        msg: String,
+    },
     #[error("Coordinate type {coord_type} not valid for conversion to {dest_type}: {msg}")]
     InvalidRange {
         coord_type: String,
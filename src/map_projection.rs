@@ -0,0 +1,164 @@
+//! Raw map projections, without the false eastings/northings and zones of
+//! [`UtmUps`](crate::UtmUps).
+//! 
+//! [`TransverseMercator`] projects with any central meridian and central scale
+//! factor, using the same math as UTM (accurate to a few nanometers within
+//! 3,900 km of the central meridian). This is useful for custom grids and for
+//! experimenting with the projection itself.
+//! 
+//! # Usage
+//! 
+//! ```
+//! use geoconvert::{Ellipsoid, LatLon, map_projection::TransverseMercator};
+//! 
+//! // UTM zone 18, without the false easting
+//! let tm = TransverseMercator::create(Ellipsoid::WGS84, -75.0, 0.9996).unwrap();
+//! 
+//! let coord = LatLon::create(40.748333, -73.985278).unwrap();
+//! let (x, y) = tm.from_latlon(&coord);
+//! 
+//! let utm = coord.to_utmups();
+//! assert!((x + 500_000.0 - utm.easting()).abs() < 1e-6);
+//! assert!((y - utm.northing()).abs() < 1e-6);
+//! 
+//! assert!(tm.to_latlon(x, y).approx_eq(&coord, 1e-9));
+//! ```
+
+use alloc::format;
+
+use crate::{Error, Ellipsoid, LatLon, latlon::wrap_longitude, projections::transverse_mercator};
+
+/// A transverse Mercator projection on an [`Ellipsoid`], with a chosen central
+/// meridian and central scale factor.
+/// 
+/// Projected coordinates `(x, y)` are in meters, with `x` increasing to the
+/// east of the central meridian and `y` increasing to the north of the
+/// equator, so the origin is the point on the equator at the central meridian.
+#[derive(Clone, Debug)]
+pub struct TransverseMercator {
+    ellipsoid: Ellipsoid,
+    lon0: f64,
+    k0: f64,
+    inner: transverse_mercator::TransverseMercator,
+}
+
+impl TransverseMercator {
+    /// Tries to create a transverse Mercator projection on `ellipsoid` with
+    /// central meridian `lon0` (in degrees) and central scale factor `k0`.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidProjection`] if `lon0` is outside the range
+    /// `[-180, 180]` or `k0` is not positive.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{Ellipsoid, map_projection::TransverseMercator};
+    /// 
+    /// assert!(TransverseMercator::create(Ellipsoid::WGS84, -75.0, 0.9996).is_ok());
+    /// 
+    /// assert!(TransverseMercator::create(Ellipsoid::WGS84, 200.0, 0.9996).is_err());
+    /// assert!(TransverseMercator::create(Ellipsoid::WGS84, -75.0, 0.0).is_err());
+    /// ```
+    pub fn create(ellipsoid: Ellipsoid, lon0: f64, k0: f64) -> Result<TransverseMercator, Error> {
+        if !(-180_f64..=180_f64).contains(&lon0) {
+            Err(Error::InvalidProjection(format!("Central meridian {lon0} outside of valid range [-180, 180].")))
+        } else if !(k0.is_finite() && k0 > 0.0) {
+            Err(Error::InvalidProjection(format!("Central scale factor {k0} must be positive.")))
+        } else {
+            Ok(Self {
+                ellipsoid,
+                lon0,
+                k0,
+                inner: transverse_mercator::TransverseMercator::with_params(ellipsoid.a, ellipsoid.f, k0),
+            })
+        }
+    }
+
+    /// Returns the ellipsoid the projection is on.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::{Ellipsoid, map_projection::TransverseMercator};
+    /// 
+    /// let tm = TransverseMercator::create(Ellipsoid::GRS80, -75.0, 0.9996).unwrap();
+    /// assert_eq!(tm.ellipsoid(), Ellipsoid::GRS80);
+    /// ```
+    #[inline]
+    pub fn ellipsoid(&self) -> Ellipsoid {
+        self.ellipsoid
+    }
+
+    /// Returns the central meridian in degrees.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::{Ellipsoid, map_projection::TransverseMercator};
+    /// 
+    /// let tm = TransverseMercator::create(Ellipsoid::WGS84, -75.0, 0.9996).unwrap();
+    /// assert_eq!(tm.central_meridian(), -75.0);
+    /// ```
+    #[inline]
+    pub fn central_meridian(&self) -> f64 {
+        self.lon0
+    }
+
+    /// Returns the central scale factor.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::{Ellipsoid, map_projection::TransverseMercator};
+    /// 
+    /// let tm = TransverseMercator::create(Ellipsoid::WGS84, -75.0, 0.9996).unwrap();
+    /// assert_eq!(tm.scale_factor(), 0.9996);
+    /// ```
+    #[inline]
+    pub fn scale_factor(&self) -> f64 {
+        self.k0
+    }
+
+    /// Projects a [`LatLon`], returning `(x, y)` in meters.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{Ellipsoid, LatLon, map_projection::TransverseMercator};
+    /// 
+    /// let tm = TransverseMercator::create(Ellipsoid::WGS84, 0.0, 1.0).unwrap();
+    /// 
+    /// // The central meridian is projected onto the y axis, true to scale
+    /// let (x, y) = tm.from_latlon(&LatLon::create(45.0, 0.0).unwrap());
+    /// assert_eq!(x, 0.0);
+    /// assert!((y - 4_984_944.378).abs() < 1e-3);
+    /// 
+    /// // West of the central meridian
+    /// let (x, _) = tm.from_latlon(&LatLon::create(45.0, -1.0).unwrap());
+    /// assert!(x < 0.0);
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_latlon(&self, value: &LatLon) -> (f64, f64) {
+        self.inner.from_latlon(self.lon0, value.latitude, value.longitude)
+    }
+
+    /// Converts projected coordinates `(x, y)` in meters back to a [`LatLon`].
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{Ellipsoid, LatLon, map_projection::TransverseMercator};
+    /// 
+    /// let tm = TransverseMercator::create(Ellipsoid::WGS84, 0.0, 1.0).unwrap();
+    /// 
+    /// assert_eq!(tm.to_latlon(0.0, 0.0), LatLon::create(0.0, 0.0).unwrap());
+    /// 
+    /// let coord = LatLon::create(-33.8688, 2.5).unwrap();
+    /// let (x, y) = tm.from_latlon(&coord);
+    /// assert!(tm.to_latlon(x, y).approx_eq(&coord, 1e-9));
+    /// ```
+    pub fn to_latlon(&self, x: f64, y: f64) -> LatLon {
+        let coord = self.inner.to_latlon(self.lon0, x, y);
+
+        LatLon::new(coord.latitude, wrap_longitude(coord.longitude))
+    }
+}
@@ -0,0 +1,458 @@
+//! Grid references on the [British National Grid](https://en.wikipedia.org/wiki/Ordnance_Survey_National_Grid)
+//! of the Ordnance Survey, such as `TQ 30 80`.
+//! 
+//! The grid is a transverse Mercator projection of the OSGB36 datum, on the
+//! Airy 1830 ellipsoid, with its true origin at 49°N 2°W and a central scale
+//! factor of `0.9996012717`. The false origin is 400 km west and 100 km north
+//! of the true origin, so eastings are in `[0, 700,000)` and northings in
+//! `[0, 1,300,000)`.
+//! 
+//! Conversions to and from [`LatLon`] (WGS84) shift between the datums with the
+//! Ordnance Survey's 7-parameter Helmert transformation, which is accurate to
+//! within about 5 m across Great Britain. The reverse transformation uses the
+//! negated parameters, as recommended by the Ordnance Survey, so a round trip
+//! through the grid moves a point by a few millimeters. Use [`OsgbGridRef::from_osgb36`] and
+//! [`OsgbGridRef::to_osgb36`] to work with OSGB36 latitudes and longitudes
+//! directly, without the datum shift.
+//! 
+//! # Usage
+//! 
+//! ```
+//! use geoconvert::{LatLon, osgb::OsgbGridRef};
+//! 
+//! let grid_ref = OsgbGridRef::parse_str("TQ 30 80").unwrap();
+//! assert_eq!(grid_ref.easting(), 530_000.0);
+//! assert_eq!(grid_ref.northing(), 180_000.0);
+//! 
+//! let coord = grid_ref.to_latlon();
+//! assert!((coord.latitude() - 51.504).abs() < 1e-3);
+//! assert!((coord.longitude() - -0.128).abs() < 1e-3);
+//! 
+//! // Nelson's Column, Trafalgar Square
+//! let coord = LatLon::create(51.508039, -0.128069).unwrap();
+//! let grid_ref = OsgbGridRef::from_latlon(&coord, 4).unwrap();
+//! assert_eq!(grid_ref.to_string(), "TQ 3000 8045");
+//! ```
+
+use alloc::{format, vec::Vec};
+use core::{fmt::Display, str::FromStr};
+
+#[cfg(not(feature = "std"))]
+use num::traits::Float;
+
+use crate::{Error, Ellipsoid, LatLon, ThisOrThat, constants::OSGB_K0, projections::transverse_mercator::TransverseMercator};
+
+/// Latitude of the true origin in degrees
+const LAT0: f64 = 49.0;
+/// Longitude of the true origin (the central meridian) in degrees
+const LON0: f64 = -2.0;
+/// Easting of the true origin in meters
+const E0: f64 = 400_000.0;
+/// Northing of the true origin in meters
+const N0: f64 = -100_000.0;
+
+/// Size of a 100 km grid square in meters
+const TILE: i32 = 100_000;
+/// Size of a 500 km grid square in meters
+const LARGE_TILE: i32 = 5 * TILE;
+/// Extent of the grid in meters
+const MAX_EASTING: i32 = 7 * TILE;
+const MAX_NORTHING: i32 = 13 * TILE;
+
+/// Maximum number of digits in each of the easting and northing (1 m)
+const MAX_PRECISION: i32 = 5;
+const BASE: i32 = 10;
+
+/// Letters labelling the squares of a 5x5 grid, row by row from the north west
+const LETTERS: &[u8; 25] = b"ABCDEFGHJKLMNOPQRSTUVWXYZ";
+
+/// The Ordnance Survey's Helmert transformation from WGS84 to OSGB36 (translation
+/// in meters, scale in ppm, rotations in arcseconds). The reverse uses the
+/// negated parameters.
+const HELMERT: Helmert = Helmert {
+    tx: -446.448,
+    ty: 125.157,
+    tz: -542.060,
+    s: 20.4894,
+    rx: -0.1502,
+    ry: -0.2470,
+    rz: -0.8421,
+};
+
+struct Helmert {
+    tx: f64,
+    ty: f64,
+    tz: f64,
+    s: f64,
+    rx: f64,
+    ry: f64,
+    rz: f64,
+}
+
+impl Helmert {
+    /// Shifts `value` from the ellipsoid `from` to `to` using this transformation,
+    /// or its inverse if `inverse` is set.
+    fn apply(&self, value: &LatLon, from: Ellipsoid, to: Ellipsoid, inverse: bool) -> LatLon {
+        let sign = inverse.ternary(-1.0, 1.0);
+
+        let (tx, ty, tz) = (sign * self.tx, sign * self.ty, sign * self.tz);
+        let scale = 1.0 + sign * self.s * 1e-6;
+        let (rx, ry, rz) = (
+            (sign * self.rx / 3600.0).to_radians(),
+            (sign * self.ry / 3600.0).to_radians(),
+            (sign * self.rz / 3600.0).to_radians(),
+        );
+
+        let (x, y, z) = value.to_ecef_with_ellipsoid(0.0, from);
+
+        let (point, _) = LatLon::from_ecef_with_ellipsoid(
+            tx + scale * x - rz * y + ry * z,
+            ty + rz * x + scale * y - rx * z,
+            tz - ry * x + rx * y + scale * z,
+            to,
+        );
+
+        point
+    }
+}
+
+/// A grid reference on the British National Grid, such as `TQ 30 80`.
+/// 
+/// Holds the easting and northing in meters along with the precision used when
+/// formatting it, as the number of digits in each of the easting and northing
+/// (`0` for just the 100 km square, up to `5` for 1 m). Like the Ordnance
+/// Survey, a formatted grid reference identifies the south west corner of its
+/// grid square.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OsgbGridRef {
+    pub(crate) easting: f64,
+    pub(crate) northing: f64,
+    pub(crate) precision: i32,
+}
+
+impl OsgbGridRef {
+    /// Tries to create a grid reference from its easting and northing in meters
+    /// and its precision.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidOsgb`] if the easting is outside `[0, 700,000)`, the
+    /// northing is outside `[0, 1,300,000)` or the precision is outside `[0, 5]`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::osgb::OsgbGridRef;
+    /// 
+    /// let grid_ref = OsgbGridRef::create(651409.903, 313177.270, 4).unwrap();
+    /// assert_eq!(grid_ref.to_string(), "TG 5140 1317");
+    /// 
+    /// assert!(OsgbGridRef::create(-1.0, 313177.270, 4).is_err());
+    /// assert!(OsgbGridRef::create(651409.903, 1_300_000.0, 4).is_err());
+    /// assert!(OsgbGridRef::create(651409.903, 313177.270, 6).is_err());
+    /// ```
+    pub fn create(easting: f64, northing: f64, precision: i32) -> Result<OsgbGridRef, Error> {
+        if !(0_f64..f64::from(MAX_EASTING)).contains(&easting) {
+            Err(Error::InvalidOsgb(format!("Easting {easting} outside of valid range [0, {MAX_EASTING}).")))
+        } else if !(0_f64..f64::from(MAX_NORTHING)).contains(&northing) {
+            Err(Error::InvalidOsgb(format!("Northing {northing} outside of valid range [0, {MAX_NORTHING}).")))
+        } else if !(0..=MAX_PRECISION).contains(&precision) {
+            Err(Error::InvalidOsgb(format!("Precision {precision} outside of valid range [0, {MAX_PRECISION}].")))
+        } else {
+            Ok(OsgbGridRef { easting, northing, precision })
+        }
+    }
+
+    /// Parses a grid reference made of the two letters of its 100 km square
+    /// followed by an even number of digits (up to 10), split evenly between
+    /// the easting and northing, such as:
+    /// 
+    /// * `TQ 30 80`
+    /// * `TQ3080`
+    /// * `tg 51409 13177`
+    /// * `SV`
+    /// 
+    /// The point is placed at the south west corner of the grid square.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`Error::InvalidOsgb`]: the string couldn't be parsed to a valid grid reference.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::osgb::OsgbGridRef;
+    /// 
+    /// let grid_ref = OsgbGridRef::parse_str("TG 51409 13177").unwrap();
+    /// assert_eq!(grid_ref.easting(), 651409.0);
+    /// assert_eq!(grid_ref.northing(), 313177.0);
+    /// assert_eq!(grid_ref.precision(), 5);
+    /// 
+    /// assert!(OsgbGridRef::parse_str("TG 5140 131").is_err());
+    /// // No such square on the grid
+    /// assert!(OsgbGridRef::parse_str("AA 51409 13177").is_err());
+    /// ```
+    pub fn parse_str(s: &str) -> Result<OsgbGridRef, Error> {
+        // If the easting and northing are separate groups they must be the same length
+        let mut groups = s.split_whitespace().rev();
+        if let (Some(last), Some(second_last)) = (groups.next(), groups.next()) {
+            let is_digits = |group: &str| group.bytes().all(|c| c.is_ascii_digit());
+            if is_digits(last) && is_digits(second_last) && last.len() != second_last.len() {
+                return Err(Error::InvalidOsgb(format!("Easting and northing have different numbers of digits in {s}")));
+            }
+        }
+
+        let chars: Vec<u8> = s.bytes().filter(|c| !c.is_ascii_whitespace()).collect();
+
+        if chars.len() < 2 {
+            return Err(Error::InvalidOsgb(format!("Missing 100 km square in {s}")));
+        }
+
+        let square_index = |c: u8| {
+            LETTERS
+                .iter()
+                .position(|&letter| letter == c.to_ascii_uppercase())
+                .map(|index| index as i32)
+                .ok_or_else(|| Error::InvalidOsgb(format!("Invalid grid letter {} in {s}", c as char)))
+        };
+
+        let large = square_index(chars[0])?;
+        let small = square_index(chars[1])?;
+
+        // The 500 km squares are labelled relative to S, the square at the false origin
+        let easting = (large % 5 - 2) * LARGE_TILE + (small % 5) * TILE;
+        let northing = (3 - large / 5) * LARGE_TILE + (4 - small / 5) * TILE;
+
+        let digits = &chars[2..];
+
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return Err(Error::InvalidOsgb(format!("Non-digit characters after the grid letters in {s}")));
+        }
+        if !digits.len().is_multiple_of(2) {
+            return Err(Error::InvalidOsgb(format!("Odd number of digits in {s}")));
+        }
+
+        let precision = (digits.len() / 2) as i32;
+        if precision > MAX_PRECISION {
+            return Err(Error::InvalidOsgb(format!("More than {MAX_PRECISION} digits each of easting and northing in {s}")));
+        }
+
+        #[allow(clippy::cast_sign_loss)]
+        let unit = BASE.pow((MAX_PRECISION - precision) as u32);
+        let value = |digits: &[u8]| digits.iter().fold(0, |acc, c| acc * BASE + i32::from(c - b'0')) * unit;
+
+        let (easting_digits, northing_digits) = digits.split_at(digits.len() / 2);
+
+        Self::create(
+            f64::from(easting + value(easting_digits)),
+            f64::from(northing + value(northing_digits)),
+            precision,
+        )
+        .map_err(|_| Error::InvalidOsgb(format!("Grid reference {s} is outside the grid")))
+    }
+
+    /// Returns the easting in meters.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::osgb::OsgbGridRef;
+    /// 
+    /// let grid_ref = OsgbGridRef::create(651409.903, 313177.270, 4).unwrap();
+    /// assert_eq!(grid_ref.easting(), 651409.903);
+    /// ```
+    #[inline]
+    pub fn easting(&self) -> f64 {
+        self.easting
+    }
+
+    /// Returns the northing in meters.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::osgb::OsgbGridRef;
+    /// 
+    /// let grid_ref = OsgbGridRef::create(651409.903, 313177.270, 4).unwrap();
+    /// assert_eq!(grid_ref.northing(), 313177.270);
+    /// ```
+    #[inline]
+    pub fn northing(&self) -> f64 {
+        self.northing
+    }
+
+    /// Returns the precision, as the number of digits in each of the easting
+    /// and northing.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::osgb::OsgbGridRef;
+    /// 
+    /// let grid_ref = OsgbGridRef::create(651409.903, 313177.270, 4).unwrap();
+    /// assert_eq!(grid_ref.precision(), 4);
+    /// ```
+    #[inline]
+    pub fn precision(&self) -> i32 {
+        self.precision
+    }
+
+    /// Converts from [`LatLon`] (WGS84) to a grid reference with the given
+    /// precision, shifting to the OSGB36 datum with the Helmert transformation.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidOsgb`] if the point is outside the grid or the
+    /// precision is outside `[0, 5]`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, osgb::OsgbGridRef};
+    /// 
+    /// // Ben Nevis
+    /// let coord = LatLon::create(56.796891, -5.003675).unwrap();
+    /// let grid_ref = OsgbGridRef::from_latlon(&coord, 3).unwrap();
+    /// assert_eq!(grid_ref.to_string(), "NN 166 712");
+    /// 
+    /// // Paris is off the grid
+    /// assert!(OsgbGridRef::from_latlon(&LatLon::create(48.8566, 2.3522).unwrap(), 3).is_err());
+    /// ```
+    pub fn from_latlon(value: &LatLon, precision: i32) -> Result<OsgbGridRef, Error> {
+        let osgb36 = HELMERT.apply(value, Ellipsoid::WGS84, Ellipsoid::AIRY1830, false);
+
+        Self::from_osgb36(&osgb36, precision)
+    }
+
+    /// Converts to [`LatLon`] (WGS84), shifting from the OSGB36 datum with the
+    /// Helmert transformation.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, osgb::OsgbGridRef};
+    /// 
+    /// let coord = LatLon::create(56.796891, -5.003675).unwrap();
+    /// let grid_ref = OsgbGridRef::from_latlon(&coord, 5).unwrap();
+    /// 
+    /// // Within a few millimeters
+    /// assert!(grid_ref.to_latlon().haversine(&coord) < 0.01);
+    /// ```
+    pub fn to_latlon(&self) -> LatLon {
+        HELMERT.apply(&self.to_osgb36(), Ellipsoid::AIRY1830, Ellipsoid::WGS84, true)
+    }
+
+    /// Converts from a latitude and longitude on the OSGB36 datum (rather than
+    /// WGS84) to a grid reference with the given precision.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidOsgb`] if the point is outside the grid or the
+    /// precision is outside `[0, 5]`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, osgb::OsgbGridRef};
+    /// 
+    /// // The worked example from the Ordnance Survey's "A guide to coordinate
+    /// // systems in Great Britain"
+    /// let coord = LatLon::create(52.657570306, 1.717921583).unwrap();
+    /// let grid_ref = OsgbGridRef::from_osgb36(&coord, 5).unwrap();
+    /// 
+    /// assert!((grid_ref.easting() - 651409.903).abs() < 1e-3);
+    /// assert!((grid_ref.northing() - 313177.270).abs() < 1e-3);
+    /// assert_eq!(grid_ref.to_string(), "TG 51409 13177");
+    /// ```
+    pub fn from_osgb36(value: &LatLon, precision: i32) -> Result<OsgbGridRef, Error> {
+        let tm = projection();
+
+        let (x, y) = tm.from_latlon(LON0, value.latitude, value.longitude);
+        let (_, y0) = tm.from_latlon(LON0, LAT0, LON0);
+
+        Self::create(x + E0, y - y0 + N0, precision)
+    }
+
+    /// Converts to a latitude and longitude on the OSGB36 datum (rather than
+    /// WGS84).
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, osgb::OsgbGridRef};
+    /// 
+    /// let grid_ref = OsgbGridRef::create(651409.903, 313177.270, 5).unwrap();
+    /// let coord = grid_ref.to_osgb36();
+    /// 
+    /// assert!(coord.approx_eq(&LatLon::create(52.657570306, 1.717921583).unwrap(), 1e-8));
+    /// ```
+    pub fn to_osgb36(&self) -> LatLon {
+        let tm = projection();
+
+        let (_, y0) = tm.from_latlon(LON0, LAT0, LON0);
+
+        tm.to_latlon(LON0, self.easting - E0, self.northing - N0 + y0)
+    }
+}
+
+/// The transverse Mercator projection of the National Grid
+fn projection() -> TransverseMercator {
+    let ellipsoid = Ellipsoid::AIRY1830;
+
+    TransverseMercator::with_params(ellipsoid.a, ellipsoid.f, OSGB_K0)
+}
+
+impl FromStr for OsgbGridRef {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(s)
+    }
+}
+
+/// Formats the grid reference as its 100 km square followed by the easting and
+/// northing truncated to the precision, separated by spaces (e.g. `TQ 30 80`).
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::osgb::OsgbGridRef;
+/// 
+/// let grid_ref = OsgbGridRef::create(530_123.0, 180_987.0, 2).unwrap();
+/// assert_eq!(grid_ref.to_string(), "TQ 30 80");
+/// 
+/// let grid_ref = OsgbGridRef::create(530_123.0, 180_987.0, 0).unwrap();
+/// assert_eq!(grid_ref.to_string(), "TQ");
+/// ```
+impl Display for OsgbGridRef {
+    #[allow(clippy::cast_sign_loss)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Whole meters, which is the finest precision
+        let easting = self.easting.floor() as i32;
+        let northing = self.northing.floor() as i32;
+
+        // Offset from the 500 km square S, at the false origin, to the north
+        // west corner of the grid of 500 km squares
+        let large_col = easting / LARGE_TILE + 2;
+        let large_row = 3 - northing / LARGE_TILE;
+        let small_col = easting % LARGE_TILE / TILE;
+        let small_row = 4 - northing % LARGE_TILE / TILE;
+
+        let large = LETTERS[(large_row * 5 + large_col) as usize] as char;
+        let small = LETTERS[(small_row * 5 + small_col) as usize] as char;
+
+        write!(f, "{large}{small}")?;
+
+        if self.precision > 0 {
+            #[allow(clippy::cast_sign_loss)]
+            let unit = BASE.pow((MAX_PRECISION - self.precision) as u32);
+            let width = self.precision as usize;
+
+            write!(
+                f,
+                " {:0width$} {:0width$}",
+                easting % TILE / unit,
+                northing % TILE / unit,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,39 +1,60 @@
-use crate::{ThisOrThat, constants::{WGS84_A, WGS84_F, UPS_K0}, utility::{GeoMath, dms}, latlon::LatLon};
+#[cfg(not(feature = "std"))]
+use num::traits::Float;
 
-const F: f64 = WGS84_F;
-const E2: f64 = F * (2.0 - F);
+use crate::{ThisOrThat, constants::UPS_K0, utility::{GeoMath, dms}, latlon::LatLon, ellipsoid::Ellipsoid};
 
 pub(crate) struct PolarStereographic {
     a: f64,
     k0: f64,
+    e2: f64,
     es: f64,
+    e2m: f64,
     c: f64,
 }
 
 impl PolarStereographic {
-    pub fn ups() -> PolarStereographic {
-
-        let es = (F < 0.0).ternary(-1.0, 1.0) * E2.abs().sqrt();
-        let c = (1.0 - F) * 1_f64.eatanhe(es).exp();
+    pub fn ups(ellipsoid: Ellipsoid) -> PolarStereographic {
+        let e2 = ellipsoid.e2();
+        let es = ellipsoid.es();
+        let c = (1.0 - ellipsoid.f) * 1_f64.eatanhe(es).exp();
 
         Self {
-            a: WGS84_A,
+            a: ellipsoid.a,
             k0: UPS_K0,
+            e2,
             es,
+            e2m: 1.0 - e2,
             c,
         }
     }
 
     #[allow(clippy::wrong_self_convention)]
     pub fn from_latlon(&self, northp: bool, lat: f64, lon: f64) -> (f64, f64) {
+        let (x, y, _, _) = self.from_latlon_full(northp, lat, lon);
+
+        (x, y)
+    }
+
+    /// Same as [`from_latlon`](Self::from_latlon), but also returns the meridian
+    /// convergence `gamma` (in degrees) and the point scale `k` as `(x, y, gamma, k)`.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_latlon_full(&self, northp: bool, lat: f64, lon: f64) -> (f64, f64, f64, f64) {
         let lat = lat * northp.ternary(1.0, -1.0);
 
         let tau = lat.to_radians().tan();
+        let secphi = 1_f64.hypot(tau);
         let taup = tau.taupf(self.es);
         let mut rho = 1_f64.hypot(taup) + taup.abs();
-        rho = (taup >= 0.0).ternary_lazy(|| (!lat.eps_eq(f64::from(dms::QD))).ternary_lazy(|| 1.0 / rho, || 0.0), || rho);
+        let is_pole = lat.eps_eq(f64::from(dms::QD));
+        rho = (taup >= 0.0).ternary_lazy(|| (!is_pole).ternary_lazy(|| 1.0 / rho, || 0.0), || rho);
         rho *= 2.0 * self.k0 * self.a / self.c;
 
+        let k = is_pole.ternary_lazy(
+            || self.k0,
+            || (rho / self.a) * secphi * (self.e2m + self.e2 / secphi.powi(2)).sqrt(),
+        );
+        let gamma = northp.ternary(lon, -lon).ang_normalize();
+
         let (mut x, mut y) = {
             let (x, y) = lon.to_radians().sin_cos();
             (x, y)
@@ -42,7 +63,7 @@ impl PolarStereographic {
         x *= rho;
         y *= northp.ternary(-rho, rho);
 
-        (x, y)
+        (x, y, gamma, k)
     }
 
     pub fn to_latlon(&self, northp: bool, x: f64, y: f64) -> LatLon {
@@ -56,7 +77,9 @@ impl PolarStereographic {
         let tau = taup.tauf(self.es);
 
         let lat = northp.ternary(1.0, -1.0) * tau.atan().to_degrees();
-        let lon = x.atan2(northp.ternary(-y, y)).to_degrees();
+        // Longitude is undefined at the pole itself, so use 0 rather than
+        // whatever atan2 gives for the sign of the zero
+        let lon = (rho != 0.0).ternary_lazy(|| x.atan2(northp.ternary(-y, y)).to_degrees(), || 0.0);
 
         LatLon {
             latitude: lat,
@@ -1,8 +1,11 @@
-use std::f64::consts::PI;
+use core::f64::consts::PI;
+
+#[cfg(not(feature = "std"))]
+use num::traits::Float;
 
 use num::{Complex, Integer};
 
-use crate::{latlon::LatLon, utility::{polyval, GeoMath, dms}, ThisOrThat, constants::{WGS84_A, WGS84_F, UTM_K0}};
+use crate::{latlon::LatLon, utility::{polyval, GeoMath, dms}, ThisOrThat, constants::UTM_K0, ellipsoid::Ellipsoid};
 
 // ================================
 // Transverse Mercator Constants
@@ -48,48 +51,66 @@ const BET_COEFF: [f64; 27] = [
 
 const MAXPOW: usize = 6;
 
-const A: f64 = WGS84_A;
-const F: f64 = WGS84_F;
 const M: usize = MAXPOW / 2;
-const N: f64 = F / (2.0 - F);
-const E2: f64 = F * (2.0 - F);
 
+#[derive(Clone, Debug)]
 pub(crate) struct TransverseMercator {
     k0: f64,
+    e2: f64,
     es: f64,
+    e2m: f64,
+    c: f64,
+    b1: f64,
     a1: f64,
     alp: [f64; MAXPOW + 1],
     bet: [f64; MAXPOW + 1],
 }
 
 impl TransverseMercator {
-    pub fn utm() -> TransverseMercator {
-
-        let es = (F < 0.0).ternary(-1.0, 1.0) * E2.abs().sqrt();
+    /// The projection used by UTM, with a central scale factor of `0.9996`.
+    pub fn utm(ellipsoid: Ellipsoid) -> TransverseMercator {
+        Self::with_params(ellipsoid.a, ellipsoid.f, UTM_K0)
+    }
 
-        let b1 = polyval(&B1_COEFF[0..=M], N.powi(2)) / (B1_COEFF[M + 1] * (1.0 + N));
+    /// A transverse Mercator projection on the ellipsoid with equatorial radius
+    /// `a` and flattening `f`, with central scale factor `k0`.
+    #[allow(clippy::many_single_char_names)]
+    pub fn with_params(a: f64, f: f64, k0: f64) -> TransverseMercator {
+        let ellipsoid = Ellipsoid { a, f };
+        let n = ellipsoid.n();
+        let e2 = ellipsoid.e2();
+        let es = ellipsoid.es();
+        let e2m = 1.0 - e2;
+        // Scale at the pole
+        let c = e2m.sqrt() * 1_f64.eatanhe(es).exp();
+
+        let b1 = polyval(&B1_COEFF[0..=M], n.powi(2)) / (B1_COEFF[M + 1] * (1.0 + n));
         // a1 is the equivalent radius for computing the circumference of
         // ellipse.
-        let a1 = b1 * A;
+        let a1 = b1 * ellipsoid.a;
 
         let mut alp = [0_f64; MAXPOW + 1];
         let mut bet = [0_f64; MAXPOW + 1];
 
         let mut o = 0;
-        let mut d = N;
+        let mut d = n;
         let mut m;
 
         for l in 1..=MAXPOW {
             m = MAXPOW - l;
-            alp[l] = d * polyval(&ALP_COEFF[o..=o+m], N) / ALP_COEFF[o + m + 1];
-            bet[l] = d * polyval(&BET_COEFF[o..=o+m], N) / BET_COEFF[o + m + 1];
+            alp[l] = d * polyval(&ALP_COEFF[o..=o+m], n) / ALP_COEFF[o + m + 1];
+            bet[l] = d * polyval(&BET_COEFF[o..=o+m], n) / BET_COEFF[o + m + 1];
             o += m + 2;
-            d *= N;
+            d *= n;
         }
 
         Self {
-            k0: UTM_K0,
+            k0,
+            e2,
             es,
+            e2m,
+            c,
+            b1,
             a1,
             alp,
             bet,
@@ -97,8 +118,18 @@ impl TransverseMercator {
     }
 
     #[allow(clippy::wrong_self_convention)]
-    #[allow(clippy::similar_names)]
     pub fn from_latlon(&self, lon0: f64, lat: f64, lon: f64) -> (f64, f64) {
+        let (x, y, _, _) = self.from_latlon_full(lon0, lat, lon);
+
+        (x, y)
+    }
+
+    /// Same as [`from_latlon`](Self::from_latlon), but also returns the meridian
+    /// convergence `gamma` (in degrees) and the point scale `k` as `(x, y, gamma, k)`.
+    #[allow(clippy::wrong_self_convention)]
+    #[allow(clippy::many_single_char_names)]
+    #[allow(clippy::similar_names)]
+    pub fn from_latlon_full(&self, lon0: f64, lat: f64, lon: f64) -> (f64, f64, f64, f64) {
         let mut lat = lat;
         let mut lon = lon0.ang_diff(lon);
 
@@ -122,15 +153,17 @@ impl TransverseMercator {
         let (lamda_sin, lambda_cos) = lon.to_radians().sin_cos();
 
         // Check if lat == QD
-        let (etap, xip) = if lat.eps_eq(f64::from(dms::QD)) {
-            (0.0, PI / 2.0)
+        let (etap, xip, mut gamma, mut k) = if lat.eps_eq(f64::from(dms::QD)) {
+            (0.0, PI / 2.0, lon, self.c)
         } else {
             let tau = phi_sin / phi_cos;
             let taup = tau.taupf(self.es);
             let xip = taup.atan2(lambda_cos);
             let etap = (lamda_sin / taup.hypot(lambda_cos)).asinh();
+            let gamma = (lamda_sin * taup).atan2(lambda_cos * 1_f64.hypot(taup)).to_degrees();
+            let k = (self.e2m + self.e2 * phi_cos.powi(2)).sqrt() * 1_f64.hypot(tau) / taup.hypot(lambda_cos);
 
-            (etap, xip)
+            (etap, xip, gamma, k)
         };
 
         let c0 = (2.0 * xip).cos();
@@ -143,6 +176,9 @@ impl TransverseMercator {
 
         let mut y0 = Complex::new(n.is_odd().ternary_lazy( ||self.alp[n], || 0.0), 0.0);
         let mut y1 = Complex::default();
+        // Derivative of the series, used for gamma and k
+        let mut z0 = Complex::new(n.is_odd().ternary_lazy(|| 2.0 * n as f64 * self.alp[n], || 0.0), 0.0);
+        let mut z1 = Complex::default();
 
         if n.is_odd() {
             n -= 1;
@@ -150,21 +186,33 @@ impl TransverseMercator {
 
         while n > 0 {
             y1 = a * y0 - y1 + self.alp[n];
+            z1 = a * z0 - z1 + 2.0 * n as f64 * self.alp[n];
             n -= 1;
             y0 = a * y1 - y0 + self.alp[n];
+            z0 = a * z1 - z0 + 2.0 * n as f64 * self.alp[n];
             n -= 1;
         }
 
         a /= 2.0;
+        z1 = 1.0 - z1 + a * z0;
         a = Complex::new(s0 * ch0, c0 * sh0);
         y1 = Complex::new(xip, etap) + a * y0;
 
+        gamma -= z1.im.atan2(z1.re).to_degrees();
+        k *= self.b1 * z1.norm();
+
         let xi = y1.re;
         let eta = y1.im;
         let y = self.a1 * self.k0 * backside.ternary_lazy(|| PI - xi, || xi) * lat_sign;
         let x = self.a1 * self.k0 * eta * lon_sign;
 
-        (x, y)
+        if backside {
+            gamma = f64::from(dms::HD) - gamma;
+        }
+        gamma = (gamma * lat_sign * lon_sign).ang_normalize();
+        k *= self.k0;
+
+        (x, y, gamma, k)
     }
 
     #[allow(clippy::many_single_char_names)]
@@ -0,0 +1,32 @@
+//! The WGS84 UTM and UPS projections, which nearly every conversion uses.
+//! 
+//! Setting up the transverse Mercator projection computes its series
+//! coefficients, a noticeable part of the cost of converting a single point
+//! (see the `projection_setup` benchmark). With the `std` feature both
+//! projections are set up on first use and shared after that; without it
+//! they're set up on every call.
+
+#[cfg(feature = "std")]
+use std::sync::LazyLock;
+
+use crate::ellipsoid::Ellipsoid;
+
+use super::{polar_stereographic::PolarStereographic, transverse_mercator::TransverseMercator};
+
+#[cfg(feature = "std")]
+static UTM: LazyLock<TransverseMercator> = LazyLock::new(|| TransverseMercator::utm(Ellipsoid::WGS84));
+
+#[cfg(feature = "std")]
+static UPS: LazyLock<PolarStereographic> = LazyLock::new(|| PolarStereographic::ups(Ellipsoid::WGS84));
+
+/// Calls `f` with the WGS84 UTM and UPS projections.
+#[cfg(feature = "std")]
+pub(crate) fn with_projections<T>(f: impl FnOnce(&TransverseMercator, &PolarStereographic) -> T) -> T {
+    f(&UTM, &UPS)
+}
+
+/// Calls `f` with the WGS84 UTM and UPS projections.
+#[cfg(not(feature = "std"))]
+pub(crate) fn with_projections<T>(f: impl FnOnce(&TransverseMercator, &PolarStereographic) -> T) -> T {
+    f(&TransverseMercator::utm(Ellipsoid::WGS84), &PolarStereographic::ups(Ellipsoid::WGS84))
+}
@@ -0,0 +1,314 @@
+//! Reading coordinates from CSV files, enabled by the `csv` feature.
+//! 
+//! [`CoordReader`] wraps a [`csv::Reader`] and parses each row into a
+//! coordinate, reading the columns given by index or by header name. Each row
+//! is parsed on its own, so a bad row gives an [`Err`] for that row and reading
+//! continues with the next one.
+//! 
+//! # Usage
+//! 
+//! ```
+//! use geoconvert::{LatLon, reader::CoordReader};
+//! 
+//! let data = "\
+//! name,lat,lon
+//! Empire State Building,40.748333,-73.985278
+//! Nowhere,100.0,0.0
+//! Sydney Opera House,-33.8568,151.2153
+//! ";
+//! 
+//! let reader = csv::Reader::from_reader(data.as_bytes());
+//! let coords: Vec<_> = CoordReader::latlon(reader, "lat", "lon").unwrap().collect();
+//! 
+//! assert_eq!(coords.len(), 3);
+//! assert_eq!(coords[0].as_ref().unwrap(), &LatLon::create(40.748333, -73.985278).unwrap());
+//! assert!(coords[1].is_err());
+//! assert!(coords[2].is_ok());
+//! ```
+
+use std::io;
+
+use crate::{Error, LatLon, Mgrs, UtmUps};
+
+/// A column of a CSV file, either by its index (starting at `0`) or by its
+/// name in the header row.
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::reader::Column;
+/// 
+/// assert_eq!(Column::from(1), Column::Index(1));
+/// assert_eq!(Column::from("lat"), Column::Name("lat".to_string()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Column {
+    Index(usize),
+    Name(String),
+}
+
+impl From<usize> for Column {
+    fn from(value: usize) -> Self {
+        Column::Index(value)
+    }
+}
+
+impl From<&str> for Column {
+    fn from(value: &str) -> Self {
+        Column::Name(value.to_string())
+    }
+}
+
+impl From<String> for Column {
+    fn from(value: String) -> Self {
+        Column::Name(value)
+    }
+}
+
+/// Reads coordinates from the rows of a [`csv::Reader`].
+/// 
+/// Create one with [`latlon`](CoordReader::latlon)/[`lonlat`](CoordReader::lonlat)
+/// for latitude and longitude in separate columns, or with
+/// [`utmups`](CoordReader::utmups)/[`mgrs`](CoordReader::mgrs) for a column of
+/// UTM/UPS or MGRS strings. Then iterate over it to get one
+/// `Result<T, Error>` per row.
+/// 
+/// Errors reading the CSV itself are returned as [`Error::Csv`], and rows
+/// missing a column as [`Error::MissingColumn`]. Other errors are from parsing
+/// the coordinate. [`line`](CoordReader::line) gives the line of the last row
+/// read, for reporting errors.
+/// 
+/// # Usage
+/// 
+/// ```
+/// use geoconvert::{Mgrs, reader::CoordReader};
+/// 
+/// let data = "18TWL8566411315\n18TWL856113\n";
+/// let reader = csv::ReaderBuilder::new()
+///     .has_headers(false)
+///     .from_reader(data.as_bytes());
+/// 
+/// let mut coords = CoordReader::mgrs(reader, 0).unwrap();
+/// 
+/// assert_eq!(coords.next().unwrap().unwrap().to_string(), "18TWL8566411315");
+/// assert_eq!(coords.line(), 1);
+/// assert_eq!(coords.next().unwrap().unwrap().to_string(), "18TWL856113");
+/// assert!(coords.next().is_none());
+/// ```
+pub struct CoordReader<R, T> {
+    reader: csv::Reader<R>,
+    columns: Vec<usize>,
+    record: csv::StringRecord,
+    parse: fn(&[&str]) -> Result<T, Error>,
+}
+
+impl<R: io::Read> CoordReader<R, LatLon> {
+    /// Reads [`LatLon`] coordinates from the `lat` and `lon` columns, given
+    /// in decimal degrees.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`Error::MissingColumn`]: a column is given by name, and the header row
+    ///   doesn't contain it or the reader has no header row.
+    /// * [`Error::Csv`]: the header row couldn't be read.
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, reader::CoordReader};
+    /// 
+    /// let data = "40.748333,-73.985278\n";
+    /// let reader = csv::ReaderBuilder::new()
+    ///     .has_headers(false)
+    ///     .from_reader(data.as_bytes());
+    /// 
+    /// let coord = CoordReader::latlon(reader, 0, 1).unwrap().next().unwrap().unwrap();
+    /// assert_eq!(coord, LatLon::create(40.748333, -73.985278).unwrap());
+    /// 
+    /// let reader = csv::Reader::from_reader(data.as_bytes());
+    /// assert!(CoordReader::latlon(reader, "lat", "lon").is_err());
+    /// ```
+    pub fn latlon(reader: csv::Reader<R>, lat: impl Into<Column>, lon: impl Into<Column>) -> Result<Self, Error> {
+        Self::create(reader, &[lat.into(), lon.into()], |fields| {
+            LatLon::create(parse_degrees(fields[0])?, parse_degrees(fields[1])?)
+        })
+    }
+
+    /// Reads [`LatLon`] coordinates like [`latlon`](CoordReader::latlon), with
+    /// the columns given longitude first, e.g. for files in `x, y` order.
+    /// 
+    /// # Errors
+    /// 
+    /// See [`latlon`](CoordReader::latlon).
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{LatLon, reader::CoordReader};
+    /// 
+    /// let data = "-73.985278,40.748333\n";
+    /// let reader = csv::ReaderBuilder::new()
+    ///     .has_headers(false)
+    ///     .from_reader(data.as_bytes());
+    /// 
+    /// let coord = CoordReader::lonlat(reader, 0, 1).unwrap().next().unwrap().unwrap();
+    /// assert_eq!(coord, LatLon::create(40.748333, -73.985278).unwrap());
+    /// ```
+    pub fn lonlat(reader: csv::Reader<R>, lon: impl Into<Column>, lat: impl Into<Column>) -> Result<Self, Error> {
+        Self::latlon(reader, lat, lon)
+    }
+}
+
+impl<R: io::Read> CoordReader<R, UtmUps> {
+    /// Reads [`UtmUps`] coordinates from a column of strings in the format
+    /// parsed by its [`FromStr`](core::str::FromStr) implementation, e.g.
+    /// `18n 585664 4511315`.
+    /// 
+    /// # Errors
+    /// 
+    /// See [`latlon`](CoordReader::latlon).
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{UtmUps, reader::CoordReader};
+    /// 
+    /// let data = "id,utm\n1,18n 585664 4511315\n";
+    /// let reader = csv::Reader::from_reader(data.as_bytes());
+    /// 
+    /// let coord = CoordReader::utmups(reader, "utm").unwrap().next().unwrap().unwrap();
+    /// assert_eq!(coord, UtmUps::create(18, true, 585664.0, 4511315.0).unwrap());
+    /// ```
+    pub fn utmups(reader: csv::Reader<R>, column: impl Into<Column>) -> Result<Self, Error> {
+        Self::create(reader, &[column.into()], |fields| fields[0].trim().parse())
+    }
+}
+
+impl<R: io::Read> CoordReader<R, Mgrs> {
+    /// Reads [`Mgrs`] coordinates from a column of MGRS strings, parsed with
+    /// [`Mgrs::parse_str`].
+    /// 
+    /// # Errors
+    /// 
+    /// See [`latlon`](CoordReader::latlon).
+    /// 
+    /// # Usage
+    /// 
+    /// ```
+    /// use geoconvert::{Mgrs, reader::CoordReader};
+    /// 
+    /// let data = "id,mgrs\n1,18TWL8566411315\n2,not mgrs\n";
+    /// let reader = csv::Reader::from_reader(data.as_bytes());
+    /// 
+    /// let coords: Vec<_> = CoordReader::mgrs(reader, "mgrs").unwrap().collect();
+    /// assert_eq!(coords[0].as_ref().unwrap(), &Mgrs::parse_str("18TWL8566411315").unwrap());
+    /// assert!(coords[1].is_err());
+    /// ```
+    pub fn mgrs(reader: csv::Reader<R>, column: impl Into<Column>) -> Result<Self, Error> {
+        Self::create(reader, &[column.into()], |fields| Mgrs::parse_str(fields[0].trim()))
+    }
+}
+
+impl<R: io::Read, T> CoordReader<R, T> {
+    fn create(mut reader: csv::Reader<R>, columns: &[Column], parse: fn(&[&str]) -> Result<T, Error>) -> Result<Self, Error> {
+        let columns = columns
+            .iter()
+            .map(|column| match column {
+                Column::Index(index) => Ok(*index),
+                Column::Name(name) => {
+                    if !reader.has_headers() {
+                        return Err(Error::MissingColumn(format!("Column \"{name}\" given by name, but the reader has no header row")));
+                    }
+
+                    reader
+                        .headers()?
+                        .iter()
+                        .position(|header| header.trim() == name)
+                        .ok_or_else(|| Error::MissingColumn(format!("No column named \"{name}\" in the header row")))
+                },
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            reader,
+            columns,
+            record: csv::StringRecord::new(),
+            parse,
+        })
+    }
+
+    /// Returns the line number (starting at `1`) of the last row read, or `0`
+    /// if no row has been read yet.
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::reader::CoordReader;
+    /// 
+    /// let data = "lat,lon\n40.748333,-73.985278\n100.0,0.0\n";
+    /// let reader = csv::Reader::from_reader(data.as_bytes());
+    /// 
+    /// let mut coords = CoordReader::latlon(reader, "lat", "lon").unwrap();
+    /// assert_eq!(coords.line(), 0);
+    /// 
+    /// while let Some(coord) = coords.next() {
+    ///     if coord.is_err() {
+    ///         assert_eq!(coords.line(), 3);
+    ///     }
+    /// }
+    /// ```
+    pub fn line(&self) -> u64 {
+        self.record.position().map_or(0, csv::Position::line)
+    }
+
+    /// Returns the underlying [`csv::Reader`].
+    /// 
+    /// # Example
+    /// ```
+    /// use geoconvert::reader::CoordReader;
+    /// 
+    /// let reader = csv::Reader::from_reader("lat,lon\n".as_bytes());
+    /// let reader = CoordReader::latlon(reader, "lat", "lon").unwrap().into_inner();
+    /// 
+    /// assert!(reader.has_headers());
+    /// ```
+    pub fn into_inner(self) -> csv::Reader<R> {
+        self.reader
+    }
+}
+
+impl<R: io::Read, T> Iterator for CoordReader<R, T> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.reader.read_record(&mut self.record) {
+            Ok(false) => None,
+            Err(err) => Some(Err(err.into())),
+            Ok(true) => {
+                let fields = self.columns
+                    .iter()
+                    .map(|&index| {
+                        self.record
+                            .get(index)
+                            .ok_or_else(|| Error::MissingColumn(format!("Line {} has no column {index}", self.line())))
+                    })
+                    .collect::<Result<Vec<_>, _>>();
+
+                Some(fields.and_then(|fields| (self.parse)(&fields)))
+            },
+        }
+    }
+}
+
+impl From<csv::Error> for Error {
+    fn from(value: csv::Error) -> Self {
+        Error::Csv(Box::new(value))
+    }
+}
+
+/// Parses a field in decimal degrees.
+fn parse_degrees(value: &str) -> Result<f64, Error> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| Error::InvalidCoord(format!("Unable to parse \"{value}\" as degrees")))
+}
@@ -0,0 +1,109 @@
+//! Serializes an [`Mgrs`] as a plain struct of its fields, without
+//! `#[serde(flatten)]`.
+//! 
+//! Intended for use with `#[serde(with = "geoconvert::serde_fields")]` on
+//! [`Mgrs`] fields. The output has the same fields as the derived implementation
+//! (`zone`, `northp`, `easting`, `northing` and `precision`), so the two can read
+//! each other's data in self-describing formats such as JSON.
+//! 
+//! The representations available for [`Mgrs`] support different serde formats:
+//! 
+//! * The derived implementation flattens the inner [`UtmUps`](crate::UtmUps),
+//!   which only works with self-describing formats such as JSON, YAML and TOML.
+//!   It fails with formats that don't support `#[serde(flatten)]`, such as
+//!   `bincode`, `postcard` and CSV.
+//! * This module writes the fields as a regular struct, which works with any
+//!   format, including `bincode`, `postcard` and CSV.
+//! * [`serde_string`](crate::serde_string) writes the string form (e.g.
+//!   `"18TWL856641113154"`), which any format can write. Since it accepts either
+//!   form when reading, reading it back needs a self-describing format.
+//! 
+//! Deserialization validates the fields with [`Mgrs::create`].
+//! 
+//! # Usage
+//! 
+//! ```
+//! use geoconvert::Mgrs;
+//! use serde::{Deserialize, Serialize};
+//! 
+//! #[derive(Serialize, Deserialize)]
+//! struct Waypoint {
+//!     #[serde(with = "geoconvert::serde_fields")]
+//!     position: Mgrs,
+//! }
+//! 
+//! let waypoint = Waypoint {
+//!     position: Mgrs::create(18, true, 585664.0, 4511315.0, 5).unwrap(),
+//! };
+//! 
+//! let json = serde_json::to_string(&waypoint).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"position":{"zone":18,"northp":true,"easting":585664.0,"northing":4511315.0,"precision":5}}"#,
+//! );
+//! 
+//! let parsed: Waypoint = serde_json::from_str(&json).unwrap();
+//! assert_eq!(parsed.position.to_string(), "18TWL8566411315");
+//! 
+//! // The same as the derived implementation
+//! assert_eq!(serde_json::to_string(&waypoint.position).unwrap(), r#"{"zone":18,"northp":true,"easting":585664.0,"northing":4511315.0,"precision":5}"#);
+//! 
+//! // Invalid fields are rejected
+//! let json = r#"{"position":{"zone":61,"northp":true,"easting":585664.0,"northing":4511315.0,"precision":5}}"#;
+//! assert!(serde_json::from_str::<Waypoint>(json).is_err());
+//! 
+//! let json = r#"{"position":{"zone":18,"northp":true,"easting":585664.0,"northing":4511315.0,"precision":50}}"#;
+//! assert!(serde_json::from_str::<Waypoint>(json).is_err());
+//! ```
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Mgrs;
+
+/// The fields of an [`Mgrs`], as a regular struct
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Mgrs")]
+struct Fields {
+    zone: i32,
+    #[serde(alias = "north", alias = "is_north")]
+    northp: bool,
+    easting: f64,
+    northing: f64,
+    precision: i32,
+}
+
+/// Serializes `value` as a struct of its fields.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the serializer fails to write the struct.
+pub fn serialize<S>(value: &Mgrs, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    Fields {
+        zone: value.utm.zone,
+        northp: value.utm.northp,
+        easting: value.utm.easting,
+        northing: value.utm.northing,
+        precision: value.precision,
+    }
+    .serialize(serializer)
+}
+
+/// Deserializes an [`Mgrs`] from a struct of its fields, validated with
+/// [`Mgrs::create`].
+/// 
+/// # Errors
+/// 
+/// Returns an error if the input is not a valid struct, or the fields don't
+/// form a valid MGRS coordinate.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Mgrs, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let fields = Fields::deserialize(deserializer)?;
+
+    Mgrs::create(fields.zone, fields.northp, fields.easting, fields.northing, fields.precision)
+        .map_err(de::Error::custom)
+}
@@ -0,0 +1,83 @@
+//! Serializes a coordinate as its string representation instead of as a struct.
+//!
+//! Intended for use with `#[serde(with = "geoconvert::serde_string")]` on fields
+//! of any type implementing [`Display`] and [`FromStr`], such as [`LatLon`](crate::LatLon)
+//! (`"40.748333 -73.985278"`) and [`Mgrs`](crate::Mgrs) (`"18TWL856641113154"`).
+//!
+//! Deserialization accepts either the string form or the regular struct form,
+//! so existing data serialized with the derived implementations can still be read.
+//!
+//! # Usage
+//!
+//! ```
+//! use geoconvert::{LatLon, Mgrs};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Waypoint {
+//!     #[serde(with = "geoconvert::serde_string")]
+//!     position: Mgrs,
+//!     #[serde(with = "geoconvert::serde_string")]
+//!     origin: LatLon,
+//! }
+//!
+//! let waypoint = Waypoint {
+//!     position: Mgrs::parse_str("18TWL856641113154").unwrap(),
+//!     origin: LatLon::create(40.748333, -73.985278).unwrap(),
+//! };
+//!
+//! let json = serde_json::to_string(&waypoint).unwrap();
+//! assert_eq!(json, r#"{"position":"18TWL856641113154","origin":"40.748333 -73.985278"}"#);
+//!
+//! let parsed: Waypoint = serde_json::from_str(&json).unwrap();
+//! assert_eq!(parsed.position.to_string(), "18TWL856641113154");
+//!
+//! // The struct form is also accepted
+//! let json = r#"{"position":"18TWL856641113154","origin":{"latitude":40.748333,"longitude":-73.985278}}"#;
+//! let parsed: Waypoint = serde_json::from_str(json).unwrap();
+//! assert_eq!(parsed.origin.latitude(), 40.748333);
+//! ```
+
+use alloc::string::String;
+use core::{fmt::Display, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serializer};
+
+/// Either of the accepted forms when deserializing
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Repr<T> {
+    String(String),
+    Struct(T),
+}
+
+/// Serializes `value` using its [`Display`] implementation.
+///
+/// # Errors
+///
+/// Returns an error if the serializer fails to write the string.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Display,
+    S: Serializer,
+{
+    serializer.collect_str(value)
+}
+
+/// Deserializes a value from either its string form (parsed with [`FromStr`])
+/// or its struct form.
+///
+/// # Errors
+///
+/// Returns an error if the input is neither a valid string nor a valid struct.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr + Deserialize<'de>,
+    T::Err: Display,
+    D: Deserializer<'de>,
+{
+    match Repr::<T>::deserialize(deserializer)? {
+        Repr::String(value) => value.parse().map_err(de::Error::custom),
+        Repr::Struct(value) => Ok(value),
+    }
+}
@@ -1,3 +1,6 @@
+#[cfg(not(feature = "std"))]
+use num::traits::Float;
+
 use crate::ThisOrThat;
 
 #[allow(dead_code)]
@@ -14,6 +17,215 @@ pub(crate) mod dms {
     pub const TD: i32 = 2 * HD;
     /// Seconds per degree
     pub const DS: i32 = DM * MS;
+
+    use alloc::{format, string::String};
+
+    #[cfg(not(feature = "std"))]
+    use num::traits::Float;
+
+    use crate::{Error, ThisOrThat};
+
+    /// Which coordinate a hemisphere designator attached to an angle refers to
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Flag {
+        /// No hemisphere designator was given
+        None,
+        /// `N` or `S` was given
+        Latitude,
+        /// `E` or `W` was given
+        Longitude,
+    }
+
+    /// Decode a single angle given either in decimal degrees (`-73.985278`) or in
+    /// degrees, minutes and seconds (`73°59'7"W`, `73d59'7"`, `73 59 7.0`). A
+    /// hemisphere designator (`N`, `S`, `E` or `W`) may lead or trail the value, in
+    /// which case the angle must not also carry a sign.
+    pub fn decode(value: &str) -> Result<(f64, Flag), Error> {
+        let value = value.trim();
+        let invalid = |msg: &str| Error::InvalidCoord(format!("Unable to parse angle \"{value}\": {msg}"));
+
+        // Hemisphere designator, either leading or trailing
+        let (body, hemisphere) = match (value.chars().next(), value.chars().last()) {
+            (_, Some(c)) if "NSEWnsew".contains(c) => (&value[..value.len() - 1], Some(c.to_ascii_uppercase())),
+            (Some(c), _) if "NSEWnsew".contains(c) => (&value[1..], Some(c.to_ascii_uppercase())),
+            _ => (value, None),
+        };
+
+        let body = body.trim();
+        if hemisphere.is_some() && body.starts_with(['-', '+']) {
+            return Err(invalid("sign conflicts with hemisphere designator"));
+        }
+
+        let (body, negative) = if let Some(body) = body.strip_prefix('-') {
+            (body, true)
+        } else {
+            (body.strip_prefix('+').unwrap_or(body), false)
+        };
+
+        // Degrees, minutes, seconds
+        let mut parts: [Option<f64>; 3] = [None; 3];
+        let mut next = 0;
+        let mut num = String::new();
+        let mut chars = body.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            let idx = match c {
+                '°' | 'd' | 'D' => Some(0),
+                '\'' | '′' => {
+                    // Two single quotes are an alternative to a double quote
+                    if chars.peek() == Some(&'\'') {
+                        chars.next();
+                        Some(2)
+                    } else {
+                        Some(1)
+                    }
+                }
+                '"' | '″' => Some(2),
+                c if c.is_whitespace() => None,
+                c => {
+                    num.push(c);
+                    continue;
+                }
+            };
+
+            if num.is_empty() {
+                if idx.is_some() {
+                    return Err(invalid("separator without a preceding number"));
+                }
+                continue;
+            }
+
+            let idx = idx.unwrap_or(next);
+            if idx < next || idx > 2 {
+                return Err(invalid("components out of order"));
+            }
+
+            parts[idx] = Some(num.parse().map_err(|_| invalid("not a number"))?);
+            num.clear();
+            next = idx + 1;
+        }
+
+        if !num.is_empty() {
+            if next > 2 {
+                return Err(invalid("too many components"));
+            }
+            parts[next] = Some(num.parse().map_err(|_| invalid("not a number"))?);
+        }
+
+        let (degrees, minutes, seconds) = match parts {
+            [Some(d), None, None] => (d, 0.0, 0.0),
+            [Some(d), Some(m), None] => (d, m, 0.0),
+            [Some(d), Some(m), Some(s)] => (d, m, s),
+            _ => return Err(invalid("missing degrees or minutes")),
+        };
+
+        if (parts[1].is_some() && degrees < 0.0) || !(0.0..f64::from(DM)).contains(&minutes) || !(0.0..f64::from(MS)).contains(&seconds) {
+            return Err(invalid("minutes and seconds must be in range [0, 60)"));
+        }
+
+        let mut angle = degrees + minutes / f64::from(DM) + seconds / f64::from(DS);
+        if negative {
+            angle = -angle;
+        }
+
+        let flag = match hemisphere {
+            Some('N') => Flag::Latitude,
+            Some('S') => {
+                angle = -angle;
+                Flag::Latitude
+            }
+            Some('E') => Flag::Longitude,
+            Some('W') => {
+                angle = -angle;
+                Flag::Longitude
+            }
+            _ => Flag::None,
+        };
+
+        Ok((angle, flag))
+    }
+
+    /// Decode a latitude/longitude pair. The two angles may be separated by a
+    /// comma or by whitespace. If hemisphere designators are given the pair may
+    /// appear in either order, otherwise latitude is assumed to come first.
+    pub fn decode_lat_lon(value: &str) -> Result<(f64, f64), Error> {
+        let value = value.trim();
+        let (first, second) = split_pair(value)
+            .ok_or_else(|| Error::InvalidCoord(format!("Expected a latitude/longitude pair in \"{value}\"")))?;
+
+        let (first, first_flag) = decode(first)?;
+        let (second, second_flag) = decode(second)?;
+
+        if first_flag != Flag::None && first_flag == second_flag {
+            return Err(Error::InvalidCoord(format!("Both angles in \"{value}\" have the same hemisphere type")));
+        }
+
+        if first_flag == Flag::Longitude || second_flag == Flag::Latitude {
+            Ok((second, first))
+        } else {
+            Ok((first, second))
+        }
+    }
+
+    /// Encode an angle as degrees, minutes and seconds, e.g. `40°44'54.0"N`, with
+    /// `seconds_precision` decimal places of seconds (at most 9). The hemisphere
+    /// designator is chosen from the sign according to `flag`; with [`Flag::None`]
+    /// a leading `-` is used instead. Longitude degrees are padded to 3 digits and
+    /// other angles to 2. Seconds are rounded before splitting into components, so
+    /// they never round up to 60.
+    pub fn encode(angle: f64, flag: Flag, seconds_precision: usize) -> String {
+        let precision = seconds_precision.min(9);
+        let scale = 10_u64.pow(precision as u32);
+        #[allow(clippy::cast_sign_loss)]
+        let total = (angle.abs() * f64::from(DS) * scale as f64).round() as u64;
+
+        let seconds = total % (MS as u64 * scale);
+        let minutes = total / (MS as u64 * scale) % DM as u64;
+        let degrees = total / (DS as u64 * scale);
+
+        let negative = angle.is_sign_negative() && total != 0;
+        let (prefix, hemisphere) = match flag {
+            Flag::None => (negative.ternary("-", ""), ""),
+            Flag::Latitude => ("", negative.ternary("S", "N")),
+            Flag::Longitude => ("", negative.ternary("W", "E")),
+        };
+        let width = (flag == Flag::Longitude).ternary(3, 2);
+
+        let seconds_str = if precision == 0 {
+            format!("{seconds:02}")
+        } else {
+            format!("{:02}.{:0precision$}", seconds / scale, seconds % scale)
+        };
+
+        format!("{prefix}{degrees:0width$}°{minutes:02}'{seconds_str}\"{hemisphere}")
+    }
+
+    /// Split a pair of angles on a comma, whitespace, or just after the first
+    /// hemisphere designator.
+    fn split_pair(value: &str) -> Option<(&str, &str)> {
+        if let Some((first, second)) = value.split_once(',') {
+            return Some((first, second));
+        }
+
+        let mut tokens = value.split_whitespace();
+        if let (Some(first), Some(second), None) = (tokens.next(), tokens.next(), tokens.next()) {
+            return Some((first, second));
+        }
+
+        // An 'e' between digits is an exponent, not a hemisphere
+        let bytes = value.as_bytes();
+        let idx = bytes
+            .iter()
+            .enumerate()
+            .position(|(i, c)| match c.to_ascii_uppercase() {
+                b'N' | b'S' | b'W' => true,
+                b'E' => !(i > 0 && bytes[i - 1].is_ascii_digit() && bytes.get(i + 1).is_some_and(|c| c.is_ascii_digit() || *c == b'-' || *c == b'+')),
+                _ => false,
+            })?;
+
+        let (first, second) = value.split_at(idx + 1);
+        (!second.trim().is_empty()).then_some((first, second))
+    }
 }
 
 fn special_sum(u: f64, v: f64) -> (f64, f64) {
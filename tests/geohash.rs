@@ -0,0 +1,43 @@
+#![cfg(feature = "geohash")]
+
+use geoconvert::{LatLon, Mgrs};
+
+#[test]
+fn round_trip_within_cell() {
+    let points = [
+        (40.748333, -73.985278),
+        (-33.8688, 151.2093),
+        (90.0, 0.0),
+        (-90.0, -180.0),
+        (0.0, 0.0),
+        (-0.000001, 179.999999),
+    ];
+
+    for (lat, lon) in points {
+        let coord = LatLon::create(lat, lon).unwrap();
+
+        for len in 1..=12 {
+            let geohash = coord.to_geohash(len);
+            assert_eq!(geohash.len(), len);
+
+            // The cell halves in longitude, then latitude, for each bit
+            let bits = 5 * len as i32;
+            let lon_size = 360.0 / 2_f64.powi((bits + 1) / 2);
+            let lat_size = 180.0 / 2_f64.powi(bits / 2);
+
+            let decoded = LatLon::from_geohash(&geohash).unwrap();
+            assert!((decoded.latitude() - lat).abs() <= lat_size / 2.0, "{geohash}");
+            assert!((decoded.longitude() - lon).abs() <= lon_size / 2.0, "{geohash}");
+
+            // Encoding the center of the cell gives the same cell
+            assert_eq!(decoded.to_geohash(len), geohash);
+        }
+    }
+}
+
+#[test]
+fn mgrs_to_geohash() {
+    let coord = Mgrs::parse_str("18TWL8566411315").unwrap().to_latlon();
+
+    assert_eq!(coord.to_geohash(7), "dr5ru6j");
+}
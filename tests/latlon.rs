@@ -0,0 +1,402 @@
+use std::f64::consts::PI;
+
+use geoconvert::{Ellipsoid, Error, LatLon, map_projection::TransverseMercator};
+
+const EARTH_MEAN_RADIUS_M: f64 = 6_371_008.8;
+
+fn ring(points: &[(f64, f64)]) -> Vec<LatLon> {
+    points.iter().map(|&(lat, lon)| LatLon::create(lat, lon).unwrap()).collect()
+}
+
+#[test]
+fn polygon_area_around_poles() {
+    // Great circle edges bulge poleward of the parallel, so the area is a little
+    // smaller than the polar cap
+    for lat in [80.0, -80.0] {
+        let points: Vec<LatLon> = (0..360).map(|lon| LatLon::create(lat, f64::from(lon) - 180.0).unwrap()).collect();
+
+        let cap = 2.0 * PI * (1.0 - f64::sin(f64::to_radians(80.0))) * EARTH_MEAN_RADIUS_M.powi(2);
+        let area = LatLon::polygon_area(&points);
+
+        assert!(area < cap);
+        assert!((area - cap).abs() / cap < 1e-3, "{area} != {cap}");
+
+        let mut reversed = points.clone();
+        reversed.reverse();
+        assert!((LatLon::polygon_area(&reversed) - area).abs() < 1.0);
+    }
+}
+
+#[test]
+fn polygon_area_octant() {
+    // One eighth of the sphere
+    let octant = ring(&[(0.0, 0.0), (0.0, 90.0), (90.0, 0.0)]);
+    let expected = PI / 2.0 * EARTH_MEAN_RADIUS_M.powi(2);
+
+    assert!((LatLon::polygon_area(&octant) - expected).abs() / expected < 1e-12);
+}
+
+#[test]
+fn polygon_area_across_antimeridian() {
+    let east = ring(&[(10.0, 170.0), (10.0, 179.0), (20.0, 179.0), (20.0, 170.0)]);
+    let across = ring(&[(10.0, 175.0), (10.0, -176.0), (20.0, -176.0), (20.0, 175.0)]);
+
+    assert!((LatLon::polygon_area(&east) - LatLon::polygon_area(&across)).abs() < 1e-3);
+}
+
+#[test]
+fn polygon_area_degenerate() {
+    assert_eq!(LatLon::polygon_area(&[]), 0.0);
+    assert_eq!(LatLon::polygon_area(&ring(&[(10.0, 10.0)])), 0.0);
+    assert_eq!(LatLon::polygon_area(&ring(&[(10.0, 10.0), (20.0, 20.0)])), 0.0);
+}
+
+#[test]
+fn interpolate_stays_on_great_circle() {
+    for (a, b) in [((40.6413, -73.7781), (51.47, -0.4543)), ((-33.9, 151.2), (37.6, -122.4)), ((89.0, 0.0), (89.0, -180.0))] {
+        let a = LatLon::create(a.0, a.1).unwrap();
+        let b = LatLon::create(b.0, b.1).unwrap();
+        let distance = a.haversine(&b);
+
+        for fraction in [0.1, 0.3, 0.5, 0.9] {
+            let point = a.interpolate(&b, fraction);
+
+            assert!((point.haversine(&a) - fraction * distance).abs() < 1e-6);
+            assert!((point.haversine(&b) - (1.0 - fraction) * distance).abs() < 1e-6);
+        }
+    }
+}
+
+#[test]
+fn interpolate_degenerate() {
+    let a = LatLon::create(10.0, 20.0).unwrap();
+    let antipode = LatLon::create(-10.0, -160.0).unwrap();
+
+    // Identical points
+    assert_eq!(a.interpolate(&a, 0.5), a);
+
+    // Antipodal points have no unique path
+    assert_eq!(a.interpolate(&antipode, 0.5), a);
+    assert_eq!(a.interpolate(&antipode, 1.0), antipode);
+
+    // Nearly antipodal points still give a point on a great circle between them
+    let near = LatLon::create(-10.0, -159.999).unwrap();
+    let mid = a.interpolate(&near, 0.5);
+    assert!((mid.haversine(&a) - mid.haversine(&near)).abs() < 1e-3);
+}
+
+#[test]
+fn wkt_longitude_first() {
+    // Asymmetric values, so swapping them would be noticed
+    let coord = LatLon::create(-33.8688, 151.2093).unwrap();
+    assert_eq!(coord.to_wkt(), "POINT(151.2093 -33.8688)");
+
+    let parsed = LatLon::from_wkt("POINT(151.2093 -33.8688)").unwrap();
+    assert_eq!(parsed.latitude(), -33.8688);
+    assert_eq!(parsed.longitude(), 151.2093);
+
+    for (lat, lon) in [(0.0, 0.0), (90.0, -180.0), (-12.5, 45.000001), (40.748333, -73.985278)] {
+        let coord = LatLon::create(lat, lon).unwrap();
+        assert_eq!(LatLon::from_wkt(&coord.to_wkt()).unwrap(), coord);
+    }
+
+    for invalid in ["", "POINT", "POINT()", "POINT(1)", "POINT(1 2 3)", "POINT(1 2", "LINESTRING(1 2, 3 4)", "POINT(a b)"] {
+        assert!(LatLon::from_wkt(invalid).is_err(), "{invalid}");
+    }
+}
+
+#[test]
+fn ecef_round_trip() {
+    for lat in [-90.0, -89.9999, -60.0, -1e-9, 0.0, 30.0, 45.0, 89.9999, 90.0] {
+        for lon in [-180.0, -73.985278, 0.0, 100.0, 179.999] {
+            // From deep below the surface to beyond the Moon
+            for height in [-6_000_000.0, -5000.0, 0.0, 443.0, 10_000_000.0, 400_000_000.0] {
+                let coord = LatLon::create(lat, lon).unwrap();
+                let (x, y, z) = coord.to_ecef(height);
+                let (back, back_height) = LatLon::from_ecef(x, y, z);
+
+                assert!((back.latitude() - lat).abs() < 1e-12, "({lat}, {lon}, {height}): {back}");
+                if lat.abs() < 90.0 {
+                    assert!(back.approx_eq(&coord, 1e-12), "({lat}, {lon}, {height}): {back}");
+                }
+                assert!((back_height - height).abs() < 1e-6 * height.abs().max(1.0), "({lat}, {lon}, {height}): {back_height}");
+            }
+        }
+    }
+
+    // The center of the Earth still gives a valid point
+    let (coord, height) = LatLon::from_ecef(0.0, 0.0, 0.0);
+    assert_eq!(coord, LatLon::ORIGIN);
+    assert_eq!(height, -6_378_137.0);
+}
+
+#[test]
+fn cross_and_along_track() {
+    let start = LatLon::create(40.6413, -73.7781).unwrap();
+    let end = LatLon::create(51.47, -0.4543).unwrap();
+    let bearing = start.initial_bearing(&end);
+
+    for along in [-500_000.0, 0.0, 1_000.0, 2_000_000.0, 6_000_000.0] {
+        for cross in [-300_000.0, -10.0, 0.0, 10.0, 300_000.0] {
+            // Walk along the track, then off it at right angles
+            let on_track = start.destination(bearing, along);
+            let track_bearing = if along == 0.0 {
+                bearing
+            } else if along < 0.0 {
+                on_track.initial_bearing(&start)
+            } else {
+                start.final_bearing(&on_track)
+            };
+            let point = on_track.destination(track_bearing + 90.0, cross);
+
+            let xt = point.cross_track_distance(&start, &end);
+            let at = point.along_track_distance(&start, &end);
+
+            assert!((xt - cross).abs() < 1e-3, "({along}, {cross}): cross track {xt}");
+            assert!((at - along).abs() < 1e-3, "({along}, {cross}): along track {at}");
+        }
+    }
+}
+
+#[test]
+fn normalize_always_valid() {
+    for lat in [-450.0, -270.0, -190.0, -180.0, -91.0, -90.0, -45.5, 0.0, 89.999, 90.0, 135.0, 180.0, 275.0, 720.5] {
+        for lon in [-900.0, -540.0, -180.0, -179.5, 0.0, 179.999, 180.0, 359.0, 360.0, 1e6] {
+            let coord = LatLon::normalize(lat, lon);
+
+            assert_eq!(
+                LatLon::create(coord.latitude(), coord.longitude()).ok(),
+                Some(coord),
+                "({lat}, {lon}) normalized to ({}, {})", coord.latitude(), coord.longitude()
+            );
+            assert_eq!(LatLon::normalize(coord.latitude(), coord.longitude()), coord);
+        }
+    }
+
+    // Reflecting over a pole reaches the same place as going the long way round
+    let over = LatLon::normalize(100.0, 20.0);
+    assert!(over.approx_eq(&LatLon::create(80.0, -160.0).unwrap(), 1e-12));
+}
+
+#[test]
+fn bearing_distance_matches_separate() {
+    let points = [
+        (40.6413, -73.7781),
+        (51.47, -0.4543),
+        (-33.8688, 151.2093),
+        (0.0, 179.9),
+        (0.0, -179.9),
+        (89.9, 10.0),
+        (-90.0, 0.0),
+        (40.6413, -73.7780),
+    ];
+
+    for (lat1, lon1) in points {
+        for (lat2, lon2) in points {
+            let a = LatLon::create(lat1, lon1).unwrap();
+            let b = LatLon::create(lat2, lon2).unwrap();
+
+            let (bearing, distance) = a.bearing_distance_to(&b);
+            let bearing_diff = (bearing - a.initial_bearing(&b)).abs();
+
+            assert!(bearing_diff.min(360.0 - bearing_diff) < 1e-9, "({lat1}, {lon1}) to ({lat2}, {lon2})");
+            assert!((distance - a.haversine(&b)).abs() < 1e-6, "({lat1}, {lon1}) to ({lat2}, {lon2})");
+        }
+    }
+}
+
+#[test]
+fn bearing_below_360() {
+    // Just west of due north, so the bearing is a tiny negative angle before
+    // it's normalized
+    let start = LatLon::create(0.0, 0.0).unwrap();
+    let end = LatLon::create(10.0, -1e-16).unwrap();
+
+    for bearing in [start.initial_bearing(&end), start.bearing_distance_to(&end).0] {
+        assert!((0.0..360.0).contains(&bearing), "{bearing}");
+    }
+}
+
+#[test]
+fn geodesic_midpoint_equidistant() {
+    let points = [
+        (40.6413, -73.7781),
+        (51.47, -0.4543),
+        (-33.8688, 151.2093),
+        (-37.95103342, 144.42486789),
+        (0.0, 179.5),
+        (10.0, -179.5),
+        (89.0, 45.0),
+    ];
+
+    for (lat1, lon1) in points {
+        for (lat2, lon2) in points {
+            let a = LatLon::create(lat1, lon1).unwrap();
+            let b = LatLon::create(lat2, lon2).unwrap();
+
+            let mid = a.geodesic_midpoint(&b);
+            let half = a.geodesic_distance(&b) / 2.0;
+
+            assert!(LatLon::create(mid.latitude(), mid.longitude()).is_ok());
+            assert!((mid.geodesic_distance(&a) - half).abs() < 1e-3, "({lat1}, {lon1}) to ({lat2}, {lon2})");
+            assert!((mid.geodesic_distance(&b) - half).abs() < 1e-3, "({lat1}, {lon1}) to ({lat2}, {lon2})");
+        }
+    }
+}
+
+#[test]
+fn geodesic_midpoint_reference() {
+    // Along the equator
+    let mid = LatLon::create(0.0, 10.0).unwrap().geodesic_midpoint(&LatLon::create(0.0, 50.0).unwrap());
+    assert!(mid.approx_eq(&LatLon::create(0.0, 30.0).unwrap(), 1e-9));
+
+    // Along a meridian, the midpoint halves the meridian arc, which is the
+    // northing of a transverse Mercator projection centered on the meridian
+    let tm = TransverseMercator::create(Ellipsoid::WGS84, 0.0, 1.0).unwrap();
+    for (lat1, lat2) in [(0.0, 90.0), (-60.0, 70.0), (10.0, 20.0)] {
+        let a = LatLon::create(lat1, 0.0).unwrap();
+        let b = LatLon::create(lat2, 0.0).unwrap();
+        let mid = a.geodesic_midpoint(&b);
+
+        let (_, y1) = tm.from_latlon(&a);
+        let (_, y2) = tm.from_latlon(&b);
+        let (x, y) = tm.from_latlon(&mid);
+
+        assert!(x.abs() < 1e-6, "({lat1}, 0) to ({lat2}, 0)");
+        assert!((y - (y1 + y2) / 2.0).abs() < 1e-3, "({lat1}, 0) to ({lat2}, 0)");
+    }
+}
+
+#[test]
+fn create_rejects_non_finite() {
+    for (lat, lon) in [(f64::NAN, 0.0), (0.0, f64::NAN), (f64::INFINITY, 0.0), (0.0, f64::NEG_INFINITY)] {
+        assert!(matches!(LatLon::create(lat, lon), Err(Error::InvalidCoord(_))), "({lat}, {lon})");
+    }
+}
+
+#[test]
+fn bounding_box_is_narrowest_containing_all_points() {
+    for start in (-180..180).step_by(17) {
+        for width in [1, 45, 170, 190, 300] {
+            let points: Vec<LatLon> = (0..=10)
+                .map(|i| {
+                    let lon = f64::from(start) + f64::from(width) * f64::from(i) / 10.0;
+                    LatLon::normalize(f64::from(i) * 5.0 - 20.0, lon)
+                })
+                .collect();
+
+            let (sw, ne) = LatLon::bounding_box(&points).unwrap();
+
+            assert_eq!(sw.latitude(), -20.0);
+            assert_eq!(ne.latitude(), 30.0);
+
+            // The largest gap between the points is always the one left out of
+            // their span, even when it crosses the antimeridian
+            let span = (ne.longitude() - sw.longitude()).rem_euclid(360.0);
+            assert!((span - f64::from(width)).abs() < 1e-9, "start {start}, width {width}: span {span}");
+
+            for point in &points {
+                let offset = (point.longitude() - sw.longitude()).rem_euclid(360.0);
+                assert!(offset <= span + 1e-9, "start {start}, width {width}: {point:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn round_to_stays_valid() {
+    for (lat, lon) in [(89.99999, 179.99999), (-89.99999, -179.99999), (0.4, 179.5), (45.123456, -179.5000001)] {
+        let coord = LatLon::create(lat, lon).unwrap();
+
+        for decimals in 0..=16 {
+            let rounded = coord.round_to(decimals);
+
+            assert!(LatLon::create(rounded.latitude(), rounded.longitude()).is_ok(), "{coord:?} to {decimals}: {rounded:?}");
+            assert!(rounded.haversine(&coord) <= coord.round_to(0).haversine(&coord).max(1e-6), "{coord:?} to {decimals}: {rounded:?}");
+        }
+    }
+}
+
+#[test]
+fn parse_hemisphere_suffixes() {
+    let expected = LatLon::create(40.7483, -73.9853).unwrap();
+
+    for s in ["40.7483N 73.9853W", "73.9853W 40.7483N", "40.7483n, 73.9853w", "N40.7483 W73.9853", "40.7483 N 73.9853 W"] {
+        assert_eq!(s.parse::<LatLon>().unwrap(), expected, "{s}");
+    }
+
+    let coord: LatLon = "33.8568S 151.2153E".parse().unwrap();
+    assert_eq!(coord, LatLon::create(-33.8568, 151.2153).unwrap());
+
+    for s in ["-40.7483N 73.9853W", "40.7483N +73.9853W", "40.7483N 73.9853S", "95.0N 73.9853W", "40.7483N 190.0W"] {
+        assert!(s.parse::<LatLon>().is_err(), "{s}");
+    }
+}
+
+#[test]
+fn densify_path_limits_segments() {
+    let path = [(0.0, 170.0), (10.0, -170.0), (10.0, -170.0), (-60.0, -100.0), (89.0, 0.0)]
+        .map(|(lat, lon)| LatLon::create(lat, lon).unwrap());
+
+    for max_segment_m in [1_000.0, 50_000.0, 1e7, 1e9] {
+        let dense = LatLon::densify_path(&path, max_segment_m).unwrap();
+
+        assert!(dense.windows(2).all(|pair| pair[0].haversine(&pair[1]) <= max_segment_m * (1.0 + 1e-9)), "{max_segment_m}");
+
+        // The original points are kept, in order
+        let mut rest = dense.iter();
+        for point in &path {
+            assert!(rest.any(|p| p == point), "{max_segment_m}: {point:?}");
+        }
+    }
+
+    assert!(LatLon::densify_path(&[], 1_000.0).unwrap().is_empty());
+    assert_eq!(LatLon::densify_path(&path[..1], 1_000.0).unwrap(), &path[..1]);
+}
+
+#[test]
+fn densify_path_rejects_too_many_points() {
+    let path = [(0.0, 0.0), (0.0, 90.0)].map(|(lat, lon)| LatLon::create(lat, lon).unwrap());
+
+    // 1,000,000 segments need one point too many, 999,999 segments are fine
+    let quarter = path[0].haversine(&path[1]);
+    assert!(LatLon::densify_path(&path, quarter / 999_999.5).is_err());
+    assert_eq!(LatLon::densify_path(&path, quarter / 999_998.5).unwrap().len(), 1_000_000);
+
+    assert!(matches!(LatLon::densify_path(&path, 1e-3), Err(Error::TooManyItems { limit: 1_000_000, .. })));
+}
+
+#[test]
+fn densify_path_rejects_invalid_segment_length() {
+    let path = [(0.0, 0.0), (0.0, 90.0)].map(|(lat, lon)| LatLon::create(lat, lon).unwrap());
+
+    for max_segment_m in [0.0, -1_000.0, f64::NAN, f64::INFINITY] {
+        assert!(matches!(LatLon::densify_path(&path, max_segment_m), Err(Error::InvalidCoord(_))), "{max_segment_m}");
+    }
+}
+
+#[test]
+fn within_fast_matches_within() {
+    let points: Vec<LatLon> = (-90..=90)
+        .step_by(3)
+        .flat_map(|lat| (-180..180).step_by(4).map(move |lon| LatLon::create(f64::from(lat), f64::from(lon)).unwrap()))
+        .collect();
+
+    for (lat, lon) in [(0.0, 0.0), (40.7, -74.0), (-85.0, 179.0), (89.0, -30.0), (10.0, -180.0)] {
+        let center = LatLon::create(lat, lon).unwrap();
+
+        for radius_m in [0.0, 1e5, 5e5, 1e6, 3e6, 1e7, 2.1e7] {
+            let inside = LatLon::within_fast(&points, &center, radius_m);
+
+            for (point, inside) in points.iter().zip(inside) {
+                assert_eq!(point.within(&center, radius_m), inside, "{point:?} from {center:?}, radius {radius_m}");
+            }
+        }
+
+        // Exactly on the edge of the circle
+        let edge = center.destination(45.0, 250_000.0);
+        let radius_m = edge.haversine(&center);
+        assert!(edge.within(&center, radius_m));
+        assert_eq!(LatLon::within_fast(&[edge], &center, radius_m), [true]);
+    }
+}
@@ -0,0 +1,463 @@
+use std::collections::HashSet;
+
+use geoconvert::{Error, LatLon, Mgrs, MgrsPrecision, Position, UtmUps};
+
+#[test]
+fn parse_with_spaces() {
+    let compact = Mgrs::parse_str("4QFJ12345678").unwrap();
+    let spaced = Mgrs::parse_str("4Q FJ 1234 5678").unwrap();
+
+    assert_eq!(compact.zone(), 4);
+    assert_eq!(compact.precision(), 4);
+    assert_eq!(compact, spaced);
+    assert_eq!(compact.to_string(), spaced.to_string());
+    // Zones are always output with two digits
+    assert_eq!(spaced.to_string(), "04QFJ12345678");
+
+    for value in [
+        "4QFJ 12345678",
+        "4QFJ 1234 5678",
+        "4Q FJ12345678",
+        " 4QFJ12345678 ",
+        "4Q\tFJ  1234\n5678",
+        "4q fj 1234 5678",
+    ] {
+        assert_eq!(Mgrs::parse_str(value).unwrap(), compact, "{value}");
+    }
+
+    let grid_zone = Mgrs::parse_str("18T WL").unwrap();
+    assert_eq!(grid_zone.to_string(), "18TWL");
+}
+
+#[test]
+fn parse_with_spaces_malformed() {
+    for value in [
+        // Uneven digit groups
+        "4Q FJ 12345 678",
+        "4Q FJ 123 45678",
+        // Still an odd number of digits overall
+        "4Q FJ 1234 567",
+        // Letters in the digits
+        "4Q FJ 1234 56X8",
+        // Spaces don't make a missing row letter valid
+        "4Q F 1234 5678",
+        "",
+        "   ",
+    ] {
+        assert!(Mgrs::parse_str(value).is_err(), "{value}");
+    }
+}
+
+/// Checks that the squares returned for a bounding box contain every point
+/// sampled from inside it, without duplicates
+fn check_squares_in_bbox(sw: (f64, f64), ne: (f64, f64), precision: i32) -> Vec<Mgrs> {
+    const SAMPLES: usize = 150;
+
+    let squares = Mgrs::squares_in_bbox(
+        &LatLon::create(sw.0, sw.1).unwrap(),
+        &LatLon::create(ne.0, ne.1).unwrap(),
+        precision,
+    ).unwrap();
+
+    let unique: HashSet<Mgrs> = squares.iter().copied().collect();
+    assert_eq!(unique.len(), squares.len(), "duplicate squares");
+
+    let width = if ne.1 > sw.1 { ne.1 - sw.1 } else { ne.1 + 360.0 - sw.1 };
+
+    for i in 0..SAMPLES {
+        for j in 0..SAMPLES {
+            let lat = sw.0 + (ne.0 - sw.0) * (i as f64 + 0.5) / SAMPLES as f64;
+            let mut lon = sw.1 + width * (j as f64 + 0.5) / SAMPLES as f64;
+            if lon >= 180.0 {
+                lon -= 360.0;
+            }
+
+            let point = LatLon::create(lat, lon).unwrap().to_mgrs(precision);
+            assert!(unique.contains(&point), "{point} missing at ({lat}, {lon})");
+        }
+    }
+
+    squares
+}
+
+#[test]
+fn squares_in_bbox() {
+    // Within a single square
+    let squares = check_squares_in_bbox((40.748, -73.986), (40.749, -73.985), 2);
+    assert_eq!(squares.len(), 1);
+    assert_eq!(squares[0].to_string(), "18TWL8511");
+
+    // Across zones 17 and 18
+    let squares = check_squares_in_bbox((40.0, -78.5), (41.2, -76.5), 1);
+    assert!(squares.iter().any(|square| square.zone() == 17));
+    assert!(squares.iter().any(|square| square.zone() == 18));
+
+    // Across the equator and the antimeridian
+    let squares = check_squares_in_bbox((-1.0, 179.5), (1.0, -179.5), 1);
+    for (zone, northp) in [(60, true), (60, false), (1, true), (1, false)] {
+        assert!(squares.iter().any(|square| square.zone() == zone && square.is_north() == northp));
+    }
+
+    // Band letters are those of the covered area
+    for square in &squares {
+        let band = square.to_string().chars().nth(2).unwrap();
+        assert!(band == 'M' || band == 'N', "{square}");
+    }
+}
+
+#[test]
+fn squares_in_bbox_zone_exceptions() {
+    // Norway: zone 32 is widened to 3°E
+    let squares = check_squares_in_bbox((60.0, 3.5), (61.0, 5.5), 1);
+    assert!(squares.iter().all(|square| square.zone() == 32));
+
+    // Svalbard: zones 32, 34 and 36 aren't used
+    let squares = check_squares_in_bbox((74.0, 5.0), (80.0, 40.0), 0);
+    assert!(squares.iter().all(|square| [31, 33, 35, 37].contains(&square.zone())));
+}
+
+#[test]
+fn squares_in_bbox_ups() {
+    let squares = check_squares_in_bbox((83.0, -180.0), (90.0, 179.999), 0);
+    assert!(squares.iter().any(|square| square.zone() == 0));
+    assert!(squares.iter().any(|square| square.zone() != 0));
+
+    let squares = check_squares_in_bbox((-90.0, -180.0), (-81.0, 179.999), 0);
+    assert!(squares.iter().all(|square| square.zone() == 0 && !square.is_north()));
+}
+
+#[test]
+fn squares_in_bbox_invalid() {
+    let sw = LatLon::create(40.0, -74.0).unwrap();
+    let ne = LatLon::create(41.0, -73.0).unwrap();
+
+    assert!(Mgrs::squares_in_bbox(&sw, &ne, -1).is_err());
+    assert!(Mgrs::squares_in_bbox(&sw, &ne, 12).is_err());
+    // Southwest corner north of the northeast corner
+    assert!(Mgrs::squares_in_bbox(&ne, &sw, 0).is_err());
+    // No width
+    assert!(Mgrs::squares_in_bbox(&sw, &LatLon::create(41.0, -74.0).unwrap(), 0).is_err());
+    // Too many squares to return, rejected without walking them
+    let too_many = |result| matches!(result, Err(Error::TooManyItems { limit: 1_000_000, .. }));
+    assert!(too_many(Mgrs::squares_in_bbox(&sw, &ne, 4)));
+    assert!(too_many(Mgrs::squares_in_bbox(&sw, &ne, 11)));
+    assert!(too_many(Mgrs::squares_in_bbox(&LatLon::create(-90.0, -180.0).unwrap(), &LatLon::create(90.0, 179.9).unwrap(), 2)));
+}
+
+#[test]
+fn equator() {
+    for (lon, expected) in [(0.0, "31NAA6602100000"), (3.0, "31NEA0000000000"), (-73.5, "18NXF6693100000")] {
+        let coord = LatLon::create(0.0, lon).unwrap().to_mgrs(5);
+        assert_eq!(coord.to_string(), expected);
+
+        let parsed = Mgrs::parse_str(expected).unwrap();
+        assert!(parsed.to_latlon().approx_eq(&LatLon::create(0.0, lon).unwrap(), 1e-5));
+    }
+}
+
+#[test]
+fn hundred_km_square_keeps_band() {
+    // The square from 4,400 km to 4,500 km north in zone 18 is split by 40°N
+    for (lat, expected) in [(39.95, "18SWK"), (40.1, "18TWK"), (40.5, "18TWK")] {
+        let coord = LatLon::create(lat, -75.0).unwrap().to_mgrs(5);
+        let square = coord.hundred_km_square();
+
+        assert_eq!(square.to_string(), expected);
+        assert_eq!(square.to_string(), coord.at_precision(0).unwrap().to_string());
+        assert_eq!(square.easting(), 550_000.0);
+    }
+
+    // UPS
+    let coord = LatLon::create(-87.0, 100.0).unwrap().to_mgrs(5);
+    let square = coord.hundred_km_square();
+    assert_eq!(square.to_string(), coord.at_precision(0).unwrap().to_string());
+    assert_eq!(square.to_string().len(), 3);
+
+    let grid_zone = Mgrs::parse_str("18T").unwrap();
+    assert_eq!(grid_zone.hundred_km_square(), grid_zone);
+}
+
+fn neighbor_names(lat: f64, lon: f64, precision: i32) -> Vec<String> {
+    let coord = LatLon::create(lat, lon).unwrap().to_mgrs(precision);
+
+    coord.neighbors().iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn neighbors_symmetric_within_zone() {
+    for (lat, lon) in [(40.748333, -73.985278), (-33.8688, 151.2093), (0.1, 3.0), (-87.0, 100.0), (88.0, -45.0)] {
+        for precision in 0..=5 {
+            let coord = LatLon::create(lat, lon).unwrap().to_mgrs(precision);
+            let neighbors = coord.neighbors();
+
+            for (i, neighbor) in neighbors.iter().enumerate() {
+                assert_ne!(*neighbor, coord);
+                assert_eq!(neighbor.precision(), precision);
+                assert_eq!(neighbor.neighbors()[(i + 4) % 8], coord, "{coord} -> {neighbor}");
+            }
+
+            let distinct: HashSet<Mgrs> = neighbors.into_iter().collect();
+            assert_eq!(distinct.len(), 8);
+        }
+    }
+}
+
+#[test]
+fn neighbors_across_boundaries() {
+    // Equator
+    assert_eq!(
+        neighbor_names(0.0001, 3.0, 0),
+        ["31NEB", "31NFB", "31NFA", "31MFV", "31MEV", "31MDV", "31NDA", "31NDB"],
+    );
+    // Antimeridian
+    assert_eq!(
+        neighbor_names(10.0, 179.99, 0),
+        ["01PAN", "01PBN", "01PBM", "01PBL", "01PAL", "60PYR", "60PYS", "60PYT"],
+    );
+    // Norway exception
+    assert_eq!(
+        neighbor_names(56.5, 2.99, 0),
+        ["31VDD", "32VJJ", "32VJH", "31UEB", "31UDB", "31UCB", "31VCC", "31VCD"],
+    );
+    // UTM and UPS
+    assert_eq!(
+        neighbor_names(-79.99, 10.0, 0),
+        ["32CNT", "33CWN", "33CWM", "BFY", "BCY", "BBY", "32CMS", "32CMT"],
+    );
+    assert_eq!(
+        neighbor_names(-80.01, 10.0, 0),
+        ["32CMS", "33CVM", "BCY", "BCX", "BBX", "BAX", "BAY", "31CDM"],
+    );
+
+    // Grid zone only is treated as the 100 km square
+    let coord = LatLon::create(60.0, 5.0).unwrap().to_mgrs(-1);
+    assert!(coord.neighbors().iter().all(|neighbor| neighbor.precision() == 0));
+}
+
+#[test]
+fn parse_center_and_corner_round_trip() {
+    for value in ["18TWL8511", "18TWL8566411315", "31NEA0000000000", "YXL6143481146", "BAN1234", "58GCQ05", "18TWL"] {
+        let center = Mgrs::parse_str(value).unwrap();
+        let corner = Mgrs::parse_str_corner(value).unwrap();
+
+        assert_eq!(center.to_string(), value);
+        assert_eq!(corner.to_string(), value);
+        assert_eq!(center, corner);
+
+        // The corner is the southwest corner of the square, and the center is
+        // half a square from it
+        let size = 100_000.0 / 10_f64.powi(center.precision());
+        let (sw, _) = center.bounds();
+        assert!(corner.to_latlon().approx_eq(&sw, 1e-9));
+        assert!((center.easting() - corner.easting() - size / 2.0).abs() < 1e-9);
+        assert!((center.northing() - corner.northing() - size / 2.0).abs() < 1e-9);
+
+        // The center survives a round trip through lat/lon. The corner is on the
+        // edge of the square, so round-off may move it into the neighboring one,
+        // but it comes back to the same position
+        let back = center.to_latlon().to_mgrs(center.precision());
+        assert_eq!(back.to_string(), value);
+
+        let back = corner.to_latlon().to_utmups();
+        assert!(back.approx_eq(&corner.to_utmups(), 1e-6));
+    }
+
+    let grid_zone = "18T";
+    assert_eq!(Mgrs::parse_str(grid_zone).unwrap().easting(), Mgrs::parse_str_corner(grid_zone).unwrap().easting());
+}
+
+#[test]
+fn same_cell_matches_at_precision() {
+    let points = [
+        (40.748333, -73.985278),
+        (40.7484, -73.9853),
+        (40.75, -73.99),
+        (0.0001, 3.0),
+        (-0.0001, 3.0),
+        (60.0, 2.9999),
+        (85.0, 10.0),
+        (85.0001, 10.0001),
+    ];
+
+    for (lat1, lon1) in points {
+        for (lat2, lon2) in points {
+            let a = LatLon::create(lat1, lon1).unwrap().to_mgrs(11);
+            let b = LatLon::create(lat2, lon2).unwrap().to_mgrs(5);
+
+            for precision in -1..=11 {
+                assert_eq!(
+                    a.same_cell(&b, precision),
+                    a.at_precision(precision).unwrap() == b.at_precision(precision).unwrap(),
+                    "{a} and {b} at precision {precision}"
+                );
+            }
+
+            assert_eq!(a.common_precision(&b), 5);
+        }
+    }
+}
+
+#[test]
+fn usng_round_trip() {
+    let points = [
+        (40.748333, -73.985278),
+        (21.3069, -157.8583),
+        (-33.8688, 151.2093),
+        (85.0, 10.0),
+        (-88.0, -120.0),
+    ];
+
+    for (lat, lon) in points {
+        for precision in -1..=5 {
+            let coord = LatLon::create(lat, lon).unwrap().to_mgrs(precision);
+            let usng = coord.to_usng_string();
+
+            assert!(!usng.starts_with('0'), "{usng}");
+            assert_eq!(Mgrs::parse_usng(&usng).unwrap(), coord, "{usng}");
+        }
+    }
+}
+
+#[test]
+fn from_latlon_zone_matches_forced_utm() {
+    for lat in [-79.5, -40.0, 0.0, 40.0, 83.5] {
+        for lon in [-77.9, -78.1, 179.8, -179.8] {
+            let coord = LatLon::create(lat, lon).unwrap();
+            let natural = coord.to_utmups();
+
+            for zone in [natural.zone(), natural.adjacent_zone().unwrap()] {
+                let mgrs = Mgrs::from_latlon_zone(&coord, zone, 5).unwrap();
+                let utm = UtmUps::from_latlon_zone(&coord, zone).unwrap();
+
+                assert_eq!(mgrs.zone(), zone);
+                assert_eq!(mgrs, utm.to_mgrs(5), "({lat}, {lon}) in zone {zone}");
+            }
+        }
+    }
+}
+
+#[test]
+fn extended_precision_round_trip() {
+    let points = [
+        (40.748333, -73.985278),
+        (-33.8688, 151.2093),
+        (0.0, 3.0),
+        (83.99999, 5.0),
+        (85.0, 10.0),
+        (-88.0, -120.0),
+    ];
+
+    for (lat, lon) in points {
+        let coord = LatLon::create(lat, lon).unwrap();
+
+        for precision in [7, 11] {
+            let mgrs = coord.to_mgrs(precision);
+            let mgrs_str = mgrs.to_string();
+            let digits = mgrs_str.len() - if mgrs.is_utm() { 5 } else { 3 };
+            assert_eq!(digits, 2 * precision as usize, "{mgrs_str}");
+
+            let parsed = Mgrs::parse_str(&mgrs_str).unwrap();
+            assert_eq!(parsed.precision(), precision);
+            assert_eq!(parsed.to_string(), mgrs_str);
+            assert_eq!(parsed, mgrs);
+
+            assert_eq!(Mgrs::parse_str_corner(&mgrs_str).unwrap().to_string(), mgrs_str);
+
+            // Half a cell diagonal from the original point at most, allowing for
+            // the scale factor of the grid
+            let tolerance = 0.5 * 2_f64.sqrt() * mgrs.resolution_meters() * 1.01;
+            assert!(parsed.to_latlon().geodesic_distance(&coord) <= tolerance, "{mgrs_str}");
+        }
+    }
+
+    assert!(Mgrs::parse_str("18TWL856641113154856641113154").is_err());
+}
+
+#[test]
+fn create_rejects_non_finite() {
+    for zone in [0, 18] {
+        for (easting, northing) in [(f64::INFINITY, 4511315.0), (585664.0, f64::NAN), (f64::NEG_INFINITY, f64::NAN)] {
+            assert!(matches!(Mgrs::create(zone, true, easting, northing, 5), Err(Error::InvalidCoord(_))), "({easting}, {northing})");
+        }
+    }
+}
+
+#[test]
+fn center_latlon_matches_parsed() {
+    for lat in (-89..90).step_by(3) {
+        for lon in (-177..180).step_by(7) {
+            let coord = LatLon::create(f64::from(lat) + 0.3, f64::from(lon) + 0.3).unwrap();
+
+            for precision in -1..=5 {
+                let mgrs = coord.to_mgrs(precision);
+                let mgrs_str = mgrs.to_string();
+                let parsed = Mgrs::parse_str(&mgrs_str).unwrap();
+
+                assert!(mgrs.center_latlon().approx_eq(&parsed.to_latlon(), 1e-9), "{mgrs_str}");
+            }
+        }
+    }
+}
+
+#[test]
+fn lowercase_round_trip() {
+    for lat in (-89..90).step_by(11) {
+        for lon in (-177..180).step_by(13) {
+            let coord = LatLon::create(f64::from(lat) + 0.3, f64::from(lon) + 0.3).unwrap();
+
+            for precision in -1..=5 {
+                let mgrs = coord.to_mgrs(precision);
+                let lower = mgrs.to_string_lowercase();
+
+                assert_eq!(lower, mgrs.to_string().to_ascii_lowercase());
+                assert_eq!(Mgrs::parse_str(&lower).unwrap(), mgrs, "{lower}");
+            }
+        }
+    }
+}
+
+#[test]
+fn precision_enum_matches_integer_precision() {
+    let coord = LatLon::create(40.748333, -73.985278).unwrap();
+
+    for precision in -1..=11 {
+        let precision_enum = MgrsPrecision::try_from(precision).unwrap();
+        assert_eq!(i32::from(precision_enum), precision);
+
+        let mgrs = Mgrs::from_latlon_precision(&coord, precision_enum);
+        assert_eq!(mgrs, coord.to_mgrs(precision));
+        assert_eq!(mgrs.precision_enum(), precision_enum);
+    }
+
+    assert!(matches!(MgrsPrecision::try_from(-2), Err(Error::InvalidPrecision(-2))));
+    assert!(matches!(MgrsPrecision::try_from(12), Err(Error::InvalidPrecision(12))));
+}
+
+#[test]
+fn out_of_range_precision_is_clamped() {
+    let coord = LatLon::create(40.748333, -73.985278).unwrap();
+    let utm = coord.to_utmups();
+
+    for (precision, clamped) in [(12, 11), (99, 11), (-2, -1), (-99, -1)] {
+        let expected = coord.to_mgrs(clamped).to_string();
+
+        let converted = [
+            Mgrs::from_latlon(&coord, precision),
+            Mgrs::from_utmups(&utm, precision),
+            coord.to_mgrs(precision),
+            utm.to_mgrs(precision),
+            Position::from(coord).to_mgrs(precision),
+            Position::from(utm).to_mgrs(precision),
+            Position::from(coord.to_mgrs(5)).to_mgrs(precision),
+        ];
+
+        for mgrs in converted {
+            assert_eq!(mgrs.precision(), clamped, "{precision}");
+            assert_eq!(i32::from(mgrs.precision_enum()), clamped, "{precision}");
+            assert_eq!(mgrs.to_string(), expected, "{precision}");
+        }
+    }
+
+    assert_eq!(coord.to_mgrs(-2).to_string(), "18T");
+    assert_eq!(coord.to_mgrs(12).to_string().len(), "18TWL".len() + 2 * 11);
+}
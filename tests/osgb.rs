@@ -0,0 +1,77 @@
+use geoconvert::{LatLon, osgb::OsgbGridRef};
+
+#[test]
+fn squares_round_trip() {
+    // Every 100 km square on the grid, with the letters counting up from SV at
+    // the false origin
+    for e in 0..7 {
+        for n in 0..13 {
+            let grid_ref = OsgbGridRef::create(f64::from(e) * 100_000.0 + 12_345.0, f64::from(n) * 100_000.0 + 67_890.0, 5).unwrap();
+            let s = grid_ref.to_string();
+
+            assert_eq!(&s[2..], " 12345 67890");
+
+            let parsed = OsgbGridRef::parse_str(&s).unwrap();
+            assert_eq!(parsed, grid_ref, "{s}");
+        }
+    }
+
+    for (s, easting, northing) in [
+        ("SV", 0.0, 0.0),
+        ("SW", 100_000.0, 0.0),
+        ("SQ", 0.0, 100_000.0),
+        ("TV", 500_000.0, 0.0),
+        ("NV", 0.0, 500_000.0),
+        ("HL", 0.0, 1_200_000.0),
+        ("JM", 600_000.0, 1_200_000.0),
+        ("TG", 600_000.0, 300_000.0),
+    ] {
+        let grid_ref = OsgbGridRef::parse_str(s).unwrap();
+
+        assert_eq!((grid_ref.easting(), grid_ref.northing()), (easting, northing), "{s}");
+        assert_eq!(grid_ref.to_string(), s);
+    }
+}
+
+#[test]
+fn parse_formats() {
+    for s in ["TQ 30 80", "TQ3080", "tq 30 80", "  TQ  3080 ", "T Q 30 80"] {
+        let grid_ref = OsgbGridRef::parse_str(s).unwrap();
+
+        assert_eq!(grid_ref.easting(), 530_000.0, "{s}");
+        assert_eq!(grid_ref.northing(), 180_000.0, "{s}");
+        assert_eq!(grid_ref.precision(), 2);
+    }
+
+    for s in ["", "T", "TQ 3", "TQ 30 8", "TQ 3 80", "TQ 30A 80", "TI 30 80", "TQ 123456 123456", "AA", "JZ", "ZZ"] {
+        assert!(OsgbGridRef::parse_str(s).is_err(), "{s}");
+    }
+}
+
+#[test]
+fn osgb36_worked_example() {
+    // From the Ordnance Survey's "A guide to coordinate systems in Great Britain"
+    let coord = LatLon::create(52.657570306, 1.717921583).unwrap();
+
+    let grid_ref = OsgbGridRef::from_osgb36(&coord, 5).unwrap();
+    assert!((grid_ref.easting() - 651_409.903).abs() < 1e-3);
+    assert!((grid_ref.northing() - 313_177.270).abs() < 1e-3);
+
+    assert!(grid_ref.to_osgb36().approx_eq(&coord, 1e-9));
+}
+
+#[test]
+fn datum_shift() {
+    // The Airy transit circle at Greenwich, on WGS84, is a little over 100 m west
+    // of the OSGB36 (and original) prime meridian
+    let coord = LatLon::create(51.477811, -0.001475).unwrap();
+    let grid_ref = OsgbGridRef::from_latlon(&coord, 3).unwrap();
+
+    assert_eq!(grid_ref.to_string(), "TQ 388 773");
+    assert!(grid_ref.to_osgb36().longitude().abs() < 2e-4);
+    assert!(grid_ref.to_latlon().haversine(&coord) < 0.01);
+
+    // Off the grid
+    assert!(OsgbGridRef::from_latlon(&LatLon::create(48.8566, 2.3522).unwrap(), 3).is_err());
+    assert!(OsgbGridRef::from_latlon(&LatLon::create(62.0, -2.0).unwrap(), 3).is_err());
+}
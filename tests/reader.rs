@@ -0,0 +1,72 @@
+#![cfg(feature = "csv")]
+
+use geoconvert::{Error, LatLon, Mgrs, reader::CoordReader};
+
+#[test]
+fn latlon_and_mgrs_columns_agree() {
+    let data = "\
+id,mgrs,lon,lat
+1,18TWL8566411315,-73.985278,40.748333
+2,56HLH3490052288,151.2153,-33.8568
+3,31UDQ4825211954,2.2945,48.8584
+";
+
+    let mgrs = CoordReader::mgrs(csv::Reader::from_reader(data.as_bytes()), "mgrs").unwrap();
+    let latlon = CoordReader::lonlat(csv::Reader::from_reader(data.as_bytes()), "lon", "lat").unwrap();
+
+    let mut count = 0;
+    for (mgrs, latlon) in mgrs.zip(latlon) {
+        let mgrs = mgrs.unwrap();
+        let latlon = latlon.unwrap();
+
+        assert_eq!(mgrs, Mgrs::from_latlon(&latlon, 5));
+        count += 1;
+    }
+
+    assert_eq!(count, 3);
+}
+
+#[test]
+fn bad_rows_are_reported_per_row() {
+    let data = "\
+lat,lon
+40.748333,-73.985278
+north,-73.985278
+-33.8568
+-33.8568,151.2153
+";
+
+    let reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(data.as_bytes());
+    let mut coords = CoordReader::latlon(reader, 0, "lon").unwrap();
+
+    assert_eq!(coords.next().unwrap().unwrap(), LatLon::create(40.748333, -73.985278).unwrap());
+
+    assert!(matches!(coords.next().unwrap(), Err(Error::InvalidCoord(_))));
+    assert_eq!(coords.line(), 3);
+
+    assert!(matches!(coords.next().unwrap(), Err(Error::MissingColumn(_))));
+    assert_eq!(coords.line(), 4);
+
+    assert_eq!(coords.next().unwrap().unwrap(), LatLon::create(-33.8568, 151.2153).unwrap());
+    assert!(coords.next().is_none());
+}
+
+#[test]
+fn unknown_header_is_an_error() {
+    let reader = csv::Reader::from_reader("lat,lon\n".as_bytes());
+
+    assert!(matches!(CoordReader::mgrs(reader, "mgrs"), Err(Error::MissingColumn(_))));
+}
+
+#[test]
+fn csv_errors_keep_source() {
+    let reader = csv::Reader::from_reader(&b"lat,lon\n40.7,\xff\n"[..]);
+    let mut coords = CoordReader::latlon(reader, "lat", "lon").unwrap();
+
+    let Some(Err(Error::Csv(err))) = coords.next() else {
+        panic!("expected a CSV error");
+    };
+    assert!(err.downcast_ref::<csv::Error>().is_some());
+}
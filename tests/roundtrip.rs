@@ -0,0 +1,66 @@
+use geoconvert::{LatLon, Mgrs, UtmUps};
+use proptest::prelude::*;
+
+/// Points in the UTM latitude range `[-80, 84]`
+fn utm_latlon() -> impl Strategy<Value = LatLon> {
+    (-80.0..=84.0, -180.0..180.0).prop_map(|(lat, lon)| LatLon::create(lat, lon).unwrap())
+}
+
+/// Points anywhere, including the UPS regions
+fn any_latlon() -> impl Strategy<Value = LatLon> {
+    (-90.0..=90.0, -180.0..180.0).prop_map(|(lat, lon)| LatLon::create(lat, lon).unwrap())
+}
+
+proptest! {
+    #[test]
+    fn latlon_utmups_latlon(coord in utm_latlon()) {
+        let utm = coord.to_utmups();
+        prop_assert_ne!(utm.zone(), 0);
+
+        let back = utm.to_latlon();
+        prop_assert!(back.approx_eq(&coord, 1e-8), "{coord:?} -> {utm:?} -> {back:?}");
+    }
+
+    #[test]
+    fn latlon_ups_latlon(coord in any_latlon()) {
+        let utm = coord.to_utmups();
+        let back = utm.to_latlon();
+
+        // Longitude is undefined at the poles
+        let tol = if coord.latitude().abs() == 90.0 { 360.0 } else { 1e-8 };
+        prop_assert!((back.latitude() - coord.latitude()).abs() < 1e-8, "{coord:?} -> {utm:?} -> {back:?}");
+        prop_assert!(back.approx_eq(&coord, tol), "{coord:?} -> {utm:?} -> {back:?}");
+    }
+
+    #[test]
+    fn utmups_string_round_trip(coord in any_latlon()) {
+        let utm = coord.to_utmups();
+        let parsed: UtmUps = utm.to_string().parse().unwrap();
+
+        // The string is rounded to the nearest millimeter
+        prop_assert_eq!(parsed.zone(), utm.zone());
+        prop_assert_eq!(parsed.is_north(), utm.is_north());
+        prop_assert!(parsed.approx_eq(&utm, 1e-3), "{utm:?} -> {parsed:?}");
+    }
+
+    #[test]
+    fn latlon_mgrs_latlon(coord in any_latlon()) {
+        for precision in 0..=11 {
+            let mgrs = coord.to_mgrs(precision);
+            let mgrs_str = mgrs.to_string();
+
+            let parsed = Mgrs::parse_str(&mgrs_str).unwrap();
+            prop_assert_eq!(parsed, mgrs, "{}", mgrs_str);
+
+            // The point is in the square named by the MGRS string, so it's
+            // within half a diagonal of the center the string parses to
+            // (allowing for the grid scale)
+            let center = parsed.to_latlon();
+            let max_distance = mgrs.resolution_meters() * core::f64::consts::FRAC_1_SQRT_2 * 1.01 + 1e-6;
+            prop_assert!(
+                coord.geodesic_distance(&center) <= max_distance,
+                "{:?} -> {} -> {:?}", coord, mgrs_str, center,
+            );
+        }
+    }
+}
@@ -0,0 +1,190 @@
+#![cfg(feature = "serde")]
+
+use geoconvert::{Ellipsoid, LatLon, Mgrs, Position, UtmUps};
+use serde::{Deserialize, Serialize};
+
+fn round_trip<T>(value: &T) -> T
+where
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+}
+
+#[test]
+fn latlon_round_trip() {
+    let coord = LatLon::create(40.748333, -73.985278).unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&coord).unwrap(),
+        r#"{"latitude":40.748333,"longitude":-73.985278}"#
+    );
+    assert_eq!(round_trip(&coord), coord);
+}
+
+#[test]
+fn latlon_aliases() {
+    let coord = LatLon::create(40.748333, -73.985278).unwrap();
+
+    for json in [
+        r#"{"latitude":40.748333,"longitude":-73.985278}"#,
+        r#"{"lat":40.748333,"longitude":-73.985278}"#,
+        r#"{"latitude":40.748333,"lon":-73.985278}"#,
+        r#"{"lat":40.748333,"lon":-73.985278}"#,
+    ] {
+        assert_eq!(serde_json::from_str::<LatLon>(json).unwrap(), coord, "{json}");
+    }
+
+    // The same field twice under different names
+    assert!(serde_json::from_str::<LatLon>(r#"{"lat":40.0,"latitude":40.0,"lon":0.0}"#).is_err());
+}
+
+#[test]
+fn utmups_round_trip() {
+    for coord in [
+        UtmUps::create(18, true, 585664.121, 4511315.422).unwrap(),
+        UtmUps::create(56, false, 334_368.6, 6_250_948.3).unwrap(),
+        UtmUps::create(0, true, 2_000_000.0, 2_000_000.0).unwrap(),
+    ] {
+        let parsed = round_trip(&coord);
+
+        assert_eq!(parsed, coord);
+        assert_eq!(parsed.zone(), coord.zone());
+        assert_eq!(parsed.is_north(), coord.is_north());
+        assert_eq!(parsed.easting(), coord.easting());
+        assert_eq!(parsed.northing(), coord.northing());
+    }
+
+    assert_eq!(
+        serde_json::to_string(&UtmUps::create(18, true, 585664.0, 4511315.0).unwrap()).unwrap(),
+        r#"{"zone":18,"northp":true,"easting":585664.0,"northing":4511315.0}"#
+    );
+}
+
+#[test]
+fn utmups_aliases() {
+    let coord = UtmUps::create(18, true, 585664.0, 4511315.0).unwrap();
+
+    for name in ["northp", "north", "is_north"] {
+        let json = format!(r#"{{"zone":18,"{name}":true,"easting":585664.0,"northing":4511315.0}}"#);
+        let parsed: UtmUps = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed, coord, "{json}");
+        assert!(parsed.is_north());
+    }
+
+    // The same field twice under different names
+    let json = r#"{"zone":18,"north":true,"is_north":true,"easting":585664.0,"northing":4511315.0}"#;
+    assert!(serde_json::from_str::<UtmUps>(json).is_err());
+}
+
+#[test]
+fn mgrs_round_trip() {
+    for s in ["18TWL856641113154", "18TWL8511", "18T", "18TWL", "ZGC2677330083", "56HLH3436850948"] {
+        let coord: Mgrs = s.parse().unwrap();
+
+        let json = serde_json::to_string(&coord).unwrap();
+        assert!(json.contains(&format!(r#""precision":{}"#, coord.precision())), "{json}");
+
+        let parsed: Mgrs = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.precision(), coord.precision());
+        assert_eq!(parsed.to_string(), s);
+        assert_eq!(parsed, coord);
+    }
+}
+
+#[test]
+fn mgrs_flattened() {
+    let coord = Mgrs::create(18, true, 585664.0, 4511315.0, 5).unwrap();
+
+    // The UTM fields sit next to the precision, not nested
+    assert_eq!(
+        serde_json::to_string(&coord).unwrap(),
+        r#"{"zone":18,"northp":true,"easting":585664.0,"northing":4511315.0,"precision":5}"#
+    );
+
+    // The UtmUps aliases work through the flatten
+    for name in ["northp", "north", "is_north"] {
+        let json = format!(r#"{{"zone":18,"{name}":true,"easting":585664.0,"northing":4511315.0,"precision":5}}"#);
+        let parsed: Mgrs = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed.to_string(), "18TWL8566411315", "{json}");
+        assert_eq!(parsed.precision(), 5);
+    }
+
+    // Precision is required
+    let json = r#"{"zone":18,"northp":true,"easting":585664.0,"northing":4511315.0}"#;
+    assert!(serde_json::from_str::<Mgrs>(json).is_err());
+
+    // and must be in range
+    for precision in [-2, 12] {
+        let json = format!(r#"{{"zone":18,"northp":true,"easting":585664.0,"northing":4511315.0,"precision":{precision}}}"#);
+        assert!(serde_json::from_str::<Mgrs>(&json).is_err(), "{json}");
+    }
+}
+
+#[test]
+fn serde_fields_matches_derived() {
+    #[derive(Serialize, Deserialize)]
+    struct Fields(#[serde(with = "geoconvert::serde_fields")] Mgrs);
+
+    for s in ["18TWL856641113154", "18T", "ZGC2677330083"] {
+        let coord: Mgrs = s.parse().unwrap();
+
+        let derived = serde_json::to_string(&coord).unwrap();
+        let fields = serde_json::to_string(&Fields(coord)).unwrap();
+        assert_eq!(derived, fields);
+
+        // Each reads the other's output
+        assert_eq!(serde_json::from_str::<Mgrs>(&fields).unwrap().to_string(), s);
+        assert_eq!(serde_json::from_str::<Fields>(&derived).unwrap().0.to_string(), s);
+    }
+}
+
+#[test]
+fn serde_fields_postcard_round_trip() {
+    #[derive(Serialize, Deserialize)]
+    struct Fields(#[serde(with = "geoconvert::serde_fields")] Mgrs);
+
+    for s in ["18TWL856641113154", "18T", "ZGC2677330083", "56HLH3436850948"] {
+        let coord: Mgrs = s.parse().unwrap();
+
+        let bytes = postcard::to_allocvec(&Fields(coord)).unwrap();
+        let parsed = postcard::from_bytes::<Fields>(&bytes).unwrap().0;
+
+        assert_eq!(parsed.to_string(), s);
+        assert_eq!(parsed.precision(), coord.precision());
+        assert_eq!(parsed.easting(), coord.easting());
+        assert_eq!(parsed.northing(), coord.northing());
+    }
+}
+
+#[test]
+fn serde_fields_rejects_invalid_precision() {
+    #[derive(Serialize, Deserialize)]
+    struct Fields(#[serde(with = "geoconvert::serde_fields")] Mgrs);
+
+    for precision in [-2, 12, 50] {
+        let json = format!(r#"{{"zone":18,"northp":true,"easting":585664.0,"northing":4511315.0,"precision":{precision}}}"#);
+        assert!(serde_json::from_str::<Fields>(&json).is_err(), "{json}");
+    }
+}
+
+#[test]
+fn position_round_trip() {
+    let coord = LatLon::create(40.748333, -73.985278).unwrap();
+
+    for position in [
+        Position::LatLon(coord),
+        Position::UtmUps(coord.to_utmups()),
+        Position::Mgrs(coord.to_mgrs(5)),
+    ] {
+        assert_eq!(round_trip(&position), position);
+    }
+}
+
+#[test]
+fn ellipsoid_round_trip() {
+    for ellipsoid in [Ellipsoid::WGS84, Ellipsoid::GRS80, Ellipsoid::CLARKE1866, Ellipsoid::AIRY1830] {
+        assert_eq!(round_trip(&ellipsoid), ellipsoid);
+    }
+}
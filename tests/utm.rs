@@ -0,0 +1,508 @@
+use geoconvert::{Error, LatLon, UtmUps};
+
+const WGS84_A: f64 = 6_378_137.0;
+const WGS84_F: f64 = 1.0 / 298.257_223_563;
+
+/// Step in degrees used for the finite differences (about 10 cm)
+const STEP: f64 = 1e-6;
+
+fn project(lat: f64, lon: f64, zone: i32) -> (f64, f64) {
+    let coord = LatLon::create(lat, lon).unwrap();
+    let coord = if zone == 0 {
+        UtmUps::from_latlon(&coord)
+    } else {
+        UtmUps::from_latlon_zone(&coord, zone).unwrap()
+    };
+
+    assert_eq!(coord.zone(), zone);
+
+    (coord.easting(), coord.northing())
+}
+
+/// Computes the convergence and scale numerically from the direction and length
+/// of a small step due north, projected onto the grid.
+fn numerical_convergence_and_scale(lat: f64, lon: f64, zone: i32) -> (f64, f64) {
+    let (x0, y0) = project(lat - STEP / 2.0, lon, zone);
+    let (x1, y1) = project(lat + STEP / 2.0, lon, zone);
+    let (dx, dy) = (x1 - x0, y1 - y0);
+
+    // Meridional radius of curvature
+    let e2 = WGS84_F * (2.0 - WGS84_F);
+    let m = WGS84_A * (1.0 - e2) / (1.0 - e2 * lat.to_radians().sin().powi(2)).powf(1.5);
+
+    let gamma = -dx.atan2(dy).to_degrees();
+    let k = dx.hypot(dy) / (m * STEP.to_radians());
+
+    (gamma, k)
+}
+
+fn check(lat: f64, lon: f64, zone: i32) {
+    let coord = LatLon::create(lat, lon).unwrap();
+    let coord = if zone == 0 {
+        UtmUps::from_latlon(&coord)
+    } else {
+        UtmUps::from_latlon_zone(&coord, zone).unwrap()
+    };
+
+    let (gamma, k) = coord.convergence_and_scale();
+    let (expected_gamma, expected_k) = numerical_convergence_and_scale(lat, lon, zone);
+
+    assert!(
+        (gamma - expected_gamma).abs() < 1e-6,
+        "gamma at ({lat}, {lon}) in zone {zone}: {gamma} != {expected_gamma}"
+    );
+    assert!(
+        (k - expected_k).abs() < 1e-7,
+        "k at ({lat}, {lon}) in zone {zone}: {k} != {expected_k}"
+    );
+}
+
+#[test]
+fn convergence_and_scale_utm() {
+    // Northern hemisphere, east and west of the central meridian
+    check(40.748333, -73.985278, 18);
+    check(40.748333, -76.5, 18);
+    // Southern hemisphere
+    check(-33.8688, 151.2093, 56);
+    check(-54.8, -68.3, 19);
+    // High latitude, Norway/Svalbard exception zones
+    check(60.4, 5.3, 32);
+    check(78.2, 15.6, 33);
+    // Near the equator
+    check(0.5, 32.9, 36);
+    // Forced into a neighbouring zone, far from its central meridian
+    check(40.0, -77.9, 17);
+}
+
+#[test]
+fn convergence_and_scale_ups() {
+    check(85.0, 45.0, 0);
+    check(87.5, -120.0, 0);
+    check(-81.0, 10.0, 0);
+    check(-88.0, 170.0, 0);
+}
+
+#[test]
+fn convergence_and_scale_on_central_meridian() {
+    for lat in [-79.0, -45.0, 0.0, 30.0, 83.0] {
+        let (gamma, k) = LatLon::create(lat, 3.0).unwrap().to_utmups().convergence_and_scale();
+
+        assert!(gamma.abs() < 1e-12);
+        assert!((k - 0.9996).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn convergence_and_scale_at_poles() {
+    // Longitude is meaningless at the pole itself, so only the scale is checked
+    for lat in [90.0, -90.0] {
+        let (_, k) = LatLon::create(lat, 30.0).unwrap().to_utmups().convergence_and_scale();
+
+        assert!((k - 0.994).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn ups_poles_round_trip() {
+    for (lat, northp) in [(90.0, true), (-90.0, false)] {
+        for lon in [-180.0, -90.0, 0.0, 45.0, 179.5] {
+            let coord = LatLon::create(lat, lon).unwrap().to_utmups();
+
+            assert_eq!(coord.zone(), 0);
+            assert_eq!(coord.is_north(), northp);
+            assert!((coord.easting() - 2_000_000.0).abs() < 1e-9);
+            assert!((coord.northing() - 2_000_000.0).abs() < 1e-9);
+
+            let back = coord.to_latlon();
+            assert_eq!(back.latitude(), lat);
+            // Canonical longitude at the pole
+            assert_eq!(back.longitude(), 0.0);
+        }
+    }
+}
+
+#[test]
+fn ups_near_poles_round_trip() {
+    for lat in [89.9999, 89.99999, -89.9999, -89.99999, 84.5, -80.5] {
+        for lon in [-180.0, -135.0, -0.5, 0.0, 60.0, 179.9] {
+            let coord = LatLon::create(lat, lon).unwrap();
+            let back = coord.to_utmups().to_latlon();
+
+            assert!(
+                coord.approx_eq(&back, 1e-6),
+                "({lat}, {lon}) round tripped to ({}, {})", back.latitude(), back.longitude()
+            );
+        }
+    }
+}
+
+#[test]
+fn zone_valid_for_latitude() {
+    // Standard zones are always valid, including on zone boundaries and at the
+    // edges of the Norway and Svalbard exceptions
+    for (lat, lon) in [
+        (40.0, -72.0),
+        (40.0, -78.0),
+        (0.0, -180.0),
+        (56.0, 3.0),
+        (63.999, 3.0),
+        (64.0, 3.0),
+        (72.0, 9.0),
+        (78.0, 21.0),
+        (83.9, 41.999),
+        (-80.0, 10.0),
+        (84.0, 10.0),
+        (-80.1, 10.0),
+    ] {
+        let coord = LatLon::create(lat, lon).unwrap().to_utmups();
+        assert!(coord.is_zone_valid_for_latitude(), "({lat}, {lon}) in zone {}", coord.zone());
+    }
+
+    // Non-standard zones
+    for (lat, lon, zone) in [
+        (40.0, -71.0, 18),
+        (60.0, 2.0, 32),
+        (60.0, 4.0, 31),
+        (78.0, 10.0, 32),
+        (78.0, 22.0, 34),
+        (78.0, 34.0, 36),
+    ] {
+        let coord = UtmUps::from_latlon_zone(&LatLon::create(lat, lon).unwrap(), zone).unwrap();
+        assert!(!coord.is_zone_valid_for_latitude(), "({lat}, {lon}) in zone {zone}");
+    }
+
+    // UPS too far from the pole
+    assert!(!UtmUps::create(0, true, 2_000_000.0, 1_250_000.0).unwrap().is_zone_valid_for_latitude());
+}
+
+#[test]
+fn create_normalizes_hemisphere() {
+    for (northp, northing, expected_northp, expected_northing) in [
+        (true, -1000.0, false, 9_999_000.0),
+        (true, -99_000.0, false, 9_901_000.0),
+        (true, -5_000_000.0, false, 5_000_000.0),
+        (false, 10_000_000.0, true, 0.0),
+        (false, 10_050_000.0, true, 50_000.0),
+        // Already in the right hemisphere
+        (true, 0.0, true, 0.0),
+        (false, 9_999_999.0, false, 9_999_999.0),
+    ] {
+        let coord = UtmUps::create(31, northp, 500_000.0, northing).unwrap();
+
+        assert_eq!(coord.is_north(), expected_northp);
+        assert_eq!(coord.northing(), expected_northing);
+
+        // Converts back to the same normalized point
+        let latlon = coord.to_latlon();
+        assert_eq!(latlon.is_north() || latlon.latitude() == 0.0, expected_northp);
+        assert!(latlon.to_utmups().approx_eq(&coord, 1e-6));
+    }
+
+    // Out of range even with the slop
+    assert!(UtmUps::create(31, true, 500_000.0, -9_200_000.0).is_err());
+    assert!(UtmUps::create(31, false, 500_000.0, 19_700_000.0).is_err());
+}
+
+#[test]
+fn grid_north_offset_ups() {
+    for (lat, lon) in [(85.0, 45.0), (89.5, -120.0), (84.2, 179.0), (-81.0, 10.0), (-88.0, -170.0), (-89.99, 0.5)] {
+        let coord = LatLon::create(lat, lon).unwrap().to_utmups();
+        assert_eq!(coord.zone(), 0);
+
+        let offset = coord.grid_north_offset();
+        let expected = if lat > 0.0 { lon } else { -lon };
+
+        assert!((offset - expected).abs() < 1e-9, "({lat}, {lon}): {offset} != {expected}");
+        assert!((offset - coord.convergence_and_scale().0).abs() < 1e-9);
+    }
+
+    for northp in [true, false] {
+        assert_eq!(UtmUps::create(0, northp, 2_000_000.0, 2_000_000.0).unwrap().grid_north_offset(), 0.0);
+    }
+}
+
+#[test]
+fn grid_north_offset_utm() {
+    for (lat, lon) in [(83.0, 5.0), (-79.5, -100.0), (60.4, 5.3)] {
+        let coord = LatLon::create(lat, lon).unwrap().to_utmups();
+        let (gamma, _) = coord.convergence_and_scale();
+
+        assert_eq!(coord.grid_north_offset(), gamma);
+    }
+}
+
+#[test]
+fn to_hemisphere_near_equator() {
+    for lat in [-0.5, -1e-6, 0.0, 1e-6, 0.5] {
+        for lon in [-75.0, -72.5, 0.3] {
+            let coord = LatLon::create(lat, lon).unwrap();
+            let utm = coord.to_utmups();
+
+            for northp in [true, false] {
+                let moved = utm.to_hemisphere(northp);
+
+                assert_eq!(moved.zone(), utm.zone());
+                assert_eq!(moved.is_north(), northp);
+                assert_eq!(moved.easting(), utm.easting());
+                assert!((moved.northing() - utm.northing()).abs() % 10_000_000.0 < 1e-6);
+                assert!(moved.to_latlon().approx_eq(&coord, 1e-9), "({lat}, {lon}) moved to northp = {northp}");
+
+                // Normalized back by create
+                let created = UtmUps::create(moved.zone(), moved.is_north(), moved.easting(), moved.northing()).unwrap();
+                assert!(created.approx_eq(&utm, 1e-6));
+            }
+        }
+    }
+
+    // UPS is left alone
+    let ups = LatLon::create(-85.0, 10.0).unwrap().to_utmups();
+    assert_eq!(ups.to_hemisphere(true), ups);
+}
+
+#[test]
+fn to_zone_neighbors() {
+    // Points close to a zone boundary, and the zone on the other side of it
+    for (lat, lon, neighbor) in [
+        (40.0, -77.9, 17),
+        (-33.8688, 150.1, 55),
+        (0.0, 0.5, 30),
+        (-10.0, -179.9, 60),
+        (10.0, 179.5, 1),
+        (83.5, 101.0, 48),
+    ] {
+        let coord = LatLon::create(lat, lon).unwrap();
+        let utm = coord.to_utmups();
+
+        for zone in [utm.zone(), neighbor] {
+            let moved = utm.to_zone(zone).unwrap();
+
+            assert_eq!(moved.zone(), zone);
+            assert_eq!(moved.is_north(), utm.is_north());
+            assert!(moved.to_latlon().approx_eq(&coord, 1e-9), "({lat}, {lon}) moved to zone {zone}");
+            assert!(moved.to_zone(utm.zone()).unwrap().approx_eq(&utm, 1e-6));
+        }
+
+        assert!(utm.to_zone((utm.zone() + 1) % 60 + 1).is_err());
+        assert!(utm.to_zone(61).is_err());
+    }
+
+    // Too far into the neighboring zone to be represented
+    let utm = LatLon::create(0.0, 4.0).unwrap().to_utmups();
+    assert!(utm.to_zone(30).is_err());
+
+    let ups = LatLon::create(85.0, 10.0).unwrap().to_utmups();
+    assert!(ups.to_zone(33).is_err());
+}
+
+#[test]
+fn zone_longitude_bounds_match_standard_zone() {
+    for lat in [-80.0, -45.0, 0.0, 55.9, 56.0, 60.0, 63.9, 64.0, 71.9, 72.0, 78.0, 83.9] {
+        for tenth in -1800..1800 {
+            let lon = f64::from(tenth) / 10.0 + 0.05;
+            let zone = LatLon::create(lat, lon).unwrap().to_utmups().zone();
+            let (west, east) = UtmUps::zone_longitude_bounds_at_latitude(zone, lat).unwrap();
+
+            assert!(west <= lon && lon < east, "({lat}, {lon}) in zone {zone} [{west}, {east})");
+        }
+    }
+}
+
+#[test]
+fn antimeridian_round_trip() {
+    for lat in [-79.9, -45.0, -1e-9, 0.0, 45.0, 60.0, 70.0, 80.0, 83.99] {
+        for (lon, zone) in [(179.9999, 60), (179.99999999, 60), (-180.0, 1), (-179.9999, 1), (-179.99999999, 1)] {
+            let coord = LatLon::create(lat, lon).unwrap();
+            let utm = coord.to_utmups();
+
+            assert_eq!(utm.zone(), zone, "({lat}, {lon})");
+            assert_eq!(UtmUps::zone_for_longitude(lon), zone);
+
+            let back = utm.to_latlon();
+            assert!(back.haversine(&coord) < 1e-6, "({lat}, {lon}) round tripped to ({}, {})", back.latitude(), back.longitude());
+
+            let back = coord.to_mgrs(5).to_latlon();
+            assert!(back.haversine(&coord) < 1.5, "({lat}, {lon}) round tripped to ({}, {})", back.latitude(), back.longitude());
+        }
+
+        // On the antimeridian, forced into the zone to the west
+        let coord = LatLon::create(lat, -180.0).unwrap();
+        let back = UtmUps::from_latlon_zone(&coord, 60).unwrap().to_latlon();
+
+        assert_eq!(back.longitude(), -180.0, "({lat}, -180) round tripped to ({}, {})", back.latitude(), back.longitude());
+        assert!(LatLon::create(back.latitude(), back.longitude()).is_ok());
+        assert!(back.haversine(&coord) < 1e-6);
+    }
+}
+
+#[test]
+fn overlap_points_convert_to_adjacent_zone() {
+    for lat in [-79.0, -30.0, 0.5, 45.0, 60.0, 83.0] {
+        for lon in (-1800..1800).map(|lon| f64::from(lon) / 10.0 + 0.03) {
+            let utm = LatLon::create(lat, lon).unwrap().to_utmups();
+            let adjacent = utm.adjacent_zone().unwrap();
+
+            assert_ne!(adjacent, utm.zone(), "({lat}, {lon})");
+
+            if utm.in_overlap_zone(40.0) {
+                let converted = UtmUps::from_latlon_zone(&utm.to_latlon(), adjacent).unwrap();
+                assert!(converted.to_latlon().haversine(&utm.to_latlon()) < 1e-6, "({lat}, {lon})");
+            }
+        }
+    }
+}
+
+#[test]
+fn grid_lines_stay_in_zone_extent() {
+    for (zone, northp, lines) in [(18, true, 9 + 96), (18, false, 9 + 91), (0, true, 15 + 15), (0, false, 25 + 25)] {
+        let mut count = 0;
+
+        for (start, end) in UtmUps::grid_lines(zone, northp, 100_000.0).unwrap() {
+            for point in [start, end] {
+                assert_eq!(point.zone(), zone);
+                assert_eq!(point.is_north(), northp);
+                assert!(point.validate().is_ok(), "{point}");
+            }
+
+            assert!(start.easting() == end.easting() || start.northing() == end.northing());
+            count += 1;
+        }
+
+        assert_eq!(count, lines, "zone {zone}, northp {northp}");
+    }
+}
+
+#[test]
+fn create_rejects_non_finite() {
+    for zone in [0, 18] {
+        for (easting, northing) in [(f64::INFINITY, 4511315.0), (585664.0, f64::NAN), (f64::NAN, f64::NAN)] {
+            assert!(matches!(UtmUps::create(zone, true, easting, northing), Err(Error::InvalidCoord(_))), "({easting}, {northing})");
+        }
+    }
+}
+
+#[test]
+fn ups_points_within_ups_bounds() {
+    for (lats, northp) in [((84..=90), true), ((-90..=-81), false)] {
+        for lat in lats {
+            for lon in (-180..180).step_by(15) {
+                let coord = LatLon::create(f64::from(lat), f64::from(lon)).unwrap().to_utmups();
+                assert!(coord.is_ups(), "({lat}, {lon})");
+                assert_eq!(coord.is_north(), northp);
+
+                let (min_e, max_e, min_n, max_n) = UtmUps::ups_bounds(northp);
+                assert!((min_e..=max_e).contains(&coord.easting()), "({lat}, {lon})");
+                assert!((min_n..=max_n).contains(&coord.northing()), "({lat}, {lon})");
+            }
+        }
+    }
+}
+
+#[test]
+fn out_of_range_error_keeps_coordinate() {
+    let err = UtmUps::create(0, true, 2_000_000.0, 4_000_000.0).unwrap_err();
+
+    let Error::UtmOutOfRange { zone, northp, easting, northing, easting_limits, northing_limits, .. } = &err else {
+        panic!("unexpected error {err:?}");
+    };
+    assert_eq!((*zone, *northp, *easting, *northing), (0, true, 2_000_000.0, 4_000_000.0));
+    assert_eq!(*easting_limits, (1_200_000.0, 2_800_000.0));
+    assert_eq!(*northing_limits, (1_200_000.0, 2_800_000.0));
+
+    assert_eq!(
+        err.to_string(),
+        "UTM coords are invalid: Northing 4000.00km not in UPS range for N hemisphere [1200.00km, 2800.00km]",
+    );
+}
+
+#[test]
+fn zones_in_bbox_contains_zones_of_points() {
+    for lat in (-90..=90).step_by(4) {
+        for lon in (-180..180).step_by(3) {
+            let point = LatLon::create(f64::from(lat), f64::from(lon)).unwrap();
+            assert_eq!(UtmUps::zones_in_bbox(&point, &point), [point.to_utmups().zone()], "({lat}, {lon})");
+        }
+    }
+
+    for (sw, ne) in [((50.0, 170.0), (80.0, -170.0)), ((-85.0, -10.0), (60.0, 20.0)), ((70.0, 0.0), (76.0, 40.0))] {
+        let sw = LatLon::create(sw.0, sw.1).unwrap();
+        let ne = LatLon::create(ne.0, ne.1).unwrap();
+        let zones = UtmUps::zones_in_bbox(&sw, &ne);
+
+        let width = (ne.longitude() - sw.longitude()).rem_euclid(360.0);
+        for i in 0..=100 {
+            for j in 0..=100 {
+                let lat = sw.latitude() + (ne.latitude() - sw.latitude()) * f64::from(i) / 100.0;
+                let point = LatLon::normalize(lat, sw.longitude() + width * f64::from(j) / 100.0);
+
+                assert!(zones.contains(&point.to_utmups().zone()), "{point:?} not in {zones:?}");
+            }
+        }
+    }
+
+    let sw = LatLon::create(10.0, 0.0).unwrap();
+    let ne = LatLon::create(0.0, 1.0).unwrap();
+    assert!(UtmUps::zones_in_bbox(&sw, &ne).is_empty());
+}
+
+#[test]
+fn forced_ups_round_trip() {
+    for (lat, lon) in [(83.0, 0.0), (84.0, 45.0), (89.9, -120.0), (-79.0, 10.0), (-80.0, -170.0), (-89.0, 90.0)] {
+        let coord = LatLon::create(lat, lon).unwrap();
+        let ups = UtmUps::try_from_latlon_ups(&coord).unwrap();
+
+        assert!(ups.is_ups(), "({lat}, {lon})");
+        assert_eq!(ups.is_north(), lat > 0.0);
+        assert!(ups.to_latlon().approx_eq(&coord, 1e-9), "({lat}, {lon})");
+    }
+
+    let coord = LatLon::create(0.0, 0.0).unwrap();
+    assert!(matches!(UtmUps::try_from_latlon_ups(&coord), Err(Error::UtmOutOfRange { zone: 0, .. })));
+}
+
+/// Zone, hemisphere, easting and northing (to the millimeter) for points in
+/// the standard zones, matching GeographicLib's `GeoConvert -u -p 3`
+#[test]
+fn geoconvert_reference() {
+    let points = [
+        (33.3, 44.4, 38, true, 444140.545, 3684706.356),
+        (40.748, -73.985, 18, true, 585688.018, 4511278.728),
+        (-33.8688, 151.2093, 56, false, 334368.634, 6250948.345),
+        // On and just south of the equator
+        (0.0, 3.0, 31, true, 500000.000, 0.000),
+        (0.0, -73.5, 18, true, 666931.643, 0.000),
+        (-1e-06, 9.0, 32, false, 500000.000, 9999999.889),
+        // Either side of the Norway exception
+        (56.0, 3.0, 32, true, 126049.971, 6222336.335),
+        (56.0, 2.999999, 31, true, 499999.938, 6206079.587),
+        (63.0, 5.0, 32, true, 297486.558, 6991891.407),
+        // Svalbard exceptions, up to the northern limit of UTM
+        (75.0, 8.5, 31, true, 658686.091, 8330970.089),
+        (78.0, 21.0, 35, true, 360973.604, 8665496.996),
+        (80.0, 33.5, 37, true, 393532.335, 8886622.365),
+        (83.999999, 40.0, 37, true, 511669.523, 9328195.000),
+        // Southern limit of UTM, the antimeridian and either side of a zone boundary
+        (-80.0, 179.9, 60, false, 556196.057, 1117013.304),
+        (-45.0, -180.0, 1, false, 263553.974, 5012670.495),
+        (12.0, 5.999999, 31, true, 826714.482, 1328333.182),
+        (12.0, 6.0, 32, true, 173285.409, 1328333.184),
+        // UPS, from the limits of UTM to the poles
+        (84.0, 0.0, 0, true, 2000000.000, 1333272.296),
+        (85.0, 45.0, 0, true, 2392767.688, 1607232.312),
+        (90.0, 0.0, 0, true, 2000000.000, 2000000.000),
+        (-80.000001, 120.0, 0, false, 2963843.861, 1443524.487),
+        (-87.5, -135.0, 0, false, 1803705.883, 1803705.883),
+        (-90.0, 0.0, 0, false, 2000000.000, 2000000.000),
+    ];
+
+    for (lat, lon, zone, northp, easting, northing) in points {
+        let coord = LatLon::create(lat, lon).unwrap().to_utmups();
+
+        assert_eq!(coord.zone(), zone, "({lat}, {lon})");
+        assert_eq!(coord.is_north(), northp, "({lat}, {lon})");
+        assert!((coord.easting() - easting).abs() < 1e-3, "easting at ({lat}, {lon}): {}", coord.easting());
+        assert!((coord.northing() - northing).abs() < 1e-3, "northing at ({lat}, {lon}): {}", coord.northing());
+
+        let back = coord.to_latlon();
+        assert!(back.approx_eq(&LatLon::create(lat, lon).unwrap(), 1e-9), "({lat}, {lon}) -> {back:?}");
+    }
+}